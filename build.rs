@@ -0,0 +1,57 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Collects the Rust sources and grammars below a directory.
+fn collect_sources(directory: &Path, sources: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_sources(&path, sources);
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "rs" || extension == "pest")
+        {
+            sources.push(path);
+        }
+    }
+}
+
+/// Exposes a hash of the sources of the compiler as `G3CSS_BUILD_HASH`, so the build cache
+/// is invalidated by any change to the compiler, even one keeping the package version.
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+    let mut sources = vec![];
+
+    for directory in [root.join("src"), root.join("crates")] {
+        println!("cargo:rerun-if-changed={}", directory.display());
+        collect_sources(&directory, &mut sources);
+    }
+
+    sources.sort();
+
+    // 64-bit FNV-1a over the path and content of every source.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for path in sources {
+        let relative = path.strip_prefix(&root).unwrap_or(&path);
+        let content = fs::read(&path).unwrap_or_default();
+
+        for byte in relative
+            .to_string_lossy()
+            .as_bytes()
+            .iter()
+            .chain(&[0])
+            .chain(&content)
+        {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    println!("cargo:rustc-env=G3CSS_BUILD_HASH={:016x}", hash);
+}
@@ -1,7 +1,48 @@
+pub mod types {
+    pub mod dependency_graph;
+    pub mod g3css_diagnostic;
+    pub mod g3css_document;
+    pub mod g3css_syntax;
+    pub mod g3css_token;
+    pub mod graph_edge;
+    pub mod graph_node;
+    pub mod syntax_view;
+    pub mod typed_ast;
+}
+
+pub mod rustal {
+    pub mod ast_lowering;
+    pub mod ast_visitor;
+    pub mod cst_builder;
+    pub mod doc_comments;
+    pub mod g3css_formatter;
+    pub mod g3css_lexer;
+    pub mod graph_export;
+    pub mod graph_handlers;
+    pub mod node_utils;
+    pub mod semantic_checks;
+    pub mod symbol_queries;
+}
+
 use std::rc::Rc;
 
-use g3css_parser::types::g3css_node::G3cssNode;
+use g3css_parser::types::{g3css_error::G3cssError, g3css_node::G3cssNode};
+use rustal::g3css_formatter::format_g3css;
 
 pub fn g3css_ast_setter(ast: Rc<G3cssNode>) {
     println!("{:#?}", ast);
 }
+
+/// Formats G3CSS source in the canonical style, keeping its comments.
+///
+/// # Arguments
+///
+/// - `src` - The G3CSS source to format.
+///
+/// # Returns
+///
+/// - `Result<String, G3cssError>` - Ok containing the formatted source, or an Err if the
+///   source does not parse.
+pub fn g3css_format(src: &str) -> Result<String, G3cssError> {
+    format_g3css(src)
+}
@@ -0,0 +1,472 @@
+use g3css_common::tables::{
+    g3css_keywords::find_g3css_block_keyword, g3css_properties::find_g3css_property,
+};
+use g3css_parser::types::{
+    g3css_alias::G3cssAlias, g3css_children::G3cssChildren, g3css_class::G3cssClass,
+    g3css_elements::G3cssElements, g3css_error::G3cssError, g3css_node::G3cssNode,
+    g3css_panoramic::G3cssPanoramic, g3css_theme::G3cssTheme, g3css_variable::G3cssVariable,
+};
+
+use crate::types::{
+    g3css_syntax::G3cssSyntaxNode,
+    syntax_view,
+    typed_ast::{
+        Alias, Block, Breakpoint, BreakpointItem, Class, ClassItem, Component, Element, Global,
+        GlobalItem, Scope, ScopeItem, Theme, ThemeKind, Variable,
+    },
+};
+
+/// Builds the error of a parser node whose shape the typed AST cannot hold.
+fn invalid_shape(message: &str) -> G3cssError {
+    G3cssError::OtherError(format!("Cannot lower the AST: {}", message))
+}
+
+/// Lowers the `[key, value]` vector of an alias, variable or nickname.
+fn lower_pair(kind: &str, pair: &[String]) -> Result<(String, String), G3cssError> {
+    match pair {
+        [key, value] => Ok((key.clone(), value.clone())),
+        _ => Err(invalid_shape(&format!(
+            "{} must hold a key and a value",
+            kind
+        ))),
+    }
+}
+
+/// Lowers the variables of a variables block or theme.
+fn lower_variables(variables: &[G3cssVariable]) -> Result<Vec<Variable>, G3cssError> {
+    variables
+        .iter()
+        .map(|G3cssVariable::Variable(pair)| {
+            let (key, value) = lower_pair("a variable", pair)?;
+            Ok(Variable { key, value })
+        })
+        .collect()
+}
+
+/// Lowers a theme, which holds a single variables block.
+fn lower_theme(kind: ThemeKind, themes: &[G3cssTheme]) -> Result<Theme, G3cssError> {
+    match themes {
+        [G3cssTheme::Variables(variables)] => Ok(Theme {
+            kind,
+            variables: lower_variables(variables)?,
+        }),
+        _ => Err(invalid_shape("a theme must hold a single variables block")),
+    }
+}
+
+/// Lowers a property or a nickname.
+fn lower_element(element: &G3cssElements) -> Result<Element, G3cssError> {
+    match element {
+        G3cssElements::Nickname(pair) => {
+            let (key, value) = lower_pair("a nickname", pair)?;
+            Ok(Element::Nickname { key, value })
+        }
+        element => {
+            let (name, value) = element.property().unwrap_or_default();
+
+            Ok(Element::Property {
+                property: find_g3css_property(name)
+                    .ok_or_else(|| invalid_shape(&format!("unknown property \"{}\"", name)))?,
+                value: value.to_string(),
+            })
+        }
+    }
+}
+
+/// Lowers an `!important` flag.
+fn lower_important(value: &str) -> Result<bool, G3cssError> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(invalid_shape(&format!(
+            "!important must be \"true\" or \"false\", found \"{}\"",
+            value
+        ))),
+    }
+}
+
+/// Lowers a properties or pseudo block, or `None` if the node is not one.
+fn lower_block(node: &G3cssClass) -> Option<Result<Block, G3cssError>> {
+    let (keyword, elements) = node.block()?;
+
+    let Some(keyword) = find_g3css_block_keyword(keyword) else {
+        return Some(Err(invalid_shape(&format!(
+            "unknown block \"{}\"",
+            keyword
+        ))));
+    };
+
+    Some(
+        elements
+            .iter()
+            .map(lower_element)
+            .collect::<Result<Vec<_>, _>>()
+            .map(|elements| Block { keyword, elements }),
+    )
+}
+
+/// Lowers a `panoramic_viewer` breakpoint, which holds its name followed by its children.
+fn lower_breakpoint(breakpoint: &[G3cssPanoramic]) -> Result<Breakpoint, G3cssError> {
+    let [G3cssPanoramic::Breakpoint(name), G3cssPanoramic::Children(children)] = breakpoint else {
+        return Err(invalid_shape(
+            "a breakpoint must hold a name and its children",
+        ));
+    };
+
+    let body = children
+        .iter()
+        .map(|node| match node {
+            G3cssClass::Important(value) => Ok(BreakpointItem::Important(lower_important(value)?)),
+            node => match lower_block(node) {
+                Some(block) => Ok(BreakpointItem::Block(block?)),
+                None => Err(invalid_shape(
+                    "a breakpoint can only hold !important and blocks",
+                )),
+            },
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Breakpoint {
+        name: name.clone(),
+        body,
+    })
+}
+
+/// Lowers a class, which starts with its name and the class it inherits, if any.
+fn lower_class(class: &[G3cssClass]) -> Result<Class, G3cssError> {
+    let (name, inherits, nodes) = match class {
+        [G3cssClass::ClassName(name), G3cssClass::Inherits(inherits), nodes @ ..] => {
+            (name, Some(inherits.clone()), nodes)
+        }
+        [G3cssClass::ClassName(name), nodes @ ..] => (name, None, nodes),
+        _ => return Err(invalid_shape("a class must start with its name")),
+    };
+
+    let body = nodes
+        .iter()
+        .map(|node| match node {
+            G3cssClass::Important(value) => Ok(ClassItem::Important(lower_important(value)?)),
+            G3cssClass::PanoramicViewer(breakpoints) => Ok(ClassItem::PanoramicViewer(
+                breakpoints
+                    .iter()
+                    .map(|breakpoint| lower_breakpoint(breakpoint))
+                    .collect::<Result<_, _>>()?,
+            )),
+            node => match lower_block(node) {
+                Some(block) => Ok(ClassItem::Block(block?)),
+                None => Err(invalid_shape(&format!(
+                    "class \"{}\" holds a second name or inherits",
+                    name
+                ))),
+            },
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Class {
+        name: name.clone(),
+        inherits,
+        body,
+    })
+}
+
+/// Lowers an element of a scope other than a theme, which only globals hold.
+fn lower_scope_item(child: &G3cssChildren) -> Result<ScopeItem, G3cssError> {
+    match child {
+        G3cssChildren::Aliases(aliases) => aliases
+            .iter()
+            .map(|G3cssAlias::Alias(pair)| {
+                let (key, value) = lower_pair("an alias", pair)?;
+                Ok(Alias { key, value })
+            })
+            .collect::<Result<_, _>>()
+            .map(ScopeItem::Aliases),
+        G3cssChildren::Variables(variables) => lower_variables(variables).map(ScopeItem::Variables),
+        G3cssChildren::Class(class) => lower_class(class).map(ScopeItem::Class),
+        G3cssChildren::Classes(classes) => classes
+            .iter()
+            .map(|class| lower_class(class))
+            .collect::<Result<_, _>>()
+            .map(ScopeItem::Classes),
+        G3cssChildren::LightTheme(_) | G3cssChildren::DarkTheme(_) => Err(invalid_shape(
+            "themes can only be defined in a global scope",
+        )),
+    }
+}
+
+/// Lowers the AST built by the parser into its typed form.
+///
+/// # Arguments
+///
+/// - `ast` - The root `G3cssNode` of a parsed, deserialized or hand-built AST.
+///
+/// # Returns
+///
+/// - `Result<Scope, G3cssError>` - Ok containing the typed AST, or an Err naming the first
+///   node whose shape the grammar cannot produce, such as a component holding a theme or a
+///   class without a name.
+pub fn lower_ast(ast: &G3cssNode) -> Result<Scope, G3cssError> {
+    let (nodes, is_global) = match ast {
+        G3cssNode::Global(nodes) => (nodes, true),
+        G3cssNode::Component(nodes) => (nodes, false),
+        _ => {
+            return Err(invalid_shape(
+                "the root of the AST must be a global or component scope",
+            ))
+        }
+    };
+
+    let (name, extends, children) = match nodes.as_slice() {
+        [G3cssNode::Name(name), G3cssNode::Children(children)] => (name, None, children),
+        [G3cssNode::Name(name), G3cssNode::Extends(extends), G3cssNode::Children(children)]
+            if !is_global =>
+        {
+            (name, Some(extends.clone()), children)
+        }
+        _ => {
+            return Err(invalid_shape(
+                "a scope must hold its name, the global it extends for components, and its \
+                 children",
+            ))
+        }
+    };
+
+    if !is_global {
+        let children = children
+            .iter()
+            .map(lower_scope_item)
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Scope::Component(Component {
+            name: name.clone(),
+            extends,
+            children,
+        }));
+    }
+
+    let children = children
+        .iter()
+        .map(|child| match child {
+            G3cssChildren::LightTheme(themes) => {
+                Ok(GlobalItem::Theme(lower_theme(ThemeKind::Light, themes)?))
+            }
+            G3cssChildren::DarkTheme(themes) => {
+                Ok(GlobalItem::Theme(lower_theme(ThemeKind::Dark, themes)?))
+            }
+            child => lower_scope_item(child).map(GlobalItem::Item),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Scope::Global(Global {
+        name: name.clone(),
+        children,
+    }))
+}
+
+/// Lowers the view of a block, whose keyword and properties must be known.
+fn lower_block_view(block: &syntax_view::Block) -> Result<Block, G3cssError> {
+    let keyword = block
+        .keyword()
+        .ok_or_else(|| invalid_shape("a block has an unknown keyword"))?;
+
+    let elements = block
+        .elements()
+        .map(|element| match element {
+            syntax_view::Element::Property(declaration) => Ok(Element::Property {
+                property: declaration
+                    .property()
+                    .ok_or_else(|| invalid_shape("a block sets an unknown property"))?,
+                value: declaration.value(),
+            }),
+            syntax_view::Element::Nickname(nickname) => Ok(Element::Nickname {
+                key: nickname.key(),
+                value: nickname.value(),
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Block { keyword, elements })
+}
+
+/// Lowers the view of a class.
+fn lower_class_view(class: &syntax_view::Class) -> Result<Class, G3cssError> {
+    let body = class
+        .body()
+        .map(|item| match item {
+            syntax_view::ClassItem::Important(important) => {
+                Ok(ClassItem::Important(important.value()))
+            }
+            syntax_view::ClassItem::Block(block) => lower_block_view(&block).map(ClassItem::Block),
+            syntax_view::ClassItem::PanoramicViewer(viewer) => viewer
+                .breakpoints()
+                .map(|breakpoint| {
+                    let body = breakpoint
+                        .body()
+                        .map(|item| match item {
+                            syntax_view::BreakpointItem::Important(important) => {
+                                Ok(BreakpointItem::Important(important.value()))
+                            }
+                            syntax_view::BreakpointItem::Block(block) => {
+                                lower_block_view(&block).map(BreakpointItem::Block)
+                            }
+                        })
+                        .collect::<Result<_, _>>()?;
+
+                    Ok(Breakpoint {
+                        name: breakpoint.name(),
+                        body,
+                    })
+                })
+                .collect::<Result<_, _>>()
+                .map(ClassItem::PanoramicViewer),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Class {
+        name: class.name(),
+        inherits: class.inherits(),
+        body,
+    })
+}
+
+/// Lowers the view of a variable.
+fn lower_variable_view(variable: syntax_view::Variable) -> Variable {
+    Variable {
+        key: variable.key(),
+        value: variable.value(),
+    }
+}
+
+/// Lowers the view of an element of a scope other than a theme.
+fn lower_scope_item_view(item: &syntax_view::ScopeItem) -> Result<ScopeItem, G3cssError> {
+    match item {
+        syntax_view::ScopeItem::Aliases(aliases) => Ok(ScopeItem::Aliases(
+            aliases
+                .aliases()
+                .map(|alias| Alias {
+                    key: alias.key(),
+                    value: alias.value(),
+                })
+                .collect(),
+        )),
+        syntax_view::ScopeItem::Variables(variables) => Ok(ScopeItem::Variables(
+            variables.variables().map(lower_variable_view).collect(),
+        )),
+        syntax_view::ScopeItem::Class(class) => lower_class_view(class).map(ScopeItem::Class),
+        syntax_view::ScopeItem::Classes(classes) => classes
+            .classes()
+            .map(|class| lower_class_view(&class))
+            .collect::<Result<_, _>>()
+            .map(ScopeItem::Classes),
+    }
+}
+
+/// Lowers a concrete syntax tree (CST) into the typed AST, read through the views of
+/// `syntax_view`.
+///
+/// The CST keeps every token of the source, so this is the lowering to use for source text;
+/// `lower_ast` is the one for an AST that has no source, such as a deserialized one.
+///
+/// # Arguments
+///
+/// - `root` - The `Root` node built by `parse_cst`.
+///
+/// # Returns
+///
+/// - `Result<Scope, G3cssError>` - Ok containing the typed AST, or an Err if the tree
+///   declares no scope or uses a block or property the tables do not define.
+pub fn lower_syntax(root: &G3cssSyntaxNode) -> Result<Scope, G3cssError> {
+    match syntax_view::Scope::from_root(root) {
+        Some(syntax_view::Scope::Global(global)) => {
+            let children = global
+                .children()
+                .map(|child| match child {
+                    syntax_view::GlobalItem::Theme(theme) => Ok(GlobalItem::Theme(Theme {
+                        kind: theme.kind(),
+                        variables: theme.variables().map(lower_variable_view).collect(),
+                    })),
+                    syntax_view::GlobalItem::Item(item) => {
+                        lower_scope_item_view(&item).map(GlobalItem::Item)
+                    }
+                })
+                .collect::<Result<_, _>>()?;
+
+            Ok(Scope::Global(Global {
+                name: global.name(),
+                children,
+            }))
+        }
+        Some(syntax_view::Scope::Component(component)) => {
+            let children = component
+                .children()
+                .map(|item| lower_scope_item_view(&item))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Scope::Component(Component {
+                name: component.name(),
+                extends: component.extends(),
+                children,
+            }))
+        }
+        None => Err(invalid_shape("the tree holds no global or component scope")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use g3css_parser::g3css_parse_source;
+
+    use crate::rustal::cst_builder::parse_cst;
+
+    use super::*;
+
+    /// Sample files of the parser, with comments, breakpoints and themes among them.
+    const SAMPLES: [&str; 4] = [
+        include_str!("../../../g3css-parser/tests/samples/app.g3css"),
+        include_str!("../../../g3css-parser/tests/samples/button.g3css"),
+        include_str!("../../../g3css-parser/tests/samples/layout.g3css"),
+        include_str!("../../../g3css-parser/tests/samples/commented.g3css"),
+    ];
+
+    #[test]
+    fn the_syntax_tree_and_the_parser_output_lower_to_the_same_ast() {
+        for source in SAMPLES {
+            let from_ast = lower_ast(&g3css_parse_source(source).unwrap()).unwrap();
+            let from_syntax = lower_syntax(&parse_cst(source).unwrap()).unwrap();
+
+            assert_eq!(from_syntax, from_ast);
+        }
+    }
+
+    #[test]
+    fn a_component_names_its_parts() {
+        let Scope::Component(component) =
+            lower_ast(&g3css_parse_source(SAMPLES[1]).unwrap()).unwrap()
+        else {
+            panic!("the sample is a component");
+        };
+
+        assert_eq!(component.name, "BUTTON");
+        assert_eq!(component.extends.as_deref(), Some("APP"));
+
+        let [ScopeItem::Class(class)] = component.children.as_slice() else {
+            panic!("the component holds a single class");
+        };
+
+        assert_eq!(class.inherits.as_deref(), Some("CARD"));
+        assert!(matches!(
+            class.body.as_slice(),
+            [ClassItem::Block(properties), ClassItem::Block(hover)]
+                if properties.keyword.name == "properties" && hover.keyword.name == "hover"
+        ));
+    }
+
+    #[test]
+    fn a_component_holding_a_theme_is_rejected() {
+        let ast = G3cssNode::Component(vec![
+            G3cssNode::Name("BUTTON".to_string()),
+            G3cssNode::Children(vec![G3cssChildren::LightTheme(vec![])]),
+        ]);
+
+        assert!(lower_ast(&ast).is_err());
+    }
+}
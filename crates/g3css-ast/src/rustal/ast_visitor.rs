@@ -0,0 +1,418 @@
+use g3css_parser::types::{
+    g3css_alias::G3cssAlias, g3css_children::G3cssChildren, g3css_class::G3cssClass,
+    g3css_elements::G3cssElements, g3css_node::G3cssNode, g3css_panoramic::G3cssPanoramic,
+    g3css_theme::G3cssTheme, g3css_variable::G3cssVariable,
+};
+
+/// Visits the nodes of an AST, from the scope down to the elements of every block.
+///
+/// Every method walks into the children of its node by default, so a pass only overrides
+/// the methods of the nodes it cares about. An overriding method calls the matching `walk_`
+/// function to keep walking into the children, or returns to skip them.
+///
+/// The `'ast` lifetime lets a visitor keep references to the nodes it visits.
+pub trait G3cssVisitor<'ast> {
+    /// Visits a scope or one of its parts.
+    fn visit_node(&mut self, node: &'ast G3cssNode) {
+        walk_node(self, node);
+    }
+
+    /// Visits the name of the scope.
+    fn visit_scope_name(&mut self, _name: &'ast str) {}
+
+    /// Visits the name of the global a component extends.
+    fn visit_extends(&mut self, _extends: &'ast str) {}
+
+    /// Visits an element of the scope: aliases, variables, a theme, a class or classes.
+    fn visit_children(&mut self, children: &'ast G3cssChildren) {
+        walk_children(self, children);
+    }
+
+    /// Visits an alias.
+    fn visit_alias(&mut self, _alias: &'ast G3cssAlias) {}
+
+    /// Visits the variables block of a light or dark theme.
+    fn visit_theme(&mut self, theme: &'ast G3cssTheme) {
+        walk_theme(self, theme);
+    }
+
+    /// Visits a variable, of the scope or of a theme.
+    fn visit_variable(&mut self, _variable: &'ast G3cssVariable) {}
+
+    /// Visits a class, declared by `define class` or inside `define classes`.
+    fn visit_class(&mut self, class: &'ast [G3cssClass]) {
+        walk_class(self, class);
+    }
+
+    /// Visits a node of a class or of a breakpoint.
+    fn visit_class_node(&mut self, node: &'ast G3cssClass) {
+        walk_class_node(self, node);
+    }
+
+    /// Visits a `panoramic_viewer` breakpoint.
+    fn visit_breakpoint(&mut self, breakpoint: &'ast [G3cssPanoramic]) {
+        walk_breakpoint(self, breakpoint);
+    }
+
+    /// Visits the name of a breakpoint (e.g. `mobile`).
+    fn visit_breakpoint_name(&mut self, _name: &'ast str) {}
+
+    /// Visits a properties or pseudo block, named by its G3CSS keyword (e.g. `hover`).
+    fn visit_block(&mut self, keyword: &'static str, elements: &'ast [G3cssElements]) {
+        walk_block(self, keyword, elements);
+    }
+
+    /// Visits a property or a nickname.
+    fn visit_element(&mut self, _element: &'ast G3cssElements) {}
+}
+
+/// Visits the nodes of an AST mutably, like `G3cssVisitor`.
+///
+/// Lists are handed over as vectors, so a pass can insert or remove entries before
+/// walking into them.
+pub trait G3cssVisitorMut {
+    /// Visits a scope or one of its parts.
+    fn visit_node_mut(&mut self, node: &mut G3cssNode) {
+        walk_node_mut(self, node);
+    }
+
+    /// Visits the name of the scope.
+    fn visit_scope_name_mut(&mut self, _name: &mut String) {}
+
+    /// Visits the name of the global a component extends.
+    fn visit_extends_mut(&mut self, _extends: &mut String) {}
+
+    /// Visits an element of the scope: aliases, variables, a theme, a class or classes.
+    fn visit_children_mut(&mut self, children: &mut G3cssChildren) {
+        walk_children_mut(self, children);
+    }
+
+    /// Visits an alias.
+    fn visit_alias_mut(&mut self, _alias: &mut G3cssAlias) {}
+
+    /// Visits the variables block of a light or dark theme.
+    fn visit_theme_mut(&mut self, theme: &mut G3cssTheme) {
+        walk_theme_mut(self, theme);
+    }
+
+    /// Visits a variable, of the scope or of a theme.
+    fn visit_variable_mut(&mut self, _variable: &mut G3cssVariable) {}
+
+    /// Visits a class, declared by `define class` or inside `define classes`.
+    fn visit_class_mut(&mut self, class: &mut Vec<G3cssClass>) {
+        walk_class_mut(self, class);
+    }
+
+    /// Visits a node of a class or of a breakpoint.
+    fn visit_class_node_mut(&mut self, node: &mut G3cssClass) {
+        walk_class_node_mut(self, node);
+    }
+
+    /// Visits a `panoramic_viewer` breakpoint.
+    fn visit_breakpoint_mut(&mut self, breakpoint: &mut Vec<G3cssPanoramic>) {
+        walk_breakpoint_mut(self, breakpoint);
+    }
+
+    /// Visits the name of a breakpoint (e.g. `mobile`).
+    fn visit_breakpoint_name_mut(&mut self, _name: &mut String) {}
+
+    /// Visits a properties or pseudo block, named by its G3CSS keyword (e.g. `hover`).
+    fn visit_block_mut(&mut self, keyword: &'static str, elements: &mut Vec<G3cssElements>) {
+        walk_block_mut(self, keyword, elements);
+    }
+
+    /// Visits a property or a nickname.
+    fn visit_element_mut(&mut self, _element: &mut G3cssElements) {}
+}
+
+/// Walks into the parts of a scope, or into the name, extended global or children it is.
+pub fn walk_node<'ast, V: G3cssVisitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast G3cssNode) {
+    match node {
+        G3cssNode::Global(nodes) | G3cssNode::Component(nodes) => {
+            for node in nodes {
+                visitor.visit_node(node);
+            }
+        }
+        G3cssNode::Name(name) => visitor.visit_scope_name(name),
+        G3cssNode::Extends(extends) => visitor.visit_extends(extends),
+        G3cssNode::Children(children) => {
+            for child in children {
+                visitor.visit_children(child);
+            }
+        }
+        G3cssNode::Unit => (),
+    }
+}
+
+/// Walks into the aliases, variables, themes or classes of an element of the scope.
+pub fn walk_children<'ast, V: G3cssVisitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    children: &'ast G3cssChildren,
+) {
+    match children {
+        G3cssChildren::Aliases(aliases) => {
+            for alias in aliases {
+                visitor.visit_alias(alias);
+            }
+        }
+        G3cssChildren::Variables(variables) => {
+            for variable in variables {
+                visitor.visit_variable(variable);
+            }
+        }
+        G3cssChildren::LightTheme(themes) | G3cssChildren::DarkTheme(themes) => {
+            for theme in themes {
+                visitor.visit_theme(theme);
+            }
+        }
+        G3cssChildren::Class(class) => visitor.visit_class(class),
+        G3cssChildren::Classes(classes) => {
+            for class in classes {
+                visitor.visit_class(class);
+            }
+        }
+    }
+}
+
+/// Walks into the variables of a theme.
+pub fn walk_theme<'ast, V: G3cssVisitor<'ast> + ?Sized>(visitor: &mut V, theme: &'ast G3cssTheme) {
+    let G3cssTheme::Variables(variables) = theme;
+
+    for variable in variables {
+        visitor.visit_variable(variable);
+    }
+}
+
+/// Walks into the nodes of a class.
+pub fn walk_class<'ast, V: G3cssVisitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    class: &'ast [G3cssClass],
+) {
+    for node in class {
+        visitor.visit_class_node(node);
+    }
+}
+
+/// Walks into the breakpoints of a `panoramic_viewer` or into a properties or pseudo block.
+pub fn walk_class_node<'ast, V: G3cssVisitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast G3cssClass,
+) {
+    match node {
+        G3cssClass::PanoramicViewer(breakpoints) => {
+            for breakpoint in breakpoints {
+                visitor.visit_breakpoint(breakpoint);
+            }
+        }
+        node => {
+            if let Some((keyword, elements)) = node.block() {
+                visitor.visit_block(keyword, elements);
+            }
+        }
+    }
+}
+
+/// Walks into the name and the nodes of a breakpoint.
+pub fn walk_breakpoint<'ast, V: G3cssVisitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    breakpoint: &'ast [G3cssPanoramic],
+) {
+    for panoramic in breakpoint {
+        match panoramic {
+            G3cssPanoramic::Breakpoint(name) => visitor.visit_breakpoint_name(name),
+            G3cssPanoramic::Children(children) => {
+                for node in children {
+                    visitor.visit_class_node(node);
+                }
+            }
+        }
+    }
+}
+
+/// Walks into the elements of a block.
+pub fn walk_block<'ast, V: G3cssVisitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    _keyword: &'static str,
+    elements: &'ast [G3cssElements],
+) {
+    for element in elements {
+        visitor.visit_element(element);
+    }
+}
+
+/// Walks mutably into the parts of a scope, like `walk_node`.
+pub fn walk_node_mut<V: G3cssVisitorMut + ?Sized>(visitor: &mut V, node: &mut G3cssNode) {
+    match node {
+        G3cssNode::Global(nodes) | G3cssNode::Component(nodes) => {
+            for node in nodes {
+                visitor.visit_node_mut(node);
+            }
+        }
+        G3cssNode::Name(name) => visitor.visit_scope_name_mut(name),
+        G3cssNode::Extends(extends) => visitor.visit_extends_mut(extends),
+        G3cssNode::Children(children) => {
+            for child in children {
+                visitor.visit_children_mut(child);
+            }
+        }
+        G3cssNode::Unit => (),
+    }
+}
+
+/// Walks mutably into an element of the scope, like `walk_children`.
+pub fn walk_children_mut<V: G3cssVisitorMut + ?Sized>(
+    visitor: &mut V,
+    children: &mut G3cssChildren,
+) {
+    match children {
+        G3cssChildren::Aliases(aliases) => {
+            for alias in aliases {
+                visitor.visit_alias_mut(alias);
+            }
+        }
+        G3cssChildren::Variables(variables) => {
+            for variable in variables {
+                visitor.visit_variable_mut(variable);
+            }
+        }
+        G3cssChildren::LightTheme(themes) | G3cssChildren::DarkTheme(themes) => {
+            for theme in themes {
+                visitor.visit_theme_mut(theme);
+            }
+        }
+        G3cssChildren::Class(class) => visitor.visit_class_mut(class),
+        G3cssChildren::Classes(classes) => {
+            for class in classes {
+                visitor.visit_class_mut(class);
+            }
+        }
+    }
+}
+
+/// Walks mutably into the variables of a theme, like `walk_theme`.
+pub fn walk_theme_mut<V: G3cssVisitorMut + ?Sized>(visitor: &mut V, theme: &mut G3cssTheme) {
+    let G3cssTheme::Variables(variables) = theme;
+
+    for variable in variables {
+        visitor.visit_variable_mut(variable);
+    }
+}
+
+/// Walks mutably into the nodes of a class, like `walk_class`.
+pub fn walk_class_mut<V: G3cssVisitorMut + ?Sized>(visitor: &mut V, class: &mut [G3cssClass]) {
+    for node in class {
+        visitor.visit_class_node_mut(node);
+    }
+}
+
+/// Walks mutably into a node of a class, like `walk_class_node`.
+pub fn walk_class_node_mut<V: G3cssVisitorMut + ?Sized>(visitor: &mut V, node: &mut G3cssClass) {
+    match node {
+        G3cssClass::PanoramicViewer(breakpoints) => {
+            for breakpoint in breakpoints {
+                visitor.visit_breakpoint_mut(breakpoint);
+            }
+        }
+        node => {
+            if let Some((keyword, elements)) = node.block_mut() {
+                visitor.visit_block_mut(keyword, elements);
+            }
+        }
+    }
+}
+
+/// Walks mutably into the name and the nodes of a breakpoint, like `walk_breakpoint`.
+pub fn walk_breakpoint_mut<V: G3cssVisitorMut + ?Sized>(
+    visitor: &mut V,
+    breakpoint: &mut [G3cssPanoramic],
+) {
+    for panoramic in breakpoint {
+        match panoramic {
+            G3cssPanoramic::Breakpoint(name) => visitor.visit_breakpoint_name_mut(name),
+            G3cssPanoramic::Children(children) => {
+                for node in children {
+                    visitor.visit_class_node_mut(node);
+                }
+            }
+        }
+    }
+}
+
+/// Walks mutably into the elements of a block, like `walk_block`.
+pub fn walk_block_mut<V: G3cssVisitorMut + ?Sized>(
+    visitor: &mut V,
+    _keyword: &'static str,
+    elements: &mut [G3cssElements],
+) {
+    for element in elements {
+        visitor.visit_element_mut(element);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use g3css_parser::g3css_parse_source;
+
+    use super::*;
+
+    const LAYOUT: &str = include_str!("../../../g3css-parser/tests/samples/layout.g3css");
+
+    /// Collects the names of the breakpoints of an AST.
+    struct BreakpointNames<'ast> {
+        names: Vec<&'ast str>,
+    }
+
+    impl<'ast> G3cssVisitor<'ast> for BreakpointNames<'ast> {
+        fn visit_breakpoint_name(&mut self, name: &'ast str) {
+            self.names.push(name);
+        }
+    }
+
+    /// Renames a breakpoint and drops the nicknames of every block.
+    struct Rewriter {
+        from: &'static str,
+        to: &'static str,
+    }
+
+    impl G3cssVisitorMut for Rewriter {
+        fn visit_breakpoint_name_mut(&mut self, name: &mut String) {
+            if name == self.from {
+                *name = self.to.to_string();
+            }
+        }
+
+        fn visit_block_mut(&mut self, keyword: &'static str, elements: &mut Vec<G3cssElements>) {
+            elements.retain(|element| !matches!(element, G3cssElements::Nickname(_)));
+            walk_block_mut(self, keyword, elements);
+        }
+    }
+
+    /// Collects the breakpoint names of an AST.
+    fn breakpoint_names(ast: &G3cssNode) -> Vec<&str> {
+        let mut visitor = BreakpointNames { names: vec![] };
+        visitor.visit_node(ast);
+        visitor.names
+    }
+
+    #[test]
+    fn breakpoint_names_are_visited() {
+        let ast = g3css_parse_source(LAYOUT).unwrap();
+
+        assert_eq!(breakpoint_names(&ast), ["mobile"]);
+    }
+
+    #[test]
+    fn mutable_visitors_rewrite_the_ast() {
+        let mut ast = G3cssNode::clone(&g3css_parse_source(LAYOUT).unwrap());
+        let mut rewriter = Rewriter {
+            from: "mobile",
+            to: "tablet",
+        };
+
+        assert!(format!("{:?}", ast).contains("Nickname"));
+        rewriter.visit_node_mut(&mut ast);
+
+        assert_eq!(breakpoint_names(&ast), ["tablet"]);
+        assert!(!format!("{:?}", ast).contains("Nickname"));
+    }
+}
@@ -0,0 +1,203 @@
+use g3css_parser::{g3css_parse_pairs, types::g3css_error::G3cssError, Rule};
+use pest::iterators::Pair;
+use rowan::{GreenNodeBuilder, Language};
+
+use crate::types::{
+    g3css_syntax::{G3cssLanguage, G3cssSyntaxKind, G3cssSyntaxNode, G3cssSyntaxToken},
+    g3css_token::G3cssToken,
+};
+
+use super::g3css_lexer::tokenize;
+
+/// Builds the green tree of a source from its grammar pairs and its lexer tokens.
+struct CstBuilder<'a> {
+    source: &'a str,
+    tokens: Vec<G3cssToken>,
+    /// Index of the token holding `offset`.
+    position: usize,
+    /// Byte offset up to which the source has been added to the tree.
+    offset: usize,
+    green: GreenNodeBuilder<'static>,
+}
+
+impl CstBuilder<'_> {
+    /// Adds the tokens of the source up to `end` to the current node. A token crossing `end`
+    /// is split, so nodes always start and end where their rule does.
+    fn tokens_until(&mut self, end: usize) {
+        while self.offset < end {
+            let token = self.tokens[self.position];
+            let piece_end = token.end.min(end);
+
+            self.green.token(
+                G3cssLanguage::kind_to_raw(token.kind),
+                &self.source[self.offset..piece_end],
+            );
+            self.offset = piece_end;
+
+            if self.offset == token.end {
+                self.position += 1;
+            }
+        }
+    }
+
+    /// Finds where the property or `!important` before the value starting at `start` begins,
+    /// as the grammar leaves the keyword and colon out of the rule of the value.
+    fn keyword_start(&self, start: usize) -> usize {
+        let before = self.tokens[self.position..]
+            .iter()
+            .take_while(|token| token.end <= start)
+            .filter(|token| !token.is_trivia())
+            .collect::<Vec<_>>();
+        let mut meaningful = before.iter().rev();
+
+        match (meaningful.next(), meaningful.next()) {
+            (Some(colon), Some(keyword))
+                if colon.kind == G3cssSyntaxKind::Colon
+                    && keyword.kind == G3cssSyntaxKind::Identifier =>
+            {
+                keyword.start.max(self.offset)
+            }
+            _ => start,
+        }
+    }
+
+    /// Adds the node of a pair, its children and the tokens between them.
+    fn node(&mut self, pair: Pair<Rule>, parent: G3cssSyntaxKind) {
+        let kind = match pair.as_rule() {
+            Rule::global => G3cssSyntaxKind::Global,
+            Rule::component => G3cssSyntaxKind::Component,
+            Rule::name => G3cssSyntaxKind::Name,
+            Rule::extends => G3cssSyntaxKind::Extends,
+            Rule::global_children | Rule::component_children => G3cssSyntaxKind::ScopeChildren,
+            Rule::aliases => G3cssSyntaxKind::Aliases,
+            Rule::alias => G3cssSyntaxKind::Alias,
+            Rule::variables => G3cssSyntaxKind::Variables,
+            Rule::variable => G3cssSyntaxKind::Variable,
+            Rule::light_theme => G3cssSyntaxKind::LightTheme,
+            Rule::dark_theme => G3cssSyntaxKind::DarkTheme,
+            Rule::classes => G3cssSyntaxKind::Classes,
+            Rule::class | Rule::inner_classes => G3cssSyntaxKind::Class,
+            Rule::class_name => G3cssSyntaxKind::ClassName,
+            Rule::inherits => G3cssSyntaxKind::Inherits,
+            Rule::important => G3cssSyntaxKind::Important,
+            Rule::panoramic_viewer => G3cssSyntaxKind::PanoramicViewer,
+            Rule::breakpoint => G3cssSyntaxKind::Breakpoint,
+            Rule::nickname => G3cssSyntaxKind::Nickname,
+            Rule::leading | Rule::primary | Rule::prime => G3cssSyntaxKind::Key,
+            Rule::importance | Rule::worth | Rule::valuation => G3cssSyntaxKind::Value,
+            Rule::EOI => return,
+            // The remaining rules are the properties of blocks and the blocks themselves.
+            _ if parent == G3cssSyntaxKind::Block => G3cssSyntaxKind::Declaration,
+            _ => G3cssSyntaxKind::Block,
+        };
+
+        let span = pair.as_span();
+
+        // Declarations and `!important` also hold their keyword and colon, their rule only
+        // holding the value.
+        if let G3cssSyntaxKind::Declaration | G3cssSyntaxKind::Important = kind {
+            self.tokens_until(self.keyword_start(span.start()));
+            self.green.start_node(G3cssLanguage::kind_to_raw(kind));
+            self.tokens_until(span.start());
+            self.green
+                .start_node(G3cssLanguage::kind_to_raw(G3cssSyntaxKind::Value));
+            self.tokens_until(span.end());
+            self.green.finish_node();
+            self.green.finish_node();
+            return;
+        }
+
+        self.tokens_until(span.start());
+        self.green.start_node(G3cssLanguage::kind_to_raw(kind));
+
+        for inner_pair in pair.into_inner() {
+            self.node(inner_pair, kind);
+        }
+
+        self.tokens_until(span.end());
+        self.green.finish_node();
+    }
+}
+
+/// Parses G3CSS source into a lossless concrete syntax tree (CST).
+///
+/// Unlike the AST, the CST keeps every token of the source, including whitespace and
+/// comments, so the text of its root is the source itself. Nodes follow the rules of the
+/// grammar, and the views of `syntax_view` read them.
+///
+/// # Arguments
+///
+/// - `source` - The G3CSS source to parse.
+///
+/// # Returns
+///
+/// - `Result<G3cssSyntaxNode, G3cssError>` - Ok containing the `Root` node of the tree, or
+///   the parse error of the source.
+pub fn parse_cst(source: &str) -> Result<G3cssSyntaxNode, G3cssError> {
+    let pairs = g3css_parse_pairs(source)?;
+
+    let mut builder = CstBuilder {
+        source,
+        tokens: tokenize(source),
+        position: 0,
+        offset: 0,
+        green: GreenNodeBuilder::new(),
+    };
+
+    builder
+        .green
+        .start_node(G3cssLanguage::kind_to_raw(G3cssSyntaxKind::Root));
+
+    for pair in pairs {
+        builder.node(pair, G3cssSyntaxKind::Root);
+    }
+
+    builder.tokens_until(source.len());
+    builder.green.finish_node();
+
+    Ok(G3cssSyntaxNode::new_root(builder.green.finish()))
+}
+
+/// Builds the concrete syntax tree (CST) of a source, even while the source does not parse.
+///
+/// A source that parses gets the tree of `parse_cst`. The tokens of any other source are kept
+/// directly under the `Root` node, without the nodes of the grammar, so tools working on
+/// sources being edited (such as the language server) still read them from a CST.
+///
+/// # Arguments
+///
+/// - `source` - The G3CSS source.
+///
+/// # Returns
+///
+/// The `Root` node of the tree, whose text is the source.
+pub fn syntax_tree(source: &str) -> G3cssSyntaxNode {
+    if let Ok(root) = parse_cst(source) {
+        return root;
+    }
+
+    let mut green = GreenNodeBuilder::new();
+    green.start_node(G3cssLanguage::kind_to_raw(G3cssSyntaxKind::Root));
+
+    for token in tokenize(source) {
+        green.token(G3cssLanguage::kind_to_raw(token.kind), token.text(source));
+    }
+
+    green.finish_node();
+
+    G3cssSyntaxNode::new_root(green.finish())
+}
+
+/// Collects the tokens of a node of the concrete syntax tree (CST), in source order.
+///
+/// # Arguments
+///
+/// - `node` - The node, usually the `Root` of a tree.
+///
+/// # Returns
+///
+/// Every token of the node, including whitespace and comments.
+pub fn syntax_tokens(node: &G3cssSyntaxNode) -> impl Iterator<Item = G3cssSyntaxToken> {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+}
@@ -0,0 +1,82 @@
+use crate::types::g3css_syntax::{G3cssSyntaxKind, G3cssSyntaxNode, G3cssSyntaxToken};
+
+/// Reads the doc comment from the trivia before the declaration starting with a token,
+/// walked backwards.
+///
+/// The doc comment is the run of `///` line comments directly above the declaration, each on
+/// its own line. A blank line, a plain comment or any other token ends it.
+fn collect_doc(first: &G3cssSyntaxToken) -> Option<String> {
+    let mut lines = vec![];
+    // Whether the last comment read has not been found to start its line yet.
+    let mut trailing = false;
+
+    for token in std::iter::successors(first.prev_token(), |token| token.prev_token()) {
+        let text = token.text();
+
+        match token.kind() {
+            G3cssSyntaxKind::Whitespace if text.matches('\n').count() > 1 => break,
+            G3cssSyntaxKind::Whitespace => trailing &= !text.contains('\n'),
+            G3cssSyntaxKind::LineComment
+                if text.starts_with("///") && !text.starts_with("////") =>
+            {
+                let line = &text[3..];
+                lines.push(
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string(),
+                );
+                trailing = true;
+            }
+            _ => {
+                // A comment following other code on its line documents that code instead.
+                if trailing {
+                    lines.pop();
+                }
+                break;
+            }
+        }
+    }
+
+    lines.reverse();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Finds the doc comment of the declaration starting at a byte offset of a source.
+///
+/// A doc comment is the run of `///` line comments right above a class, alias or variable,
+/// without blank lines in between. The `///` and the space following it are stripped from
+/// every line.
+///
+/// # Arguments
+///
+/// - `root` - The `Root` node of the concrete syntax tree (CST) of the source.
+/// - `start` - The byte offset where the declaration starts (e.g. the `range` of a symbol).
+///
+/// # Returns
+///
+/// - `Option<String>` - The lines of the doc comment joined by newlines, or None if the
+///   declaration is undocumented.
+pub fn doc_comment(root: &G3cssSyntaxNode, start: usize) -> Option<String> {
+    let start = u32::try_from(start).ok()?;
+
+    if start >= u32::from(root.text_range().end()) {
+        return None;
+    }
+
+    collect_doc(&root.token_at_offset(start.into()).right_biased()?)
+}
+
+/// Finds the doc comment of a node of the concrete syntax tree (CST), like `doc_comment`.
+///
+/// # Arguments
+///
+/// - `node` - The `Class`, `Alias` or `Variable` node.
+///
+/// # Returns
+///
+/// - `Option<String>` - The lines of the doc comment joined by newlines, or None if the node
+///   is undocumented.
+pub fn syntax_doc_comment(node: &G3cssSyntaxNode) -> Option<String> {
+    collect_doc(&node.first_token()?)
+}
@@ -0,0 +1,427 @@
+use g3css_parser::{
+    g3css_parse_source,
+    types::{g3css_children::G3cssChildren, g3css_error::G3cssError, g3css_node::G3cssNode},
+};
+
+use crate::types::g3css_syntax::{G3cssSyntaxKind, G3cssSyntaxToken};
+
+use super::cst_builder::{parse_cst, syntax_tokens};
+
+/// Indentation of one nesting level.
+const INDENT: &str = "    ";
+
+/// Represents a piece of an item: a token, a comment written inside the item, or a group.
+enum Piece {
+    Token(String),
+    Comment(String),
+    Group(Group),
+}
+
+/// Represents an entry of a comma separated list, with the comments around it.
+#[derive(Default)]
+struct Item {
+    /// Comments on their own lines before the item.
+    leading: Vec<String>,
+    /// Tokens, comments and groups of the item.
+    pieces: Vec<Piece>,
+    /// Comment on the same line after the item.
+    trailing: Option<String>,
+    /// Whether the item was separated from the previous one by a blank line.
+    blank_before: bool,
+    /// Kind of the CST node the first token of the item belongs to (e.g. `Aliases`).
+    node: Option<G3cssSyntaxKind>,
+}
+
+/// Represents a `{ ... }` or `[ ... ]` group and its items.
+struct Group {
+    open: &'static str,
+    close: &'static str,
+    items: Vec<Item>,
+    /// Comments written after the last item.
+    dangling: Vec<String>,
+}
+
+/// Reads the tokens of a concrete syntax tree (CST) into items, comments and groups.
+struct TreeBuilder {
+    tokens: Vec<G3cssSyntaxToken>,
+    position: usize,
+}
+
+impl TreeBuilder {
+    /// Reads the items of a group, up to its closing token or the end of the source.
+    fn items(&mut self) -> (Vec<Item>, Vec<String>) {
+        let mut items: Vec<Item> = vec![];
+        let mut current = Item::default();
+        // Comments met after the first token of the current item, with whether each was on
+        // the line of the token before it. They belong to the item only if it continues.
+        let mut pending: Vec<(String, bool)> = vec![];
+        // Newlines read since the last meaningful token.
+        let mut newlines = 0;
+        // Whether the last meaningful token ended an item.
+        let mut after_item = false;
+
+        // Ends the current item, handing the comments after it to the next one.
+        let finish =
+            |items: &mut Vec<Item>, current: &mut Item, pending: &mut Vec<(String, bool)>| {
+                let mut item = std::mem::take(current);
+                let mut comments = std::mem::take(pending).into_iter().peekable();
+
+                if let Some((comment, _)) = comments.next_if(|(_, same_line)| *same_line) {
+                    item.trailing = Some(comment);
+                }
+
+                current.leading = comments.map(|(comment, _)| comment).collect();
+                items.push(item);
+            };
+
+        while let Some(token) = self.tokens.get(self.position).cloned() {
+            self.position += 1;
+            let text = token.text();
+
+            if token.kind() == G3cssSyntaxKind::Whitespace {
+                newlines += text.matches('\n').count();
+                continue;
+            }
+
+            if current.pieces.is_empty() && current.leading.is_empty() {
+                current.blank_before = newlines > 1;
+            }
+
+            match token.kind() {
+                G3cssSyntaxKind::LineComment | G3cssSyntaxKind::BlockComment => {
+                    let comment = text.trim_end().to_string();
+
+                    match items.last_mut() {
+                        // A comment on the line of the previous item belongs to it.
+                        Some(previous) if after_item && newlines == 0 => {
+                            previous.trailing = Some(comment);
+                        }
+                        _ if current.pieces.is_empty() => current.leading.push(comment),
+                        _ => pending.push((comment, newlines == 0)),
+                    }
+                }
+                G3cssSyntaxKind::Comma => {
+                    // Items ending with a group are complete before their comma.
+                    if !current.pieces.is_empty() {
+                        finish(&mut items, &mut current, &mut pending);
+                    }
+
+                    after_item = true;
+                    newlines = 0;
+                    continue;
+                }
+                G3cssSyntaxKind::RightBrace | G3cssSyntaxKind::RightBracket => break,
+                G3cssSyntaxKind::LeftBrace | G3cssSyntaxKind::LeftBracket => {
+                    let (open, close) = match token.kind() {
+                        G3cssSyntaxKind::LeftBrace => ("{", "}"),
+                        _ => ("[", "]"),
+                    };
+
+                    current.pieces.extend(
+                        pending
+                            .drain(..)
+                            .map(|(comment, _)| Piece::Comment(comment)),
+                    );
+
+                    let (group_items, dangling) = self.items();
+
+                    current.pieces.push(Piece::Group(Group {
+                        open,
+                        close,
+                        items: group_items,
+                        dangling,
+                    }));
+
+                    // Nothing but a comma follows a group in G3CSS, so the group ends its item.
+                    finish(&mut items, &mut current, &mut pending);
+                    after_item = true;
+                    newlines = 0;
+                    continue;
+                }
+                _ => {
+                    current.pieces.extend(
+                        pending
+                            .drain(..)
+                            .map(|(comment, _)| Piece::Comment(comment)),
+                    );
+
+                    if current.node.is_none() {
+                        current.node = token.parent().map(|parent| parent.kind());
+                    }
+
+                    // Strings keep their quotation marks: the parser only strips double
+                    // quotes, so any other kind is part of the value.
+                    current.pieces.push(Piece::Token(text.to_string()));
+                }
+            }
+
+            after_item = false;
+            newlines = 0;
+        }
+
+        if !current.pieces.is_empty() {
+            finish(&mut items, &mut current, &mut pending);
+        }
+
+        // Comments after the last item stay before the closing token.
+        (items, current.leading)
+    }
+}
+
+/// Ranks an element of a scope body by the CST node it starts: aliases, variables, the
+/// light theme, the dark theme, then classes, which keep their order since it decides the
+/// cascade.
+fn scope_element_rank(item: &Item) -> usize {
+    match item.node {
+        Some(G3cssSyntaxKind::Aliases) => 0,
+        Some(G3cssSyntaxKind::Variables) => 1,
+        Some(G3cssSyntaxKind::LightTheme) => 2,
+        Some(G3cssSyntaxKind::DarkTheme) => 3,
+        _ => 4,
+    }
+}
+
+/// Ranks an element of a scope in the AST, like `scope_element_rank`.
+fn children_rank(children: &G3cssChildren) -> usize {
+    match children {
+        G3cssChildren::Aliases(_) => 0,
+        G3cssChildren::Variables(_) => 1,
+        G3cssChildren::LightTheme(_) => 2,
+        G3cssChildren::DarkTheme(_) => 3,
+        G3cssChildren::Class(_) | G3cssChildren::Classes(_) => 4,
+    }
+}
+
+/// Copies an AST with the elements of its scope in the order the formatter writes them, so
+/// the ASTs before and after formatting can be compared.
+fn scope_order(node: &G3cssNode) -> G3cssNode {
+    let order = |nodes: &[G3cssNode]| nodes.iter().map(scope_order).collect();
+
+    match node {
+        G3cssNode::Global(nodes) => G3cssNode::Global(order(nodes)),
+        G3cssNode::Component(nodes) => G3cssNode::Component(order(nodes)),
+        G3cssNode::Children(children) => {
+            let mut children = children.clone();
+            children.sort_by_key(children_rank);
+            G3cssNode::Children(children)
+        }
+        _ => node.clone(),
+    }
+}
+
+/// Writes the items of a group, one per line.
+fn write_items(output: &mut String, items: &[Item], depth: usize, separator: &str) {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 && item.blank_before {
+            output.push('\n');
+        }
+
+        for comment in &item.leading {
+            output.push_str(&INDENT.repeat(depth));
+            output.push_str(comment);
+            output.push('\n');
+        }
+
+        output.push_str(&INDENT.repeat(depth));
+        write_pieces(output, &item.pieces, depth);
+        output.push_str(separator);
+
+        if let Some(comment) = &item.trailing {
+            output.push(' ');
+            output.push_str(comment);
+        }
+
+        output.push('\n');
+    }
+}
+
+/// Writes the pieces of an item on one line, groups spreading over the following lines.
+fn write_pieces(output: &mut String, pieces: &[Piece], depth: usize) {
+    for (index, piece) in pieces.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|index| &pieces[index]);
+
+        // Colons stick to the key before them, everything else is separated by a space.
+        let needs_space = match (previous, piece) {
+            (None, _) => false,
+            (_, Piece::Token(text)) if text == ":" => false,
+            (Some(Piece::Comment(comment)), _) if comment.starts_with("//") => false,
+            _ => true,
+        };
+
+        if needs_space {
+            output.push(' ');
+        }
+
+        match piece {
+            Piece::Token(text) => output.push_str(text),
+            Piece::Comment(comment) => {
+                output.push_str(comment);
+
+                // Line comments end the line, so the item continues on the next one.
+                if comment.starts_with("//") {
+                    output.push('\n');
+                    output.push_str(&INDENT.repeat(depth + 1));
+                }
+            }
+            Piece::Group(group) => {
+                output.push_str(group.open);
+                output.push('\n');
+                write_items(output, &group.items, depth + 1, ",");
+
+                for comment in &group.dangling {
+                    output.push_str(&INDENT.repeat(depth + 1));
+                    output.push_str(comment);
+                    output.push('\n');
+                }
+
+                output.push_str(&INDENT.repeat(depth));
+                output.push_str(group.close);
+            }
+        }
+    }
+}
+
+/// Formats G3CSS source in the canonical style.
+///
+/// The formatter reads the concrete syntax tree (CST) of the source, which keeps its
+/// comments and blank lines. Every item goes on its own line, indented by four spaces per
+/// level and followed by a comma, and the elements of the scope are ordered as aliases,
+/// variables, light theme, dark theme and classes, each separated by a blank line. Comments
+/// are kept with the item they precede or follow, and single blank lines between items are
+/// kept. The formatted source is parsed back and compared with the original AST, so
+/// formatting never changes the meaning of the source.
+///
+/// # Arguments
+///
+/// - `source` - The G3CSS source to format.
+///
+/// # Returns
+///
+/// - `Result<String, G3cssError>` - Ok containing the formatted source, or the parse error
+///   of the source, which is never formatted while broken.
+pub fn format_g3css(source: &str) -> Result<String, G3cssError> {
+    let ast = g3css_parse_source(source)?;
+
+    let mut builder = TreeBuilder {
+        tokens: syntax_tokens(&parse_cst(source)?).collect(),
+        position: 0,
+    };
+    let (mut items, dangling) = builder.items();
+
+    // Order the elements of the scope, which is the group ending the top-level item.
+    for item in &mut items {
+        if let Some(Piece::Group(scope)) = item.pieces.last_mut() {
+            scope.items.sort_by_key(scope_element_rank);
+
+            for element in &mut scope.items {
+                element.blank_before = true;
+            }
+        }
+    }
+
+    let mut output = String::with_capacity(source.len());
+    write_items(&mut output, &items, 0, "");
+
+    for comment in dangling {
+        output.push_str(&comment);
+        output.push('\n');
+    }
+
+    // The formatter only moves tokens around, so anything else is a bug to report rather
+    // than source to write back.
+    match g3css_parse_source(&output) {
+        Ok(formatted) if scope_order(&formatted) == scope_order(&ast) => Ok(output),
+        Ok(_) => Err(G3cssError::OtherError(
+            "The formatter changed the meaning of the source".to_string(),
+        )),
+        Err(_) => Err(G3cssError::OtherError(
+            "The formatter produced source that does not parse".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: [&str; 4] = [
+        include_str!("../../../g3css-parser/tests/samples/app.g3css"),
+        include_str!("../../../g3css-parser/tests/samples/button.g3css"),
+        include_str!("../../../g3css-parser/tests/samples/commented.g3css"),
+        include_str!("../../../g3css-parser/tests/samples/layout.g3css"),
+    ];
+
+    #[test]
+    fn formatting_is_idempotent() {
+        for sample in SAMPLES {
+            let formatted = format_g3css(sample).unwrap();
+
+            assert_eq!(format_g3css(&formatted).unwrap(), formatted);
+        }
+    }
+
+    #[test]
+    fn comments_stay_with_their_items() {
+        let formatted = format_g3css(SAMPLES[2]).unwrap();
+
+        assert_eq!(
+            formatted,
+            r##"// Header comment
+define global scope 'APP' {
+    define aliases {
+        bg: background_color, // bg alias
+        // own-line comment
+        fg: color,
+        // dangling in aliases
+    },
+
+    /* block before variables */
+    define variables {
+        gap: "4px",
+    },
+
+    define light theme {
+        define variables {
+            primary: "#fff",
+        },
+    },
+
+    define dark theme {
+        define variables {
+            primary: "#000",
+        },
+    },
+
+    define class "BASE" {
+        properties: {
+            display: 'flex',
+            gap: "${gap}",
+        },
+        hover: {
+            opacity: "0.5",
+        },
+    }, // trailing on class
+
+    define classes [
+        "A" inherits "BASE" {
+            !important: true,
+            properties: {
+                border: “1px solid red”,
+                font_family: 'Inter',
+            },
+        },
+    ],
+}
+// end of file
+"##
+        );
+    }
+
+    #[test]
+    fn broken_source_is_not_formatted() {
+        assert!(matches!(
+            format_g3css("define global scope \"APP\" {"),
+            Err(G3cssError::ParseError(_))
+        ));
+    }
+}
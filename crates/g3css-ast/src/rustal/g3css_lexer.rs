@@ -0,0 +1,81 @@
+use pest::unicode::QUOTATION_MARK;
+
+use crate::types::{g3css_syntax::G3cssSyntaxKind, g3css_token::G3cssToken};
+
+/// Whether a character can be part of an identifier.
+fn is_identifier_char(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_'
+}
+
+/// Whether a character opens or closes a quoted value, matching the grammar's
+/// `QUOTATION_MARK` (any character of the Unicode `Quotation_Mark` property, such as `“`).
+pub fn is_quote(character: char) -> bool {
+    QUOTATION_MARK(character)
+}
+
+/// Splits G3CSS source into tokens without losing any character.
+///
+/// Unlike the parser, the lexer never fails: unterminated strings and comments run to the
+/// end of the source and unexpected characters become `Unknown` tokens, so sources being
+/// edited can still be analyzed.
+///
+/// # Arguments
+///
+/// - `source` - The G3CSS source.
+///
+/// # Returns
+///
+/// The tokens of the source, in order, including whitespace and comments.
+pub fn tokenize(source: &str) -> Vec<G3cssToken> {
+    let mut tokens = vec![];
+    let mut characters = source.char_indices().peekable();
+
+    while let Some((start, character)) = characters.next() {
+        let rest = &source[start..];
+
+        let (kind, end) = if character.is_whitespace() {
+            let length = rest
+                .find(|other: char| !other.is_whitespace())
+                .unwrap_or(rest.len());
+            (G3cssSyntaxKind::Whitespace, start + length)
+        } else if rest.starts_with("//") {
+            let length = rest.find('\n').unwrap_or(rest.len());
+            (G3cssSyntaxKind::LineComment, start + length)
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            let length = body.find("*/").map_or(rest.len(), |index| index + 4);
+            (G3cssSyntaxKind::BlockComment, start + length)
+        } else if is_quote(character) {
+            // Strings end at the next quotation mark of any kind, as in the grammar.
+            let open = character.len_utf8();
+            let length = rest[open..]
+                .char_indices()
+                .find(|(_, other)| is_quote(*other))
+                .map_or(rest.len(), |(index, close)| open + index + close.len_utf8());
+            (G3cssSyntaxKind::String, start + length)
+        } else if is_identifier_char(character) || character == '!' {
+            // `!` only starts identifiers, so `!important` is a single token.
+            let length = rest[1..]
+                .find(|other: char| !is_identifier_char(other))
+                .map_or(rest.len(), |index| index + 1);
+            (G3cssSyntaxKind::Identifier, start + length)
+        } else {
+            let kind = match character {
+                '{' => G3cssSyntaxKind::LeftBrace,
+                '}' => G3cssSyntaxKind::RightBrace,
+                '[' => G3cssSyntaxKind::LeftBracket,
+                ']' => G3cssSyntaxKind::RightBracket,
+                ':' => G3cssSyntaxKind::Colon,
+                ',' => G3cssSyntaxKind::Comma,
+                _ => G3cssSyntaxKind::Unknown,
+            };
+            (kind, start + character.len_utf8())
+        };
+
+        tokens.push(G3cssToken { kind, start, end });
+
+        // Skip the characters consumed by multi-character tokens.
+        while characters.next_if(|(index, _)| *index < end).is_some() {}
+    }
+
+    tokens
+}
@@ -0,0 +1,98 @@
+use serde::Serialize;
+
+use crate::types::{
+    dependency_graph::G3cssDependencyGraph, graph_edge::G3cssGraphEdge, graph_node::G3cssGraphNode,
+};
+
+/// Escapes a string for a double quoted DOT identifier.
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Exports the dependency graph in the Graphviz DOT format.
+///
+/// # Arguments
+///
+/// - `graph` - The graph to export.
+///
+/// # Returns
+///
+/// The DOT source of the graph, with one node per construct and one labelled edge per
+/// dependency.
+pub fn graph_to_dot(graph: &G3cssDependencyGraph) -> String {
+    let mut dot = String::from("digraph g3css {\n    rankdir=LR;\n");
+
+    for (index, node) in graph.nodes().iter().enumerate() {
+        let shape = match node {
+            G3cssGraphNode::Global { .. } | G3cssGraphNode::Component { .. } => "box",
+            G3cssGraphNode::Class { .. } => "ellipse",
+            G3cssGraphNode::Alias { .. } | G3cssGraphNode::Variable { .. } => "note",
+        };
+
+        dot.push_str(&format!(
+            "    n{} [label=\"{}\", shape={}];\n",
+            index,
+            escape_dot(&node.label()),
+            shape
+        ));
+    }
+
+    let index_of = |node: &G3cssGraphNode| graph.nodes().iter().position(|other| other == node);
+
+    for (from, to, kind) in graph.edges() {
+        if let (Some(from), Some(to)) = (index_of(from), index_of(to)) {
+            dot.push_str(&format!(
+                "    n{} -> n{} [label=\"{}\"];\n",
+                from,
+                to,
+                kind.name()
+            ));
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Edge of the JSON export, referencing nodes by index.
+#[derive(Serialize)]
+struct JsonEdge {
+    from: usize,
+    to: usize,
+    kind: G3cssGraphEdge,
+}
+
+/// Shape of the JSON export.
+#[derive(Serialize)]
+struct JsonGraph<'a> {
+    nodes: &'a [G3cssGraphNode],
+    edges: Vec<JsonEdge>,
+}
+
+/// Exports the dependency graph as JSON.
+///
+/// # Arguments
+///
+/// - `graph` - The graph to export.
+///
+/// # Returns
+///
+/// A JSON object with a `nodes` array (each node tagged by `kind`) and an `edges` array of
+/// `{ from, to, kind }` objects referencing nodes by index.
+pub fn graph_to_json(graph: &G3cssDependencyGraph) -> String {
+    let nodes = graph.nodes();
+    let index_of = |node: &G3cssGraphNode| nodes.iter().position(|other| other == node);
+
+    let edges = graph
+        .edges()
+        .filter_map(|(from, to, kind)| {
+            Some(JsonEdge {
+                from: index_of(from)?,
+                to: index_of(to)?,
+                kind,
+            })
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&JsonGraph { nodes, edges }).unwrap_or_default()
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use g3css_common::utils::node_utils::{class_name, scope_classes, scope_parts};
+use g3css_parser::types::{g3css_elements::G3cssElements, g3css_node::G3cssNode};
+
+use crate::types::{
+    dependency_graph::G3cssDependencyGraph, graph_edge::G3cssGraphEdge, graph_node::G3cssGraphNode,
+};
+
+use super::node_utils::{class_elements, class_inherits, scope_declarations, variable_references};
+
+/// Names declared by a single scope, used to resolve the references of its classes.
+struct ScopeDeclarations<'a> {
+    /// Name of the scope.
+    name: &'a str,
+    /// Name of the extended global, if any.
+    extends: Option<&'a str>,
+    /// Names of the classes declared by the scope.
+    classes: Vec<&'a str>,
+    /// Names of the aliases declared by the scope.
+    aliases: Vec<&'a str>,
+    /// Names of the variables and theme variables declared by the scope.
+    variables: Vec<&'a str>,
+}
+
+/// Finds the scope declaring a name, looking at the scope itself first and at the global it
+/// extends next. Names declared nowhere are attributed to the scope using them.
+///
+/// # Arguments
+///
+/// - `globals` - The declarations of every global, by name.
+/// - `scope` - The declarations of the scope using the name.
+/// - `declares` - Selects the declared names of a scope to search.
+/// - `name` - The name to resolve.
+///
+/// # Returns
+///
+/// The name of the scope owning `name`.
+fn resolve_owner<'a>(
+    globals: &HashMap<&str, &ScopeDeclarations<'a>>,
+    scope: &ScopeDeclarations<'a>,
+    declares: impl for<'b> Fn(&'b ScopeDeclarations<'a>) -> &'b Vec<&'a str>,
+    name: &str,
+) -> String {
+    if declares(scope).contains(&name) {
+        return scope.name.to_string();
+    }
+
+    match scope.extends.and_then(|extends| globals.get(extends)) {
+        Some(global) if declares(global).contains(&name) => global.name.to_string(),
+        _ => scope.name.to_string(),
+    }
+}
+
+/// Builds the dependency graph of a set of parsed files.
+///
+/// The graph records which component extends which global, which scope declares which
+/// class, alias and variable, which class inherits which, and which aliases (nicknames) and
+/// variables every class uses. References are resolved the way the transformer resolves
+/// them: in the scope itself first, then in the global it extends.
+///
+/// # Arguments
+///
+/// - `asts` - The root nodes of every file of the project.
+///
+/// # Returns
+///
+/// The dependency graph of the project.
+pub fn build_dependency_graph(asts: &[&G3cssNode]) -> G3cssDependencyGraph {
+    let mut graph = G3cssDependencyGraph::new();
+
+    // Gather the declarations of every scope first, so references can point to any file.
+    let scopes = asts
+        .iter()
+        .filter_map(|ast| {
+            let (name, extends, children) = scope_parts(ast)?;
+            let (aliases, variables) = scope_declarations(children);
+            let classes = scope_classes(children);
+
+            let declarations = ScopeDeclarations {
+                name,
+                extends,
+                classes: classes.iter().map(|class| class_name(class)).collect(),
+                aliases,
+                variables,
+            };
+
+            Some((*ast, declarations, classes))
+        })
+        .collect::<Vec<_>>();
+
+    let globals = scopes
+        .iter()
+        .filter(|(ast, _, _)| matches!(ast, G3cssNode::Global(_)))
+        .map(|(_, declarations, _)| (declarations.name, declarations))
+        .collect::<HashMap<_, _>>();
+
+    for (ast, scope, classes) in &scopes {
+        let scope_node = match ast {
+            G3cssNode::Global(_) => G3cssGraphNode::Global {
+                name: scope.name.to_string(),
+            },
+            _ => G3cssGraphNode::Component {
+                name: scope.name.to_string(),
+            },
+        };
+
+        graph.add_node(scope_node.clone());
+
+        if let Some(extends) = scope.extends {
+            let global = G3cssGraphNode::Global {
+                name: extends.to_string(),
+            };
+
+            graph.add_edge(scope_node.clone(), global, G3cssGraphEdge::Extends);
+        }
+
+        for alias in &scope.aliases {
+            let alias = G3cssGraphNode::Alias {
+                scope: scope.name.to_string(),
+                name: alias.to_string(),
+            };
+
+            graph.add_edge(scope_node.clone(), alias, G3cssGraphEdge::Declares);
+        }
+
+        for variable in &scope.variables {
+            let variable = G3cssGraphNode::Variable {
+                scope: scope.name.to_string(),
+                name: variable.to_string(),
+            };
+
+            graph.add_edge(scope_node.clone(), variable, G3cssGraphEdge::Declares);
+        }
+
+        for class in classes {
+            let class_node = G3cssGraphNode::Class {
+                scope: scope.name.to_string(),
+                name: class_name(class).to_string(),
+            };
+
+            graph.add_edge(
+                scope_node.clone(),
+                class_node.clone(),
+                G3cssGraphEdge::Declares,
+            );
+
+            if let Some(parent) = class_inherits(class) {
+                let parent = G3cssGraphNode::Class {
+                    scope: resolve_owner(&globals, scope, |scope| &scope.classes, parent),
+                    name: parent.to_string(),
+                };
+
+                graph.add_edge(class_node.clone(), parent, G3cssGraphEdge::Inherits);
+            }
+
+            for element in class_elements(class) {
+                // Nicknames reference an alias through their key.
+                let value = match (element.property(), element) {
+                    (Some((_, value)), _) => value,
+                    (None, G3cssElements::Nickname(nickname)) => {
+                        let [key, value] = nickname.as_slice() else {
+                            continue;
+                        };
+
+                        let alias = G3cssGraphNode::Alias {
+                            scope: resolve_owner(&globals, scope, |scope| &scope.aliases, key),
+                            name: key.to_string(),
+                        };
+
+                        graph.add_edge(class_node.clone(), alias, G3cssGraphEdge::UsesAlias);
+                        value.as_str()
+                    }
+                    _ => continue,
+                };
+
+                for name in variable_references(value) {
+                    let variable = G3cssGraphNode::Variable {
+                        scope: resolve_owner(&globals, scope, |scope| &scope.variables, name),
+                        name: name.to_string(),
+                    };
+
+                    graph.add_edge(class_node.clone(), variable, G3cssGraphEdge::UsesVariable);
+                }
+            }
+        }
+    }
+
+    graph
+}
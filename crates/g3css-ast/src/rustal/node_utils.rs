@@ -0,0 +1,93 @@
+use g3css_parser::types::{
+    g3css_alias::G3cssAlias, g3css_children::G3cssChildren, g3css_class::G3cssClass,
+    g3css_elements::G3cssElements, g3css_theme::G3cssTheme, g3css_variable::G3cssVariable,
+};
+
+use super::ast_visitor::G3cssVisitor;
+
+/// Retrieves the name of the class a class inherits, if any.
+pub fn class_inherits(class: &[G3cssClass]) -> Option<&str> {
+    class.iter().find_map(|node| match node {
+        G3cssClass::Inherits(name) => Some(name.as_str()),
+        _ => None,
+    })
+}
+
+/// Collects the elements visited by `class_elements`.
+struct ElementCollector<'ast> {
+    elements: Vec<&'ast G3cssElements>,
+}
+
+impl<'ast> G3cssVisitor<'ast> for ElementCollector<'ast> {
+    fn visit_element(&mut self, element: &'ast G3cssElements) {
+        self.elements.push(element);
+    }
+}
+
+/// Collects the elements of every block of a class, including the blocks nested in its
+/// `panoramic_viewer` breakpoints, in source order.
+pub fn class_elements(class: &[G3cssClass]) -> Vec<&G3cssElements> {
+    let mut collector = ElementCollector { elements: vec![] };
+    collector.visit_class(class);
+    collector.elements
+}
+
+/// Collects the names of every `${name}` variable reference inside a value.
+pub fn variable_references(value: &str) -> Vec<&str> {
+    let mut names = vec![];
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        let Some(length) = rest[start..].find('}') else {
+            break;
+        };
+
+        names.push(rest[start + 2..start + length].trim());
+        rest = &rest[start + length + 1..];
+    }
+
+    names
+}
+
+/// Retrieves the name of a variable from its `[key, value]` vector.
+fn variable_key(variable: &G3cssVariable) -> Option<&str> {
+    let G3cssVariable::Variable(pair) = variable;
+    pair.first().map(String::as_str)
+}
+
+/// Collects the keys of the `[key, value]` vectors of aliases, variables and themes
+/// declared directly by a scope.
+///
+/// # Returns
+///
+/// A tuple with the alias names and the variable names (including theme variables).
+pub fn scope_declarations(children: &[G3cssChildren]) -> (Vec<&str>, Vec<&str>) {
+    let mut aliases = vec![];
+    let mut variables = vec![];
+
+    for child in children {
+        match child {
+            G3cssChildren::Aliases(group) => {
+                for G3cssAlias::Alias(pair) in group {
+                    aliases.extend(pair.first().map(String::as_str));
+                }
+            }
+            G3cssChildren::Variables(group) => {
+                variables.extend(group.iter().filter_map(variable_key));
+            }
+            G3cssChildren::LightTheme(themes) | G3cssChildren::DarkTheme(themes) => {
+                for G3cssTheme::Variables(group) in themes {
+                    for name in group.iter().filter_map(variable_key) {
+                        // A variable declared by both themes is a single node.
+                        if !variables.contains(&name) {
+                            variables.push(name);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    (aliases, variables)
+}
@@ -0,0 +1,410 @@
+use g3css_common::tables::{
+    deprecated_properties::find_g3css_deprecated_property, g3css_properties::css_property_name,
+};
+use g3css_parser::types::{
+    g3css_error::G3cssError,
+    g3css_span::G3cssSpan,
+    g3css_symbol::{G3cssSymbol, G3cssSymbolKind},
+};
+use pest::error::{InputLocation, LineColLocation};
+
+use crate::{
+    rustal::{doc_comments::doc_comment, symbol_queries::variable_theme},
+    types::{
+        g3css_diagnostic::{G3cssDiagnostic, G3cssSeverity},
+        g3css_document::G3cssDocument,
+    },
+};
+
+/// Collects the names of the symbols of a kind.
+fn symbol_names(symbols: &[G3cssSymbol], kind: G3cssSymbolKind) -> Vec<&str> {
+    symbols
+        .iter()
+        .filter(|symbol| symbol.kind == kind)
+        .map(|symbol| symbol.name.as_str())
+        .collect()
+}
+
+/// Builds an error diagnostic located at a symbol.
+fn symbol_error(symbol: &G3cssSymbol, code: &'static str, message: String) -> G3cssDiagnostic {
+    G3cssDiagnostic {
+        severity: G3cssSeverity::Error,
+        code,
+        message,
+        span: symbol.span,
+    }
+}
+
+/// Builds a warning diagnostic located at a symbol.
+fn symbol_warning(symbol: &G3cssSymbol, code: &'static str, message: String) -> G3cssDiagnostic {
+    G3cssDiagnostic {
+        severity: G3cssSeverity::Warning,
+        ..symbol_error(symbol, code, message)
+    }
+}
+
+/// Builds a hint diagnostic located at a symbol.
+fn symbol_hint(symbol: &G3cssSymbol, code: &'static str, message: String) -> G3cssDiagnostic {
+    G3cssDiagnostic {
+        severity: G3cssSeverity::Hint,
+        ..symbol_error(symbol, code, message)
+    }
+}
+
+/// Follows the parents of a class until it comes back to the class or reaches a class
+/// without parent.
+///
+/// # Arguments
+///
+/// - `parents` - Every visible class with the class it inherits, if any.
+/// - `class` - The name of the class.
+///
+/// # Returns
+///
+/// The classes of the cycle, starting and ending with `class`, or `None` if the class does
+/// not inherit from itself.
+fn inheritance_cycle<'a>(
+    parents: &[(&'a str, Option<&'a str>)],
+    class: &'a str,
+) -> Option<Vec<&'a str>> {
+    let mut chain = vec![class];
+    let mut current = class;
+
+    while let Some(parent) = parents
+        .iter()
+        .find(|(name, _)| *name == current)
+        .and_then(|(_, parent)| *parent)
+    {
+        chain.push(parent);
+
+        if parent == class {
+            return Some(chain);
+        }
+
+        // A cycle further up the chain is reported on the classes taking part in it.
+        if chain[..chain.len() - 1].contains(&parent) {
+            return None;
+        }
+
+        current = parent;
+    }
+
+    None
+}
+
+/// Collects every class of the symbols along with the class it inherits.
+fn class_parents(symbols: &[G3cssSymbol]) -> Vec<(&str, Option<&str>)> {
+    symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, symbol)| symbol.kind == G3cssSymbolKind::Class)
+        .map(|(index, class)| {
+            let parent = symbols.iter().find(|symbol| {
+                symbol.kind == G3cssSymbolKind::Inherits && symbol.parent == Some(index)
+            });
+
+            (
+                class.name.as_str(),
+                parent.map(|parent| parent.name.as_str()),
+            )
+        })
+        .collect()
+}
+
+/// Finds the global named `name` among the documents of a workspace.
+///
+/// # Arguments
+///
+/// - `workspace` - Every document of the workspace.
+/// - `name` - The name of the global.
+///
+/// # Returns
+///
+/// The first document declaring a global named `name`, or `None` if there is none.
+pub fn find_global<'a>(workspace: &[&'a G3cssDocument], name: &str) -> Option<&'a G3cssDocument> {
+    workspace
+        .iter()
+        .find(|document| document.is_global() && document.scope_name() == Some(name))
+        .copied()
+}
+
+/// Converts a parse error into a diagnostic.
+///
+/// # Arguments
+///
+/// - `error` - The error returned by the parser.
+///
+/// # Returns
+///
+/// A diagnostic located where the parser stopped, or at the start of the document for
+/// errors without a location.
+pub fn error_diagnostic(error: &G3cssError) -> G3cssDiagnostic {
+    match error {
+        G3cssError::ParseError(error) => {
+            let (start, end) = match error.location {
+                InputLocation::Pos(position) => (position, position),
+                InputLocation::Span(span) => span,
+            };
+
+            let (line, column) = match error.line_col {
+                LineColLocation::Pos(position) => position,
+                LineColLocation::Span(start, _) => start,
+            };
+
+            G3cssDiagnostic {
+                severity: G3cssSeverity::Error,
+                code: "parse-error",
+                message: error.variant.message().to_string(),
+                span: G3cssSpan {
+                    start,
+                    end,
+                    line,
+                    column,
+                },
+            }
+        }
+        G3cssError::OtherError(message) => G3cssDiagnostic {
+            severity: G3cssSeverity::Error,
+            code: "error",
+            message: message.clone(),
+            span: G3cssSpan {
+                line: 1,
+                column: 1,
+                ..G3cssSpan::default()
+            },
+        },
+    }
+}
+
+/// Checks the references of a parsed document against its own declarations and the ones of
+/// the global it extends.
+///
+/// The checks report nicknames using an unknown alias, aliases pointing to an unknown
+/// property, references to unknown variables, classes inheriting an unknown class or
+/// inheriting from themselves, classes declared twice in the document, breakpoints missing
+/// from the configuration, and components extending a global that does not exist in the
+/// workspace. These are the errors that make the build fail. Deprecated
+/// properties and theme variables missing from the other theme are reported as warnings,
+/// and classes of globals without a `///` doc comment as hints.
+///
+/// # Arguments
+///
+/// - `document` - The document to check.
+/// - `workspace` - Every document of the workspace, used to find the extended global.
+/// - `breakpoints` - The names of the breakpoints available to `panoramic_viewer`.
+///
+/// # Returns
+///
+/// The semantic diagnostics of the document, in source order.
+pub fn semantic_diagnostics(
+    document: &G3cssDocument,
+    workspace: &[&G3cssDocument],
+    breakpoints: &[&str],
+) -> Vec<G3cssDiagnostic> {
+    let mut diagnostics = vec![];
+    let symbols = &document.symbols;
+
+    let global = document
+        .extends()
+        .and_then(|extends| find_global(workspace, extends));
+    let global_symbols = global.map_or(&[][..], |global| global.symbols.as_slice());
+
+    // Names visible from the document: its own declarations and its global's.
+    let visible = |kind| {
+        let mut names = symbol_names(symbols, kind);
+        names.extend(symbol_names(global_symbols, kind));
+        names
+    };
+
+    let aliases = visible(G3cssSymbolKind::Alias);
+    let variables = visible(G3cssSymbolKind::Variable);
+    let classes = visible(G3cssSymbolKind::Class);
+
+    // The document's classes come first, as they hide the global's classes of the same name.
+    let mut parents = class_parents(symbols);
+    parents.extend(class_parents(global_symbols));
+
+    // Theme parity is only checked when the document declares both themes.
+    let has_theme = |theme| {
+        symbols
+            .iter()
+            .any(|symbol| symbol.kind == G3cssSymbolKind::Theme && symbol.name == theme)
+    };
+    let check_parity = has_theme("light") && has_theme("dark");
+
+    let theme_declares = |theme, name| {
+        symbols.iter().enumerate().any(|(index, symbol)| {
+            symbol.kind == G3cssSymbolKind::Variable
+                && symbol.name == name
+                && variable_theme(symbols, index) == Some(theme)
+        })
+    };
+
+    for (index, symbol) in symbols.iter().enumerate() {
+        let name = symbol.name.as_str();
+
+        match symbol.kind {
+            G3cssSymbolKind::Extends if global.is_none() => {
+                let is_component = workspace
+                    .iter()
+                    .any(|other| !other.is_global() && other.scope_name() == Some(name));
+
+                let message = if is_component {
+                    format!("\"{}\" is a component, only globals can be extended", name)
+                } else {
+                    format!("Unknown global \"{}\"", name)
+                };
+
+                diagnostics.push(symbol_error(symbol, "unknown-global", message));
+            }
+            G3cssSymbolKind::Nickname if !aliases.contains(&name) => {
+                diagnostics.push(symbol_error(
+                    symbol,
+                    "unknown-alias",
+                    format!("Unknown alias \"{}\"", name),
+                ));
+            }
+            G3cssSymbolKind::VariableReference if !variables.contains(&name) => {
+                diagnostics.push(symbol_error(
+                    symbol,
+                    "unknown-variable",
+                    format!("Unknown variable \"{}\"", name),
+                ));
+            }
+            G3cssSymbolKind::AliasTarget if css_property_name(name).is_none() => {
+                diagnostics.push(symbol_error(
+                    symbol,
+                    "unknown-property",
+                    format!("Alias points to unknown property \"{}\"", name),
+                ));
+            }
+            G3cssSymbolKind::Inherits if !classes.contains(&name) => {
+                diagnostics.push(symbol_error(
+                    symbol,
+                    "missing-parent",
+                    format!("Class \"{}\" inherited here does not exist", name),
+                ));
+            }
+            G3cssSymbolKind::Inherits => {
+                let Some(class) = symbol.parent.map(|parent| symbols[parent].name.as_str()) else {
+                    continue;
+                };
+
+                if let Some(cycle) = inheritance_cycle(&parents, class) {
+                    diagnostics.push(symbol_error(
+                        symbol,
+                        "inheritance-cycle",
+                        format!(
+                            "Class \"{}\" inherits from itself through \"{}\"",
+                            class,
+                            cycle.join("\" -> \"")
+                        ),
+                    ));
+                }
+            }
+            G3cssSymbolKind::Breakpoint if !breakpoints.contains(&name) => {
+                diagnostics.push(symbol_error(
+                    symbol,
+                    "unknown-breakpoint",
+                    format!(
+                        "Unknown breakpoint \"{}\", expected one of \"{}\"",
+                        name,
+                        breakpoints.join("\", \"")
+                    ),
+                ));
+            }
+            G3cssSymbolKind::Class => {
+                let first = symbols[..index]
+                    .iter()
+                    .find(|other| other.kind == G3cssSymbolKind::Class && other.name == name);
+
+                if let Some(first) = first {
+                    diagnostics.push(symbol_error(
+                        symbol,
+                        "duplicate-class",
+                        format!(
+                            "Class \"{}\" is already declared on line {}",
+                            name, first.span.line
+                        ),
+                    ));
+                }
+
+                // Globals are shared by every component, so their classes should be documented.
+                if document.is_global()
+                    && doc_comment(&document.syntax, symbol.range.start).is_none()
+                {
+                    diagnostics.push(symbol_hint(
+                        symbol,
+                        "undocumented-class",
+                        format!("Class \"{}\" has no `///` doc comment", name),
+                    ));
+                }
+            }
+            G3cssSymbolKind::Variable if check_parity => {
+                let Some(theme) = variable_theme(symbols, index) else {
+                    continue;
+                };
+
+                let other = match theme {
+                    "light" => "dark",
+                    _ => "light",
+                };
+
+                if !theme_declares(other, name) {
+                    diagnostics.push(symbol_warning(
+                        symbol,
+                        "theme-parity",
+                        format!(
+                            "Variable \"{}\" is declared by the {} theme but not by the {} theme",
+                            name, theme, other
+                        ),
+                    ));
+                }
+            }
+            G3cssSymbolKind::Property => {
+                let Some(deprecated) = find_g3css_deprecated_property(name) else {
+                    continue;
+                };
+
+                let message = match deprecated.replacement {
+                    Some(replacement) => format!(
+                        "Property \"{}\" is deprecated, use \"{}\" instead",
+                        name, replacement
+                    ),
+                    None => format!(
+                        "Property \"{}\" is deprecated and not supported by current browsers",
+                        name
+                    ),
+                };
+
+                diagnostics.push(symbol_warning(symbol, "deprecated-property", message));
+            }
+            _ => (),
+        }
+    }
+
+    diagnostics
+}
+
+/// Collects every diagnostic of a document: its parse error, or its semantic diagnostics
+/// when it parsed successfully.
+///
+/// # Arguments
+///
+/// - `document` - The document to check.
+/// - `workspace` - Every document of the workspace, used to find the extended global.
+/// - `breakpoints` - The names of the breakpoints available to `panoramic_viewer`.
+///
+/// # Returns
+///
+/// The diagnostics of the document.
+pub fn document_diagnostics(
+    document: &G3cssDocument,
+    workspace: &[&G3cssDocument],
+    breakpoints: &[&str],
+) -> Vec<G3cssDiagnostic> {
+    match &document.error {
+        Some(error) => vec![error_diagnostic(error)],
+        None => semantic_diagnostics(document, workspace, breakpoints),
+    }
+}
@@ -0,0 +1,108 @@
+use g3css_parser::types::g3css_symbol::{G3cssSymbol, G3cssSymbolKind};
+
+/// Finds the symbol whose name is written at an offset.
+///
+/// # Arguments
+///
+/// - `symbols` - The symbols of a document, in source order.
+/// - `offset` - The byte offset, which may sit right after the name.
+///
+/// # Returns
+///
+/// The index of the innermost symbol whose name covers the offset (e.g. a variable
+/// reference rather than the value holding it), or `None` if there is none.
+pub fn symbol_at(symbols: &[G3cssSymbol], offset: usize) -> Option<usize> {
+    symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, symbol)| symbol.span.start <= offset && offset <= symbol.span.end)
+        .min_by_key(|(_, symbol)| symbol.span.end - symbol.span.start)
+        .map(|(index, _)| index)
+}
+
+/// Finds the first child of a symbol with the given kind.
+///
+/// # Arguments
+///
+/// - `symbols` - The symbols of a document, in source order.
+/// - `parent` - The index of the parent symbol.
+/// - `kind` - The kind of the child.
+///
+/// # Returns
+///
+/// The child, or `None` if the symbol has no child of that kind.
+pub fn child_symbol(
+    symbols: &[G3cssSymbol],
+    parent: usize,
+    kind: G3cssSymbolKind,
+) -> Option<&G3cssSymbol> {
+    // Children always follow their parent.
+    symbols[parent + 1..]
+        .iter()
+        .find(|symbol| symbol.parent == Some(parent) && symbol.kind == kind)
+}
+
+/// Finds the theme declaring a variable.
+///
+/// # Arguments
+///
+/// - `symbols` - The symbols of a document, in source order.
+/// - `variable` - The index of the variable symbol.
+///
+/// # Returns
+///
+/// Either `light` or `dark`, or `None` for a plain variable.
+pub fn variable_theme(symbols: &[G3cssSymbol], variable: usize) -> Option<&str> {
+    let parent = &symbols[symbols[variable].parent?];
+
+    (parent.kind == G3cssSymbolKind::Theme).then_some(parent.name.as_str())
+}
+
+/// Finds the enclosing symbol of a given kind, such as the class holding a block.
+///
+/// # Arguments
+///
+/// - `symbols` - The symbols of a document, in source order.
+/// - `index` - The index of the symbol to start from, which is itself considered.
+/// - `kind` - The kind of the ancestor.
+///
+/// # Returns
+///
+/// The index of the closest ancestor with that kind, or `None` if there is none.
+pub fn ancestor_symbol(
+    symbols: &[G3cssSymbol],
+    index: usize,
+    kind: G3cssSymbolKind,
+) -> Option<usize> {
+    let mut current = Some(index);
+
+    while let Some(index) = current {
+        if symbols[index].kind == kind {
+            return Some(index);
+        }
+
+        current = symbols[index].parent;
+    }
+
+    None
+}
+
+/// Collects the indexes of the symbols of a kind with a given name.
+///
+/// # Arguments
+///
+/// - `symbols` - The symbols of a document, in source order.
+/// - `kind` - The kind of the symbols.
+/// - `name` - The name of the symbols.
+///
+/// # Returns
+///
+/// The matching indexes, in source order.
+pub fn named_symbols(symbols: &[G3cssSymbol], kind: G3cssSymbolKind, name: &str) -> Vec<usize> {
+    symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, symbol)| symbol.kind == kind && symbol.name == name)
+        .map(|(index, _)| index)
+        .collect()
+}
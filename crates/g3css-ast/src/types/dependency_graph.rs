@@ -0,0 +1,122 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::{graph_edge::G3cssGraphEdge, graph_node::G3cssGraphNode};
+
+/// Directed graph of the dependencies between scopes, classes, aliases and variables.
+///
+/// Every edge goes from a node to a node it depends on, so the dependents of a node are the
+/// nodes affected when it changes.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct G3cssDependencyGraph {
+    /// Every node, in insertion order.
+    nodes: Vec<G3cssGraphNode>,
+    /// Every edge as `(from, to, kind)` indexes into `nodes`.
+    edges: Vec<(usize, usize, G3cssGraphEdge)>,
+    /// Index of every node in `nodes`.
+    indexes: HashMap<G3cssGraphNode, usize>,
+}
+
+impl G3cssDependencyGraph {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node if the graph does not hold it yet.
+    ///
+    /// # Returns
+    ///
+    /// The index of the node.
+    pub fn add_node(&mut self, node: G3cssGraphNode) -> usize {
+        if let Some(index) = self.indexes.get(&node) {
+            return *index;
+        }
+
+        self.nodes.push(node.clone());
+        self.indexes.insert(node, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    /// Adds an edge from `from` to `to`, adding both nodes if needed. Duplicate edges are
+    /// ignored.
+    pub fn add_edge(&mut self, from: G3cssGraphNode, to: G3cssGraphNode, kind: G3cssGraphEdge) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        if !self.edges.contains(&(from, to, kind)) {
+            self.edges.push((from, to, kind));
+        }
+    }
+
+    /// Every node, in insertion order.
+    pub fn nodes(&self) -> &[G3cssGraphNode] {
+        &self.nodes
+    }
+
+    /// Every edge as `(from, to, kind)`.
+    pub fn edges(
+        &self,
+    ) -> impl Iterator<Item = (&G3cssGraphNode, &G3cssGraphNode, G3cssGraphEdge)> {
+        self.edges
+            .iter()
+            .map(|(from, to, kind)| (&self.nodes[*from], &self.nodes[*to], *kind))
+    }
+
+    /// Checks whether the graph holds a node.
+    pub fn contains(&self, node: &G3cssGraphNode) -> bool {
+        self.indexes.contains_key(node)
+    }
+
+    /// Walks the graph from `node` and collects every node reached.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to start from, which is not part of the result.
+    /// - `forward` - Whether edges are followed towards dependencies instead of dependents.
+    /// - `transitive` - Whether nodes reached indirectly are collected too.
+    fn walk(&self, node: &G3cssGraphNode, forward: bool, transitive: bool) -> Vec<&G3cssGraphNode> {
+        let Some(start) = self.indexes.get(node) else {
+            return vec![];
+        };
+
+        let mut reached = BTreeSet::new();
+        let mut pending = vec![*start];
+
+        while let Some(current) = pending.pop() {
+            for (from, to, _) in &self.edges {
+                let (source, target) = if forward { (from, to) } else { (to, from) };
+
+                if *source == current && *target != *start && reached.insert(*target) && transitive
+                {
+                    pending.push(*target);
+                }
+            }
+        }
+
+        reached
+            .into_iter()
+            .map(|index| &self.nodes[index])
+            .collect()
+    }
+
+    /// Collects the nodes depending directly on `node`.
+    pub fn direct_dependents(&self, node: &G3cssGraphNode) -> Vec<&G3cssGraphNode> {
+        self.walk(node, false, false)
+    }
+
+    /// Collects every node depending on `node`, directly or through other nodes. For a
+    /// global, this holds every component extending it.
+    pub fn dependents(&self, node: &G3cssGraphNode) -> Vec<&G3cssGraphNode> {
+        self.walk(node, false, true)
+    }
+
+    /// Collects the nodes `node` depends on directly.
+    pub fn direct_dependencies(&self, node: &G3cssGraphNode) -> Vec<&G3cssGraphNode> {
+        self.walk(node, true, false)
+    }
+
+    /// Collects every node `node` depends on, directly or through other nodes.
+    pub fn dependencies(&self, node: &G3cssGraphNode) -> Vec<&G3cssGraphNode> {
+        self.walk(node, true, true)
+    }
+}
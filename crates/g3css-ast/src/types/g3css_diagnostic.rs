@@ -0,0 +1,26 @@
+use g3css_parser::types::g3css_span::G3cssSpan;
+
+/// Enum representing how serious a diagnostic is.
+/// Error - the file cannot be compiled as written
+/// Warning - the file compiles but is likely wrong
+/// Information - a remark about the file
+/// Hint - a suggestion to improve the file
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum G3cssSeverity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+/// Represents a problem found in a G3CSS file.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct G3cssDiagnostic {
+    pub severity: G3cssSeverity,
+    /// Stable identifier of the kind of problem (e.g. `unknown-alias`).
+    pub code: &'static str,
+    /// Human readable description of the problem.
+    pub message: String,
+    /// Location of the problem.
+    pub span: G3cssSpan,
+}
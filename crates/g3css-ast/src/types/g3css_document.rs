@@ -0,0 +1,69 @@
+use std::rc::Rc;
+
+use g3css_common::utils::node_utils::scope_parts;
+use g3css_parser::{
+    g3css_parse_with_symbols,
+    types::{g3css_error::G3cssError, g3css_node::G3cssNode, g3css_symbol::G3cssSymbol},
+};
+
+use crate::rustal::cst_builder::syntax_tree;
+
+use super::g3css_syntax::G3cssSyntaxNode;
+
+/// Represents a G3CSS source together with everything parsed from it.
+#[derive(PartialEq, Debug, Clone)]
+pub struct G3cssDocument {
+    /// Source of the document.
+    pub source: String,
+    /// Concrete syntax tree of the document, holding its tokens without the nodes of the
+    /// grammar if it failed to parse.
+    pub syntax: G3cssSyntaxNode,
+    /// AST of the document, or `None` if it failed to parse.
+    pub ast: Option<Rc<G3cssNode>>,
+    /// Symbols of the document in source order, empty if it failed to parse.
+    pub symbols: Vec<G3cssSymbol>,
+    /// Error met while parsing the document, if any.
+    pub error: Option<G3cssError>,
+}
+
+impl G3cssDocument {
+    /// Parses a source into a document. Parse errors are kept in the document rather than
+    /// returned, so a broken document can still be reported on.
+    pub fn parse(source: String) -> Self {
+        let syntax = syntax_tree(&source);
+
+        match g3css_parse_with_symbols(&source) {
+            Ok((ast, symbols)) => Self {
+                source,
+                syntax,
+                ast: Some(ast),
+                symbols,
+                error: None,
+            },
+            Err(error) => Self {
+                source,
+                syntax,
+                ast: None,
+                symbols: vec![],
+                error: Some(error),
+            },
+        }
+    }
+
+    /// Name of the global or component declared by the document.
+    pub fn scope_name(&self) -> Option<&str> {
+        let (name, _, _) = scope_parts(self.ast.as_deref()?)?;
+        Some(name)
+    }
+
+    /// Name of the global extended by the document, if any.
+    pub fn extends(&self) -> Option<&str> {
+        let (_, extends, _) = scope_parts(self.ast.as_deref()?)?;
+        extends
+    }
+
+    /// Whether the document declares a global.
+    pub fn is_global(&self) -> bool {
+        matches!(self.ast.as_deref(), Some(G3cssNode::Global(_)))
+    }
+}
@@ -0,0 +1,145 @@
+use rowan::Language;
+
+/// Enum representing the kinds of tokens and nodes of the concrete syntax tree (CST).
+///
+/// Tokens:
+/// Whitespace - spaces, tabs and line breaks
+/// LineComment - a `// ...` comment, without its line break
+/// BlockComment - a `/* ... */` comment, possibly unterminated
+/// Identifier - a keyword, name or property key (e.g. `define`, `margin_top`, `!important`)
+/// String - a quoted value including its quotes, possibly unterminated
+/// LeftBrace, RightBrace, LeftBracket, RightBracket, Colon, Comma - punctuation
+/// Unknown - any other character
+///
+/// Nodes:
+/// Root - the whole source, including the trivia around the scope
+/// Global, Component - a `define global scope` or `define component scope`
+/// Name, Extends - the quoted name of the scope and of the global a component extends
+/// ScopeChildren - the elements of a scope
+/// Aliases, Alias - a `define aliases` block and one of its aliases
+/// Variables, Variable - a `define variables` block and one of its variables
+/// LightTheme, DarkTheme - a `define light theme` or `define dark theme` block
+/// Classes, Class - a `define classes` block and a class, inside it or on its own
+/// ClassName, Inherits - the quoted name of a class and of the class it inherits
+/// Important - an `!important: ...` flag
+/// Block - a properties or pseudo block, starting with its keyword
+/// Declaration - a property of a block, starting with the property
+/// Nickname - an aliased property of a block
+/// PanoramicViewer, Breakpoint - a `panoramic_viewer` block and one of its breakpoints
+/// Key, Value - the key and value of an alias, variable, nickname or breakpoint, and the
+/// value of a declaration or `!important`
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+#[repr(u16)]
+pub enum G3cssSyntaxKind {
+    Whitespace,
+    LineComment,
+    BlockComment,
+    Identifier,
+    String,
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Colon,
+    Comma,
+    Unknown,
+    Root,
+    Global,
+    Component,
+    Name,
+    Extends,
+    ScopeChildren,
+    Aliases,
+    Alias,
+    Variables,
+    Variable,
+    LightTheme,
+    DarkTheme,
+    Classes,
+    Class,
+    ClassName,
+    Inherits,
+    Important,
+    Block,
+    Declaration,
+    Nickname,
+    PanoramicViewer,
+    Breakpoint,
+    Key,
+    Value,
+}
+
+impl G3cssSyntaxKind {
+    /// Whether the kind is whitespace or a comment.
+    pub fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            G3cssSyntaxKind::Whitespace
+                | G3cssSyntaxKind::LineComment
+                | G3cssSyntaxKind::BlockComment
+        )
+    }
+}
+
+/// Every kind, indexed by its `u16` representation.
+const SYNTAX_KINDS: [G3cssSyntaxKind; 36] = [
+    G3cssSyntaxKind::Whitespace,
+    G3cssSyntaxKind::LineComment,
+    G3cssSyntaxKind::BlockComment,
+    G3cssSyntaxKind::Identifier,
+    G3cssSyntaxKind::String,
+    G3cssSyntaxKind::LeftBrace,
+    G3cssSyntaxKind::RightBrace,
+    G3cssSyntaxKind::LeftBracket,
+    G3cssSyntaxKind::RightBracket,
+    G3cssSyntaxKind::Colon,
+    G3cssSyntaxKind::Comma,
+    G3cssSyntaxKind::Unknown,
+    G3cssSyntaxKind::Root,
+    G3cssSyntaxKind::Global,
+    G3cssSyntaxKind::Component,
+    G3cssSyntaxKind::Name,
+    G3cssSyntaxKind::Extends,
+    G3cssSyntaxKind::ScopeChildren,
+    G3cssSyntaxKind::Aliases,
+    G3cssSyntaxKind::Alias,
+    G3cssSyntaxKind::Variables,
+    G3cssSyntaxKind::Variable,
+    G3cssSyntaxKind::LightTheme,
+    G3cssSyntaxKind::DarkTheme,
+    G3cssSyntaxKind::Classes,
+    G3cssSyntaxKind::Class,
+    G3cssSyntaxKind::ClassName,
+    G3cssSyntaxKind::Inherits,
+    G3cssSyntaxKind::Important,
+    G3cssSyntaxKind::Block,
+    G3cssSyntaxKind::Declaration,
+    G3cssSyntaxKind::Nickname,
+    G3cssSyntaxKind::PanoramicViewer,
+    G3cssSyntaxKind::Breakpoint,
+    G3cssSyntaxKind::Key,
+    G3cssSyntaxKind::Value,
+];
+
+/// The G3CSS language of the rowan trees.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum G3cssLanguage {}
+
+impl Language for G3cssLanguage {
+    type Kind = G3cssSyntaxKind;
+
+    fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+        SYNTAX_KINDS[raw.0 as usize]
+    }
+
+    fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+        rowan::SyntaxKind(kind as u16)
+    }
+}
+
+/// Node of the concrete syntax tree, knowing its parent and position in the source.
+pub type G3cssSyntaxNode = rowan::SyntaxNode<G3cssLanguage>;
+/// Token of the concrete syntax tree, knowing its parent and position in the source.
+pub type G3cssSyntaxToken = rowan::SyntaxToken<G3cssLanguage>;
+/// Node or token of the concrete syntax tree.
+pub type G3cssSyntaxElement = rowan::SyntaxElement<G3cssLanguage>;
@@ -0,0 +1,25 @@
+use super::g3css_syntax::G3cssSyntaxKind;
+
+/// Represents a token of G3CSS source, as read by the lexer before it is added to the concrete
+/// syntax tree. Concatenating the text of every token of a source gives the source back.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct G3cssToken {
+    /// Kind of the token, one of the token kinds of `G3cssSyntaxKind`.
+    pub kind: G3cssSyntaxKind,
+    /// Byte offset of the first character of the token.
+    pub start: usize,
+    /// Byte offset just past the last character of the token.
+    pub end: usize,
+}
+
+impl G3cssToken {
+    /// Text of the token within its source.
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start..self.end]
+    }
+
+    /// Whether the token carries no meaning (whitespace or comment).
+    pub fn is_trivia(&self) -> bool {
+        self.kind.is_trivia()
+    }
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+/// Enum representing why one node of the dependency graph depends on another.
+/// Extends - a component extends a global
+/// Declares - a scope declares a class, alias or variable
+/// Inherits - a class inherits another class
+/// UsesAlias - a class uses an alias as a nickname
+/// UsesVariable - a class references a variable in one of its values
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum G3cssGraphEdge {
+    Extends,
+    Declares,
+    Inherits,
+    UsesAlias,
+    UsesVariable,
+}
+
+impl G3cssGraphEdge {
+    /// Name of the edge kind as written in exports (e.g. `uses_alias`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            G3cssGraphEdge::Extends => "extends",
+            G3cssGraphEdge::Declares => "declares",
+            G3cssGraphEdge::Inherits => "inherits",
+            G3cssGraphEdge::UsesAlias => "uses_alias",
+            G3cssGraphEdge::UsesVariable => "uses_variable",
+        }
+    }
+}
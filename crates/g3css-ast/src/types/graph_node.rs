@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+/// Enum representing the constructs tracked by the dependency graph.
+/// Global - a global scope, by name
+/// Component - a component scope, by name
+/// Class - a class, by the scope declaring it and its name
+/// Alias - an alias (nickname), by the scope declaring it and its name
+/// Variable - a variable or theme variable, by the scope declaring it and its name
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum G3cssGraphNode {
+    Global { name: String },
+    Component { name: String },
+    Class { scope: String, name: String },
+    Alias { scope: String, name: String },
+    Variable { scope: String, name: String },
+}
+
+impl G3cssGraphNode {
+    /// Human readable label of the node (e.g. `class APP.BASE`).
+    pub fn label(&self) -> String {
+        match self {
+            G3cssGraphNode::Global { name } => format!("global {}", name),
+            G3cssGraphNode::Component { name } => format!("component {}", name),
+            G3cssGraphNode::Class { scope, name } => format!("class {}.{}", scope, name),
+            G3cssGraphNode::Alias { scope, name } => format!("alias {}.{}", scope, name),
+            G3cssGraphNode::Variable { scope, name } => format!("variable {}.{}", scope, name),
+        }
+    }
+}
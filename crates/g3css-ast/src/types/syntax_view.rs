@@ -0,0 +1,655 @@
+use g3css_common::tables::{
+    g3css_keywords::{find_g3css_block_keyword, G3cssBlockKeyword},
+    g3css_properties::{find_g3css_property, G3cssProperty},
+};
+use g3css_parser::rustal::utils::remove_whitespace;
+
+use crate::rustal::doc_comments::syntax_doc_comment;
+
+use super::{
+    g3css_syntax::{G3cssSyntaxKind, G3cssSyntaxNode},
+    typed_ast::ThemeKind,
+};
+
+/// Represents a typed view over a node of the concrete syntax tree (CST).
+///
+/// Every view wraps the CST node it reads, so it knows its position, its comments and the
+/// whitespace around it, and reading a part of it reads the tree. The views mirror the
+/// nodes of the typed AST of `typed_ast`.
+pub trait G3cssAstNode: Sized {
+    /// Wraps a CST node, or returns `None` if the node is of another kind.
+    fn cast(node: G3cssSyntaxNode) -> Option<Self>;
+
+    /// The wrapped CST node.
+    fn syntax(&self) -> &G3cssSyntaxNode;
+}
+
+/// Finds the first child node of a kind.
+fn child(node: &G3cssSyntaxNode, kind: G3cssSyntaxKind) -> Option<G3cssSyntaxNode> {
+    node.children().find(|child| child.kind() == kind)
+}
+
+/// Reads the text of the first child node of a kind, trimmed of its double quotes as the
+/// parser does.
+fn child_text(node: &G3cssSyntaxNode, kind: G3cssSyntaxKind) -> Option<String> {
+    child(node, kind).map(|child| child.text().to_string().trim_matches('"').to_string())
+}
+
+/// Reads the text of the first child node of a kind like the parser reads the keys and
+/// values of aliases and variables, without whitespace.
+fn child_word(node: &G3cssSyntaxNode, kind: G3cssSyntaxKind) -> String {
+    remove_whitespace(&child_text(node, kind).unwrap_or_default())
+}
+
+/// Reads the text of the first identifier token of a node, its keyword.
+fn keyword(node: &G3cssSyntaxNode) -> String {
+    node.children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| token.kind() == G3cssSyntaxKind::Identifier)
+        .map(|token| token.text().to_string())
+        .unwrap_or_default()
+}
+
+/// Wraps the children of a node that are of the kind of a typed node, in source order.
+fn children<T: G3cssAstNode>(node: &G3cssSyntaxNode) -> impl Iterator<Item = T> {
+    node.children().filter_map(T::cast)
+}
+
+/// Enum representing the root of a CST view.
+/// Global - a `define global scope` file
+/// Component - a `define component scope` file
+///
+/// It is read from the CST built by `parse_cst` or `syntax_tree`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Scope {
+    Global(Global),
+    Component(Component),
+}
+
+impl G3cssAstNode for Scope {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        match node.kind() {
+            G3cssSyntaxKind::Global => Some(Scope::Global(Global(node))),
+            G3cssSyntaxKind::Component => Some(Scope::Component(Component(node))),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        match self {
+            Scope::Global(global) => global.syntax(),
+            Scope::Component(component) => component.syntax(),
+        }
+    }
+}
+
+impl Scope {
+    /// Finds the scope of a CST.
+    ///
+    /// # Arguments
+    ///
+    /// - `root` - The `Root` node built by `parse_cst`.
+    ///
+    /// # Returns
+    ///
+    /// The global or component declared by the tree, or `None` if it declares neither, as
+    /// for the tree of a source that does not parse.
+    pub fn from_root(root: &G3cssSyntaxNode) -> Option<Self> {
+        children(root).next()
+    }
+
+    /// Name of the scope.
+    pub fn name(&self) -> String {
+        match self {
+            Scope::Global(global) => global.name(),
+            Scope::Component(component) => component.name(),
+        }
+    }
+}
+
+/// Represents a `define global scope` and its elements, in source order.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Global(G3cssSyntaxNode);
+
+impl G3cssAstNode for Global {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Global).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Global {
+    /// Name of the global.
+    pub fn name(&self) -> String {
+        child_text(&self.0, G3cssSyntaxKind::Name).unwrap_or_default()
+    }
+
+    /// Elements of the global, in source order.
+    pub fn children(&self) -> impl Iterator<Item = GlobalItem> {
+        child(&self.0, G3cssSyntaxKind::ScopeChildren)
+            .into_iter()
+            .flat_map(|children| self::children(&children))
+    }
+}
+
+/// Represents a `define component scope`, the global it extends and its elements, in source
+/// order.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Component(G3cssSyntaxNode);
+
+impl G3cssAstNode for Component {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Component).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Component {
+    /// Name of the component.
+    pub fn name(&self) -> String {
+        child_text(&self.0, G3cssSyntaxKind::Name).unwrap_or_default()
+    }
+
+    /// Name of the global the component extends, if any.
+    pub fn extends(&self) -> Option<String> {
+        child_text(&self.0, G3cssSyntaxKind::Extends)
+    }
+
+    /// Elements of the component, in source order.
+    pub fn children(&self) -> impl Iterator<Item = ScopeItem> {
+        child(&self.0, G3cssSyntaxKind::ScopeChildren)
+            .into_iter()
+            .flat_map(|children| self::children(&children))
+    }
+}
+
+/// Enum representing an element of a global scope.
+/// Item - an element any scope may hold
+/// Theme - a light or dark theme, which only globals hold
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum GlobalItem {
+    Item(ScopeItem),
+    Theme(Theme),
+}
+
+impl G3cssAstNode for GlobalItem {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        match node.kind() {
+            G3cssSyntaxKind::LightTheme | G3cssSyntaxKind::DarkTheme => {
+                Some(GlobalItem::Theme(Theme(node)))
+            }
+            _ => ScopeItem::cast(node).map(GlobalItem::Item),
+        }
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        match self {
+            GlobalItem::Item(item) => item.syntax(),
+            GlobalItem::Theme(theme) => theme.syntax(),
+        }
+    }
+}
+
+/// Enum representing an element of a global or component scope.
+/// Aliases - a `define aliases` block
+/// Variables - a `define variables` block
+/// Class - a `define class`
+/// Classes - a `define classes` block
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum ScopeItem {
+    Aliases(Aliases),
+    Variables(Variables),
+    Class(Class),
+    Classes(Classes),
+}
+
+impl G3cssAstNode for ScopeItem {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        match node.kind() {
+            G3cssSyntaxKind::Aliases => Some(ScopeItem::Aliases(Aliases(node))),
+            G3cssSyntaxKind::Variables => Some(ScopeItem::Variables(Variables(node))),
+            G3cssSyntaxKind::Class => Some(ScopeItem::Class(Class(node))),
+            G3cssSyntaxKind::Classes => Some(ScopeItem::Classes(Classes(node))),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        match self {
+            ScopeItem::Aliases(aliases) => aliases.syntax(),
+            ScopeItem::Variables(variables) => variables.syntax(),
+            ScopeItem::Class(class) => class.syntax(),
+            ScopeItem::Classes(classes) => classes.syntax(),
+        }
+    }
+}
+
+/// Represents a `define aliases` block.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Aliases(G3cssSyntaxNode);
+
+impl G3cssAstNode for Aliases {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Aliases).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Aliases {
+    /// Aliases of the block, in source order.
+    pub fn aliases(&self) -> impl Iterator<Item = Alias> {
+        children(&self.0)
+    }
+}
+
+/// Represents an alias, naming the property `value` by `key`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Alias(G3cssSyntaxNode);
+
+impl G3cssAstNode for Alias {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Alias).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Alias {
+    /// Name of the alias.
+    pub fn key(&self) -> String {
+        child_word(&self.0, G3cssSyntaxKind::Key)
+    }
+
+    /// Property named by the alias, by its G3CSS name (e.g. `background_color`).
+    pub fn value(&self) -> String {
+        child_word(&self.0, G3cssSyntaxKind::Value)
+    }
+
+    /// Doc comment of the declaration, read from the `///` lines above it.
+    pub fn doc(&self) -> Option<String> {
+        syntax_doc_comment(&self.0)
+    }
+}
+
+/// Represents a `define variables` block, of a scope or of a theme.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Variables(G3cssSyntaxNode);
+
+impl G3cssAstNode for Variables {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Variables).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Variables {
+    /// Variables of the block, in source order.
+    pub fn variables(&self) -> impl Iterator<Item = Variable> {
+        children(&self.0)
+    }
+}
+
+/// Represents a variable of a scope or of a theme.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Variable(G3cssSyntaxNode);
+
+impl G3cssAstNode for Variable {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Variable).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Variable {
+    /// Name of the variable.
+    pub fn key(&self) -> String {
+        child_word(&self.0, G3cssSyntaxKind::Key)
+    }
+
+    /// Value of the variable.
+    pub fn value(&self) -> String {
+        child_word(&self.0, G3cssSyntaxKind::Value)
+    }
+
+    /// Doc comment of the declaration, read from the `///` lines above it.
+    pub fn doc(&self) -> Option<String> {
+        syntax_doc_comment(&self.0)
+    }
+}
+
+/// Represents a light or dark theme and its variables.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Theme(G3cssSyntaxNode);
+
+impl G3cssAstNode for Theme {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        matches!(
+            node.kind(),
+            G3cssSyntaxKind::LightTheme | G3cssSyntaxKind::DarkTheme
+        )
+        .then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Theme {
+    /// Whether the theme is the light or the dark one.
+    pub fn kind(&self) -> ThemeKind {
+        match self.0.kind() {
+            G3cssSyntaxKind::LightTheme => ThemeKind::Light,
+            _ => ThemeKind::Dark,
+        }
+    }
+
+    /// Variables of the theme, in source order.
+    pub fn variables(&self) -> impl Iterator<Item = Variable> {
+        children::<Variables>(&self.0).flat_map(|variables| variables.variables())
+    }
+}
+
+/// Represents a `define classes` block.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Classes(G3cssSyntaxNode);
+
+impl G3cssAstNode for Classes {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Classes).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Classes {
+    /// Classes of the block, in source order.
+    pub fn classes(&self) -> impl Iterator<Item = Class> {
+        children(&self.0)
+    }
+}
+
+/// Represents a class, the class it inherits and its body, in source order.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Class(G3cssSyntaxNode);
+
+impl G3cssAstNode for Class {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Class).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Class {
+    /// Name of the class.
+    pub fn name(&self) -> String {
+        child_text(&self.0, G3cssSyntaxKind::ClassName).unwrap_or_default()
+    }
+
+    /// Name of the class this class inherits, if any.
+    pub fn inherits(&self) -> Option<String> {
+        child_text(&self.0, G3cssSyntaxKind::Inherits)
+    }
+
+    /// Entries of the body of the class, in source order.
+    pub fn body(&self) -> impl Iterator<Item = ClassItem> {
+        children(&self.0)
+    }
+
+    /// Doc comment of the declaration, read from the `///` lines above it.
+    pub fn doc(&self) -> Option<String> {
+        syntax_doc_comment(&self.0)
+    }
+}
+
+/// Enum representing an entry of the body of a class.
+/// Important - an `!important` flag
+/// Block - a properties or pseudo block
+/// PanoramicViewer - the breakpoints of a `panoramic_viewer`
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum ClassItem {
+    Important(Important),
+    Block(Block),
+    PanoramicViewer(PanoramicViewer),
+}
+
+impl G3cssAstNode for ClassItem {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        match node.kind() {
+            G3cssSyntaxKind::Important => Some(ClassItem::Important(Important(node))),
+            G3cssSyntaxKind::Block => Some(ClassItem::Block(Block(node))),
+            G3cssSyntaxKind::PanoramicViewer => {
+                Some(ClassItem::PanoramicViewer(PanoramicViewer(node)))
+            }
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        match self {
+            ClassItem::Important(important) => important.syntax(),
+            ClassItem::Block(block) => block.syntax(),
+            ClassItem::PanoramicViewer(viewer) => viewer.syntax(),
+        }
+    }
+}
+
+/// Represents an `!important` flag.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Important(G3cssSyntaxNode);
+
+impl G3cssAstNode for Important {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Important).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Important {
+    /// Whether the flag is set to `"true"`.
+    pub fn value(&self) -> bool {
+        child_text(&self.0, G3cssSyntaxKind::Value).as_deref() == Some("true")
+    }
+}
+
+/// Represents a `panoramic_viewer` and its breakpoints.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct PanoramicViewer(G3cssSyntaxNode);
+
+impl G3cssAstNode for PanoramicViewer {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::PanoramicViewer).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl PanoramicViewer {
+    /// Breakpoints of the `panoramic_viewer`, in source order.
+    pub fn breakpoints(&self) -> impl Iterator<Item = Breakpoint> {
+        children(&self.0)
+    }
+}
+
+/// Represents a `panoramic_viewer` breakpoint and its body, in source order.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Breakpoint(G3cssSyntaxNode);
+
+impl G3cssAstNode for Breakpoint {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Breakpoint).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Breakpoint {
+    /// Name of the breakpoint (e.g. `mobile`).
+    pub fn name(&self) -> String {
+        child_word(&self.0, G3cssSyntaxKind::Key)
+    }
+
+    /// Entries of the body of the breakpoint, in source order.
+    pub fn body(&self) -> impl Iterator<Item = BreakpointItem> {
+        children(&self.0)
+    }
+}
+
+/// Enum representing an entry of the body of a breakpoint.
+/// Important - an `!important` flag
+/// Block - a properties or pseudo block
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum BreakpointItem {
+    Important(Important),
+    Block(Block),
+}
+
+impl G3cssAstNode for BreakpointItem {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        match node.kind() {
+            G3cssSyntaxKind::Important => Some(BreakpointItem::Important(Important(node))),
+            G3cssSyntaxKind::Block => Some(BreakpointItem::Block(Block(node))),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        match self {
+            BreakpointItem::Important(important) => important.syntax(),
+            BreakpointItem::Block(block) => block.syntax(),
+        }
+    }
+}
+
+/// Represents a properties or pseudo block and its elements.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Block(G3cssSyntaxNode);
+
+impl G3cssAstNode for Block {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Block).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Block {
+    /// Keyword of the block (e.g. `hover`), with the selector suffix it emits.
+    pub fn keyword(&self) -> Option<&'static G3cssBlockKeyword> {
+        find_g3css_block_keyword(&keyword(&self.0))
+    }
+
+    /// Elements of the block, in source order.
+    pub fn elements(&self) -> impl Iterator<Item = Element> {
+        children(&self.0)
+    }
+}
+
+/// Enum representing an entry of a block.
+/// Property - a property, by its G3CSS name (e.g. `margin_top`)
+/// Nickname - a property written through an alias of its scope
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Element {
+    Property(Declaration),
+    Nickname(Nickname),
+}
+
+impl G3cssAstNode for Element {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        match node.kind() {
+            G3cssSyntaxKind::Declaration => Some(Element::Property(Declaration(node))),
+            G3cssSyntaxKind::Nickname => Some(Element::Nickname(Nickname(node))),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        match self {
+            Element::Property(declaration) => declaration.syntax(),
+            Element::Nickname(nickname) => nickname.syntax(),
+        }
+    }
+}
+
+/// Represents a property of a block and its value.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Declaration(G3cssSyntaxNode);
+
+impl G3cssAstNode for Declaration {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Declaration).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Declaration {
+    /// Property set by the declaration.
+    pub fn property(&self) -> Option<&'static G3cssProperty> {
+        find_g3css_property(&keyword(&self.0))
+    }
+
+    /// Value of the declaration.
+    pub fn value(&self) -> String {
+        child_text(&self.0, G3cssSyntaxKind::Value).unwrap_or_default()
+    }
+}
+
+/// Represents a property of a block written through an alias of its scope.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Nickname(G3cssSyntaxNode);
+
+impl G3cssAstNode for Nickname {
+    fn cast(node: G3cssSyntaxNode) -> Option<Self> {
+        (node.kind() == G3cssSyntaxKind::Nickname).then_some(Self(node))
+    }
+
+    fn syntax(&self) -> &G3cssSyntaxNode {
+        &self.0
+    }
+}
+
+impl Nickname {
+    /// Alias used by the nickname.
+    pub fn key(&self) -> String {
+        child_text(&self.0, G3cssSyntaxKind::Key).unwrap_or_default()
+    }
+
+    /// Value of the nickname.
+    pub fn value(&self) -> String {
+        child_text(&self.0, G3cssSyntaxKind::Value).unwrap_or_default()
+    }
+}
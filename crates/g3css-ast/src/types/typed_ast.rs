@@ -0,0 +1,150 @@
+use g3css_common::tables::{g3css_keywords::G3cssBlockKeyword, g3css_properties::G3cssProperty};
+
+/// Enum representing the root of a typed AST.
+/// Global - a `define global scope` file
+/// Component - a `define component scope` file
+///
+/// Unlike the parser output, which keeps the parts of a node in positional vectors, the
+/// typed AST names every part, so shapes the grammar cannot produce (a theme in a component,
+/// a `panoramic_viewer` nested in a breakpoint, ...) cannot be represented. It is built from
+/// the parser output by `lower_ast`, or from the concrete syntax tree by `lower_syntax`.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Scope {
+    Global(Global),
+    Component(Component),
+}
+
+impl Scope {
+    /// Name of the scope.
+    pub fn name(&self) -> &str {
+        match self {
+            Scope::Global(global) => &global.name,
+            Scope::Component(component) => &component.name,
+        }
+    }
+}
+
+/// Represents a `define global scope` and its elements, in source order.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Global {
+    pub name: String,
+    pub children: Vec<GlobalItem>,
+}
+
+/// Represents a `define component scope`, the global it extends and its elements, in source
+/// order.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Component {
+    pub name: String,
+    pub extends: Option<String>,
+    pub children: Vec<ScopeItem>,
+}
+
+/// Enum representing an element of a global scope.
+/// Item - an element any scope may hold
+/// Theme - a light or dark theme, which only globals hold
+#[derive(PartialEq, Debug, Clone)]
+pub enum GlobalItem {
+    Item(ScopeItem),
+    Theme(Theme),
+}
+
+/// Enum representing an element of a global or component scope.
+/// Aliases - a `define aliases` block
+/// Variables - a `define variables` block
+/// Class - a `define class`
+/// Classes - a `define classes` block
+#[derive(PartialEq, Debug, Clone)]
+pub enum ScopeItem {
+    Aliases(Vec<Alias>),
+    Variables(Vec<Variable>),
+    Class(Class),
+    Classes(Vec<Class>),
+}
+
+/// Represents an alias, naming the property `value` by `key`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Alias {
+    pub key: String,
+    pub value: String,
+}
+
+/// Represents a variable of a scope or of a theme.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Variable {
+    pub key: String,
+    pub value: String,
+}
+
+/// Enum representing the two themes of a global.
+/// Light - a `define light theme` block
+/// Dark - a `define dark theme` block
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ThemeKind {
+    Light,
+    Dark,
+}
+
+/// Represents a light or dark theme and its variables.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Theme {
+    pub kind: ThemeKind,
+    pub variables: Vec<Variable>,
+}
+
+/// Represents a class, the class it inherits and its body, in source order.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Class {
+    pub name: String,
+    pub inherits: Option<String>,
+    pub body: Vec<ClassItem>,
+}
+
+/// Enum representing an entry of the body of a class.
+/// Important - an `!important` flag
+/// Block - a properties or pseudo block
+/// PanoramicViewer - the breakpoints of a `panoramic_viewer`
+#[derive(PartialEq, Debug, Clone)]
+pub enum ClassItem {
+    Important(bool),
+    Block(Block),
+    PanoramicViewer(Vec<Breakpoint>),
+}
+
+/// Represents a `panoramic_viewer` breakpoint and its body, in source order.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Breakpoint {
+    pub name: String,
+    pub body: Vec<BreakpointItem>,
+}
+
+/// Enum representing an entry of the body of a breakpoint.
+/// Important - an `!important` flag
+/// Block - a properties or pseudo block
+#[derive(PartialEq, Debug, Clone)]
+pub enum BreakpointItem {
+    Important(bool),
+    Block(Block),
+}
+
+/// Represents a properties or pseudo block and its elements.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Block {
+    pub keyword: &'static G3cssBlockKeyword,
+    pub elements: Vec<Element>,
+}
+
+/// Enum representing an entry of a block.
+/// Property - a property and its value
+/// Nickname - a property written through an alias of its scope
+#[derive(PartialEq, Debug, Clone)]
+pub enum Element {
+    Property {
+        property: &'static G3cssProperty,
+        value: String,
+    },
+    Nickname {
+        key: String,
+        value: String,
+    },
+}
@@ -0,0 +1,23 @@
+pub mod types {
+    pub mod g3css_config;
+}
+
+pub mod rustal {
+    pub mod config_loader;
+}
+
+pub mod tables {
+    pub mod browser_compat;
+    pub mod css_colors;
+    pub mod css_shorthands;
+    pub mod deprecated_properties;
+    pub mod g3css_keywords;
+    pub mod g3css_properties;
+}
+
+pub mod utils {
+    pub mod file_utils;
+    pub mod hashing;
+    pub mod node_utils;
+    pub mod string_utils;
+}
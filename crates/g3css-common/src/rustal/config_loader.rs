@@ -0,0 +1,354 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{Map, Value};
+
+use crate::{types::g3css_config::G3cssConfig, utils::string_utils::closest_matches};
+
+/// Names of the configuration files looked for in a project root, by order of preference.
+pub const CONFIG_FILE_NAMES: [&str; 2] = ["galadriel.toml", "g3css.json"];
+
+/// Environment variable naming the configuration file.
+pub const CONFIG_PATH_VARIABLE: &str = "G3CSS_CONFIG";
+
+/// Environment variables overriding configuration keys, with the key they override.
+pub const ENV_OVERRIDES: [(&str, &str); 10] = [
+    ("G3CSS_SOURCE_ROOTS", "source.roots"),
+    ("G3CSS_OUT_DIR", "output.dir"),
+    ("G3CSS_CACHE_DIR", "output.cache_dir"),
+    ("G3CSS_MODE", "output.mode"),
+    ("G3CSS_MINIFY", "output.minify"),
+    ("G3CSS_OPTIMIZE", "output.optimize"),
+    ("G3CSS_BROWSER_TARGETS", "browser_targets"),
+    ("G3CSS_HASHING", "hashing"),
+    ("G3CSS_THEME", "theme"),
+    ("G3CSS_PLUGINS", "plugins"),
+];
+
+/// Keys accepted at the top level of the configuration.
+const TOP_LEVEL_KEYS: [&str; 7] = [
+    "source",
+    "output",
+    "breakpoints",
+    "browser_targets",
+    "hashing",
+    "theme",
+    "plugins",
+];
+
+/// Keys accepted in the tables of the configuration, by table.
+const TABLE_KEYS: [(&str, &[&str]); 3] = [
+    ("source", &["roots", "include", "exclude"]),
+    (
+        "output",
+        &["dir", "cache_dir", "mode", "minify", "optimize"],
+    ),
+    ("breakpoints", &["name", "media"]),
+];
+
+/// Keys holding a list of strings, which overrides may write as comma separated values.
+const LIST_KEYS: [&str; 4] = [
+    "source.roots",
+    "source.include",
+    "source.exclude",
+    "plugins",
+];
+
+/// Builds the message of an unknown key, suggesting the closest known keys.
+fn unknown_key(origin: &str, prefix: &str, key: &str, known: &[&str]) -> String {
+    let mut message = format!("{}: unknown key \"{}{}\"", origin, prefix, key);
+
+    match closest_matches(key, known.iter().copied(), 3).first() {
+        Some(suggestion) => {
+            message.push_str(&format!(", did you mean \"{}{}\"?", prefix, suggestion))
+        }
+        None => message.push_str(&format!(", expected one of: {}", known.join(", "))),
+    }
+
+    message
+}
+
+/// Checks that every key of a configuration is known.
+///
+/// # Arguments
+///
+/// - `origin` - Where the configuration comes from, used in the messages.
+/// - `value` - The configuration.
+///
+/// # Returns
+///
+/// - `Result<(), String>` - An Err naming the first unknown key.
+fn validate_keys(origin: &str, value: &Value) -> Result<(), String> {
+    let Some(table) = value.as_object() else {
+        return Err(format!("{}: the configuration must be a table", origin));
+    };
+
+    for (key, value) in table {
+        if !TOP_LEVEL_KEYS.contains(&key.as_str()) {
+            return Err(unknown_key(origin, "", key, &TOP_LEVEL_KEYS));
+        }
+
+        let Some((_, known)) = TABLE_KEYS.iter().find(|(table, _)| table == key) else {
+            continue;
+        };
+
+        // Breakpoints are a list of tables, the other entries of `TABLE_KEYS` a table.
+        let tables = match value {
+            Value::Array(items) => items.iter().collect(),
+            value => vec![value],
+        };
+
+        for inner_key in tables
+            .into_iter()
+            .filter_map(Value::as_object)
+            .flat_map(Map::keys)
+        {
+            if !known.contains(&inner_key.as_str()) {
+                return Err(unknown_key(origin, &format!("{}.", key), inner_key, known));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts a configuration into its typed form, naming the key holding an invalid value.
+fn typed_config(origin: &str, value: Value) -> Result<G3cssConfig, String> {
+    let Value::Object(table) = value else {
+        return Err(format!("{}: the configuration must be a table", origin));
+    };
+
+    // Keys are typed one at a time first, so an invalid value is reported with its key.
+    for (key, value) in &table {
+        let entries = match value {
+            Value::Object(inner) => inner
+                .iter()
+                .map(|(inner_key, inner_value)| {
+                    let entry = Map::from_iter([(inner_key.clone(), inner_value.clone())]);
+                    (format!("{}.{}", key, inner_key), Value::Object(entry))
+                })
+                .collect(),
+            value => vec![(key.clone(), value.clone())],
+        };
+
+        for (name, entry) in entries {
+            let single = Map::from_iter([(key.clone(), entry)]);
+
+            if let Err(error) = serde_json::from_value::<G3cssConfig>(Value::Object(single)) {
+                return Err(format!(
+                    "{}: invalid value for \"{}\": {}",
+                    origin, name, error
+                ));
+            }
+        }
+    }
+
+    serde_json::from_value(Value::Object(table)).map_err(|error| format!("{}: {}", origin, error))
+}
+
+/// Parses a configuration file, as TOML or JSON depending on its extension.
+fn parse_config_file(path: &Path) -> Result<Value, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+
+    let value = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => serde_json::from_str::<Value>(&content).map_err(|error| error.to_string()),
+        _ => toml::from_str::<toml::Table>(&content)
+            .map_err(|error| error.to_string())
+            .and_then(|table| serde_json::to_value(table).map_err(|error| error.to_string())),
+    };
+
+    value.map_err(|error| format!("{}: {}", path.display(), error.trim_end()))
+}
+
+/// Writes an override into a configuration.
+///
+/// The value is read as JSON when it is valid JSON (`true`, `["a", "b"]`, ...), as a comma
+/// separated list for keys holding a list, and as a string otherwise.
+fn apply_override(config: &mut Value, key: &str, raw: &str) -> Result<(), String> {
+    let origin = format!("override \"{}\"", key);
+
+    let value = match serde_json::from_str::<Value>(raw) {
+        Ok(value) if !value.is_string() => value,
+        _ if LIST_KEYS.contains(&key) => Value::Array(
+            raw.split(',')
+                .map(|item| Value::String(item.trim().to_string()))
+                .collect(),
+        ),
+        _ => Value::String(raw.to_string()),
+    };
+
+    // A dotted key names an entry of a table, merged with the other entries of the table.
+    let (name, value) = match key.split_once('.') {
+        Some((table, inner)) => {
+            let inner = Map::from_iter([(inner.to_string(), value)]);
+            (table, Value::Object(inner))
+        }
+        None => (key, value),
+    };
+
+    validate_keys(
+        &origin,
+        &Value::Object(Map::from_iter([(name.to_string(), value.clone())])),
+    )?;
+
+    let Some(config) = config.as_object_mut() else {
+        return Ok(());
+    };
+
+    match (config.get_mut(name), value) {
+        (Some(Value::Object(existing)), Value::Object(inner)) => existing.extend(inner),
+        (_, value) => {
+            config.insert(name.to_string(), value);
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the configuration file of a project.
+///
+/// # Arguments
+///
+/// - `root` - The project root.
+///
+/// # Returns
+///
+/// The path of the first file of `CONFIG_FILE_NAMES` found in `root`, or `None` if there is
+/// none.
+pub fn find_config(root: &Path) -> Option<PathBuf> {
+    CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| root.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Reads the overrides set through the environment variables of `ENV_OVERRIDES`.
+///
+/// # Returns
+///
+/// The overridden keys with their raw value.
+pub fn env_overrides() -> Vec<(String, String)> {
+    ENV_OVERRIDES
+        .iter()
+        .filter_map(|(variable, key)| Some((key.to_string(), env::var(variable).ok()?)))
+        .collect()
+}
+
+/// Loads the configuration of a project.
+///
+/// The configuration file is `path` when given, else the file named by `G3CSS_CONFIG`, else
+/// the first file of `CONFIG_FILE_NAMES` in `root`. Without any file, the default
+/// configuration is used. `overrides` are applied last, in order, so later ones win.
+///
+/// # Arguments
+///
+/// - `root` - The project root.
+/// - `path` - The configuration file to use instead of looking for one.
+/// - `overrides` - Dotted keys (e.g. `output.minify`) with the raw value to set them to.
+///
+/// # Returns
+///
+/// - `Result<(G3cssConfig, Option<PathBuf>), String>` - Ok containing the configuration and
+///   the file it was read from, or an Err describing an unreadable file, an unknown key or
+///   an invalid value.
+pub fn load_config(
+    root: &Path,
+    path: Option<&Path>,
+    overrides: &[(String, String)],
+) -> Result<(G3cssConfig, Option<PathBuf>), String> {
+    let path = path
+        .map(Path::to_path_buf)
+        .or_else(|| env::var_os(CONFIG_PATH_VARIABLE).map(PathBuf::from))
+        .or_else(|| find_config(root));
+
+    let (mut value, origin) = match &path {
+        Some(path) => (parse_config_file(path)?, path.display().to_string()),
+        None => (
+            Value::Object(Map::new()),
+            "default configuration".to_string(),
+        ),
+    };
+
+    validate_keys(&origin, &value)?;
+
+    for (key, raw) in overrides {
+        apply_override(&mut value, key, raw)?;
+    }
+
+    Ok((typed_config(&origin, value)?, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::g3css_config::G3cssOutputMode;
+
+    /// Writes `content` as the configuration file of a fresh project directory.
+    fn project(name: &str, content: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("g3css-config-{}-{}", std::process::id(), name));
+
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("galadriel.toml"), content).unwrap();
+        root
+    }
+
+    /// Builds the overrides of a command line from `(key, value)` pairs.
+    fn overrides(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn overrides_win_over_the_file_and_later_overrides_win_over_earlier_ones() {
+        let root = project(
+            "precedence",
+            "[output]\ndir = \"public\"\nminify = false\nmode = \"atomic\"\n",
+        );
+        let path = root.join("galadriel.toml");
+
+        let (config, found) = load_config(
+            &root,
+            Some(&path),
+            &overrides(&[
+                ("output.minify", "true"),
+                ("output.dir", "build"),
+                ("output.dir", "out"),
+                ("source.roots", "src, lib"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(found, Some(path));
+        assert!(config.output.minify);
+        assert_eq!(config.output.dir, "out");
+        // Overriding one key of a table keeps the other keys the file set.
+        assert_eq!(config.output.mode, G3cssOutputMode::Atomic);
+        assert_eq!(config.source.roots, ["src", "lib"]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn invalid_overrides_are_reported_with_their_key() {
+        let root = project("invalid", "");
+        let path = root.join("galadriel.toml");
+
+        assert_eq!(
+            load_config(&root, Some(&path), &overrides(&[("output.minfy", "true")])).unwrap_err(),
+            "override \"output.minfy\": unknown key \"output.minfy\", did you mean \"output.minify\"?"
+        );
+        assert!(
+            load_config(&root, Some(&path), &overrides(&[("output.mode", "inline")]))
+                .unwrap_err()
+                .contains("invalid value for \"output.mode\"")
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,461 @@
+/// Version used for prefixes that no release of a browser has dropped yet.
+pub const ALWAYS_PREFIXED: (u32, u32) = (u32::MAX, 0);
+
+/// Describes a browser known to the embedded compatibility table.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct G3cssBrowser {
+    /// Canonical browserslist name of the browser (e.g. `ios_saf`).
+    pub name: &'static str,
+    /// Other names accepted in target queries.
+    pub aliases: &'static [&'static str],
+    /// Most recent releases known to the table, oldest first.
+    pub versions: &'static [(u32, u32)],
+    /// Whether the browser is no longer maintained, which excludes it from `last N versions`
+    /// and `defaults`.
+    pub dead: bool,
+}
+
+/// Describes a declaration that some browsers only understand with a vendor prefix.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct G3cssPrefixRule {
+    /// CSS property the rule applies to.
+    pub property: &'static str,
+    /// Value the rule applies to, for prefixes that go on the value (e.g. `-webkit-sticky`),
+    /// or `None` for prefixes that go on the property.
+    pub value: Option<&'static str>,
+    /// Vendor prefix to add (e.g. `-webkit-`).
+    pub prefix: &'static str,
+    /// Browser requiring the prefix.
+    pub browser: &'static str,
+    /// First version of the browser that no longer requires the prefix.
+    pub unprefixed_since: (u32, u32),
+}
+
+/// Browsers known to the embedded compatibility table.
+pub const G3CSS_BROWSERS: &[G3cssBrowser] = &[
+    G3cssBrowser {
+        name: "chrome",
+        aliases: &[],
+        versions: &[
+            (121, 0),
+            (122, 0),
+            (123, 0),
+            (124, 0),
+            (125, 0),
+            (126, 0),
+            (127, 0),
+            (128, 0),
+            (129, 0),
+            (130, 0),
+        ],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "and_chr",
+        aliases: &["chromeandroid"],
+        versions: &[(130, 0)],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "edge",
+        aliases: &[],
+        versions: &[
+            (121, 0),
+            (122, 0),
+            (123, 0),
+            (124, 0),
+            (125, 0),
+            (126, 0),
+            (127, 0),
+            (128, 0),
+            (129, 0),
+            (130, 0),
+        ],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "firefox",
+        aliases: &["ff"],
+        versions: &[
+            (123, 0),
+            (124, 0),
+            (125, 0),
+            (126, 0),
+            (127, 0),
+            (128, 0),
+            (129, 0),
+            (130, 0),
+            (131, 0),
+            (132, 0),
+        ],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "safari",
+        aliases: &[],
+        versions: &[
+            (16, 0),
+            (16, 1),
+            (16, 2),
+            (16, 3),
+            (16, 4),
+            (16, 5),
+            (16, 6),
+            (17, 0),
+            (17, 1),
+            (17, 2),
+            (17, 3),
+            (17, 4),
+            (17, 5),
+            (17, 6),
+            (18, 0),
+            (18, 1),
+        ],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "ios_saf",
+        aliases: &["ios", "ios_safari"],
+        versions: &[
+            (16, 0),
+            (16, 1),
+            (16, 2),
+            (16, 3),
+            (16, 4),
+            (16, 5),
+            (16, 6),
+            (17, 0),
+            (17, 1),
+            (17, 2),
+            (17, 3),
+            (17, 4),
+            (17, 5),
+            (17, 6),
+            (18, 0),
+            (18, 1),
+        ],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "opera",
+        aliases: &[],
+        versions: &[
+            (105, 0),
+            (106, 0),
+            (107, 0),
+            (108, 0),
+            (109, 0),
+            (110, 0),
+            (111, 0),
+            (112, 0),
+            (113, 0),
+            (114, 0),
+        ],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "samsung",
+        aliases: &[],
+        versions: &[(21, 0), (22, 0), (23, 0), (24, 0), (25, 0), (26, 0)],
+        dead: false,
+    },
+    G3cssBrowser {
+        name: "ie",
+        aliases: &["explorer"],
+        versions: &[(9, 0), (10, 0), (11, 0)],
+        dead: true,
+    },
+];
+
+/// Vendor prefixes required by each browser, and the version that dropped them.
+pub const G3CSS_PREFIX_RULES: &[G3cssPrefixRule] = &[
+    // backdrop-filter
+    G3cssPrefixRule {
+        property: "backdrop-filter",
+        value: None,
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: (18, 0),
+    },
+    G3cssPrefixRule {
+        property: "backdrop-filter",
+        value: None,
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: (18, 0),
+    },
+    // user-select
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: ALWAYS_PREFIXED,
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: ALWAYS_PREFIXED,
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-webkit-",
+        browser: "chrome",
+        unprefixed_since: (54, 0),
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-webkit-",
+        browser: "and_chr",
+        unprefixed_since: (54, 0),
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-webkit-",
+        browser: "opera",
+        unprefixed_since: (41, 0),
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-webkit-",
+        browser: "samsung",
+        unprefixed_since: (6, 0),
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-moz-",
+        browser: "firefox",
+        unprefixed_since: (69, 0),
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-ms-",
+        browser: "edge",
+        unprefixed_since: (79, 0),
+    },
+    G3cssPrefixRule {
+        property: "user-select",
+        value: None,
+        prefix: "-ms-",
+        browser: "ie",
+        unprefixed_since: ALWAYS_PREFIXED,
+    },
+    // appearance
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-webkit-",
+        browser: "chrome",
+        unprefixed_since: (84, 0),
+    },
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-webkit-",
+        browser: "and_chr",
+        unprefixed_since: (84, 0),
+    },
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-webkit-",
+        browser: "edge",
+        unprefixed_since: (84, 0),
+    },
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-webkit-",
+        browser: "opera",
+        unprefixed_since: (70, 0),
+    },
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-webkit-",
+        browser: "samsung",
+        unprefixed_since: (14, 0),
+    },
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: (15, 4),
+    },
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: (15, 4),
+    },
+    G3cssPrefixRule {
+        property: "appearance",
+        value: None,
+        prefix: "-moz-",
+        browser: "firefox",
+        unprefixed_since: (80, 0),
+    },
+    // mask
+    G3cssPrefixRule {
+        property: "mask",
+        value: None,
+        prefix: "-webkit-",
+        browser: "chrome",
+        unprefixed_since: (120, 0),
+    },
+    G3cssPrefixRule {
+        property: "mask",
+        value: None,
+        prefix: "-webkit-",
+        browser: "and_chr",
+        unprefixed_since: (120, 0),
+    },
+    G3cssPrefixRule {
+        property: "mask",
+        value: None,
+        prefix: "-webkit-",
+        browser: "edge",
+        unprefixed_since: (120, 0),
+    },
+    G3cssPrefixRule {
+        property: "mask",
+        value: None,
+        prefix: "-webkit-",
+        browser: "opera",
+        unprefixed_since: (106, 0),
+    },
+    G3cssPrefixRule {
+        property: "mask",
+        value: None,
+        prefix: "-webkit-",
+        browser: "samsung",
+        unprefixed_since: (25, 0),
+    },
+    G3cssPrefixRule {
+        property: "mask",
+        value: None,
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: (15, 4),
+    },
+    G3cssPrefixRule {
+        property: "mask",
+        value: None,
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: (15, 4),
+    },
+    // box-decoration-break
+    G3cssPrefixRule {
+        property: "box-decoration-break",
+        value: None,
+        prefix: "-webkit-",
+        browser: "chrome",
+        unprefixed_since: (130, 0),
+    },
+    G3cssPrefixRule {
+        property: "box-decoration-break",
+        value: None,
+        prefix: "-webkit-",
+        browser: "and_chr",
+        unprefixed_since: (130, 0),
+    },
+    G3cssPrefixRule {
+        property: "box-decoration-break",
+        value: None,
+        prefix: "-webkit-",
+        browser: "edge",
+        unprefixed_since: (130, 0),
+    },
+    G3cssPrefixRule {
+        property: "box-decoration-break",
+        value: None,
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: ALWAYS_PREFIXED,
+    },
+    G3cssPrefixRule {
+        property: "box-decoration-break",
+        value: None,
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: ALWAYS_PREFIXED,
+    },
+    // backface-visibility
+    G3cssPrefixRule {
+        property: "backface-visibility",
+        value: None,
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: (15, 4),
+    },
+    G3cssPrefixRule {
+        property: "backface-visibility",
+        value: None,
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: (15, 4),
+    },
+    // clip-path
+    G3cssPrefixRule {
+        property: "clip-path",
+        value: None,
+        prefix: "-webkit-",
+        browser: "chrome",
+        unprefixed_since: (55, 0),
+    },
+    G3cssPrefixRule {
+        property: "clip-path",
+        value: None,
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: (13, 1),
+    },
+    G3cssPrefixRule {
+        property: "clip-path",
+        value: None,
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: (13, 2),
+    },
+    // position: sticky
+    G3cssPrefixRule {
+        property: "position",
+        value: Some("sticky"),
+        prefix: "-webkit-",
+        browser: "safari",
+        unprefixed_since: (13, 0),
+    },
+    G3cssPrefixRule {
+        property: "position",
+        value: Some("sticky"),
+        prefix: "-webkit-",
+        browser: "ios_saf",
+        unprefixed_since: (13, 0),
+    },
+];
+
+/// Looks up a browser by its name or one of its aliases.
+///
+/// # Arguments
+///
+/// - `name` - The browser name, in lowercase.
+///
+/// # Returns
+///
+/// The matching `G3cssBrowser`, or `None` if the table does not know the browser.
+pub fn find_g3css_browser(name: &str) -> Option<&'static G3cssBrowser> {
+    G3CSS_BROWSERS
+        .iter()
+        .find(|browser| browser.name == name || browser.aliases.contains(&name))
+}
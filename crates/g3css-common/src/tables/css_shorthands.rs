@@ -0,0 +1,223 @@
+/// Every CSS shorthand with the properties it sets, some of which are shorthands too.
+pub const CSS_SHORTHANDS: &[(&str, &[&str])] = &[
+    (
+        "margin",
+        &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+    ),
+    (
+        "padding",
+        &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+    ),
+    (
+        "border",
+        &[
+            "border-top",
+            "border-right",
+            "border-bottom",
+            "border-left",
+            "border-image",
+        ],
+    ),
+    (
+        "border-top",
+        &["border-top-width", "border-top-style", "border-top-color"],
+    ),
+    (
+        "border-right",
+        &[
+            "border-right-width",
+            "border-right-style",
+            "border-right-color",
+        ],
+    ),
+    (
+        "border-bottom",
+        &[
+            "border-bottom-width",
+            "border-bottom-style",
+            "border-bottom-color",
+        ],
+    ),
+    (
+        "border-left",
+        &[
+            "border-left-width",
+            "border-left-style",
+            "border-left-color",
+        ],
+    ),
+    (
+        "border-width",
+        &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+    ),
+    (
+        "border-style",
+        &[
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+    ),
+    (
+        "border-color",
+        &[
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+    ),
+    (
+        "border-radius",
+        &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+    ),
+    (
+        "border-image",
+        &[
+            "border-image-source",
+            "border-image-slice",
+            "border-image-width",
+            "border-image-outset",
+            "border-image-repeat",
+        ],
+    ),
+    (
+        "outline",
+        &["outline-color", "outline-style", "outline-width"],
+    ),
+    (
+        "background",
+        &[
+            "background-attachment",
+            "background-clip",
+            "background-color",
+            "background-image",
+            "background-origin",
+            "background-position",
+            "background-repeat",
+            "background-size",
+        ],
+    ),
+    (
+        "background-position",
+        &["background-position-x", "background-position-y"],
+    ),
+    (
+        "font",
+        &[
+            "font-family",
+            "font-size",
+            "font-size-adjust",
+            "font-stretch",
+            "font-style",
+            "font-variant",
+            "font-weight",
+            "line-height",
+        ],
+    ),
+    ("gap", &["row-gap", "column-gap"]),
+    ("overflow", &["overflow-x", "overflow-y"]),
+    (
+        "list-style",
+        &["list-style-image", "list-style-position", "list-style-type"],
+    ),
+    ("columns", &["column-count", "column-width"]),
+    (
+        "column-rule",
+        &[
+            "column-rule-color",
+            "column-rule-style",
+            "column-rule-width",
+        ],
+    ),
+    (
+        "animation",
+        &[
+            "animation-name",
+            "animation-duration",
+            "animation-timing-function",
+            "animation-delay",
+            "animation-fill-mode",
+            "animation-iteration-count",
+            "animation-direction",
+            "animation-play-state",
+        ],
+    ),
+    (
+        "transition",
+        &[
+            "transition-property",
+            "transition-duration",
+            "transition-timing-function",
+            "transition-delay",
+        ],
+    ),
+    (
+        "grid",
+        &[
+            "grid-template",
+            "grid-auto-columns",
+            "grid-auto-flow",
+            "grid-auto-rows",
+        ],
+    ),
+    (
+        "grid-template",
+        &[
+            "grid-template-areas",
+            "grid-template-columns",
+            "grid-template-rows",
+        ],
+    ),
+    ("grid-area", &["grid-row", "grid-column"]),
+    ("grid-row", &["grid-row-start", "grid-row-end"]),
+    ("grid-column", &["grid-column-start", "grid-column-end"]),
+];
+
+/// Values every CSS property accepts.
+pub const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// Finds the longhands a CSS property sets, expanding nested shorthands.
+///
+/// # Arguments
+///
+/// - `property` - The CSS name of the property (e.g. `border-top`).
+///
+/// # Returns
+///
+/// The longhands set by the property, or the property itself if it is not a shorthand.
+pub fn css_longhands(property: &str) -> Vec<&str> {
+    match CSS_SHORTHANDS.iter().find(|(name, _)| *name == property) {
+        Some((_, properties)) => properties
+            .iter()
+            .flat_map(|property| css_longhands(property))
+            .collect(),
+        None => vec![property],
+    }
+}
+
+/// Checks whether two CSS properties set a common longhand, such as `margin` and
+/// `margin-top`, so the order in which they are declared matters.
+pub fn properties_overlap(first: &str, second: &str) -> bool {
+    let longhands = css_longhands(first);
+
+    css_longhands(second)
+        .iter()
+        .any(|longhand| longhands.contains(longhand))
+}
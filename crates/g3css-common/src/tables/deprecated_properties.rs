@@ -0,0 +1,300 @@
+/// Describes a property of the grammar that browsers dropped or renamed.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct G3cssDeprecatedProperty {
+    /// G3CSS name of the deprecated property (e.g. `box_orient`).
+    pub name: &'static str,
+    /// G3CSS name of the property replacing it, if the grammar has one.
+    pub replacement: Option<&'static str>,
+    /// Values that must be rewritten for the replacement, as `(old, new)` pairs. Values not
+    /// listed are kept as they are.
+    pub values: &'static [(&'static str, &'static str)],
+}
+
+/// Every deprecated property of the grammar.
+pub const G3CSS_DEPRECATED_PROPERTIES: &[G3cssDeprecatedProperty] = &[
+    G3cssDeprecatedProperty {
+        name: "color_profile",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "rendering_intent",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "word_wrap",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "marquee_direction",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "marquee_play_count",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "marquee_speed",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "marquee_style",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "overflow_style",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "rotation",
+        replacement: Some("rotate"),
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_align",
+        replacement: Some("align_items"),
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_direction",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_flex",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_flex_group",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_lines",
+        replacement: Some("flex_wrap"),
+        values: &[("single", "nowrap"), ("multiple", "wrap")],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_ordinal_group",
+        replacement: Some("order"),
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_orient",
+        replacement: Some("flex_direction"),
+        values: &[
+            ("horizontal", "row"),
+            ("inline-axis", "row"),
+            ("vertical", "column"),
+            ("block-axis", "column"),
+        ],
+    },
+    G3cssDeprecatedProperty {
+        name: "box_pack",
+        replacement: Some("justify_content"),
+        values: &[("justify", "space-between")],
+    },
+    G3cssDeprecatedProperty {
+        name: "alignment_adjust",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "drop_initial_after_adjust",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "drop_initial_after_align",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "drop_initial_before_adjust",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "drop_initial_before_align",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "drop_initial_size",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "drop_initial_value",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "inline_box_align",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "line_stacking",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "line_stacking_ruby",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "line_stacking_shift",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "line_stacking_strategy",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "text_height",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "page_break_after",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "page_break_before",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "page_break_inside",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "mark",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "mark_after",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "mark_before",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "phonemes",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "rest",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "rest_after",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "rest_before",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "voice_balance",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "voice_duration",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "voice_pitch",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "voice_pitch_range",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "voice_rate",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "voice_stress",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "voice_volume",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "icon",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "nav_down",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "nav_index",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "nav_left",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "nav_right",
+        replacement: None,
+        values: &[],
+    },
+    G3cssDeprecatedProperty {
+        name: "nav_up",
+        replacement: None,
+        values: &[],
+    },
+];
+
+/// Looks up a deprecated property by its G3CSS name.
+///
+/// # Arguments
+///
+/// - `name` - The G3CSS property name (e.g. `box_orient`).
+///
+/// # Returns
+///
+/// The matching `G3cssDeprecatedProperty`, or `None` if the property is not deprecated.
+pub fn find_g3css_deprecated_property(name: &str) -> Option<&'static G3cssDeprecatedProperty> {
+    G3CSS_DEPRECATED_PROPERTIES
+        .iter()
+        .find(|property| property.name == name)
+}
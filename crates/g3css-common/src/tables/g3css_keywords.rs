@@ -0,0 +1,207 @@
+/// Describes a block keyword accepted inside a G3CSS class.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct G3cssBlockKeyword {
+    /// Keyword as written in `.g3css` files (e.g. `first_of_type`).
+    pub name: &'static str,
+    /// Selector suffix emitted for the block (e.g. `:first-of-type`), empty for `properties`
+    /// and `panoramic_viewer`.
+    pub selector: &'static str,
+    /// Short description of the block, shown by editors.
+    pub description: &'static str,
+}
+
+/// Every block keyword accepted inside a class by the G3CSS grammar, in grammar order.
+pub const G3CSS_BLOCK_KEYWORDS: &[G3cssBlockKeyword] = &[
+    G3cssBlockKeyword {
+        name: "properties",
+        selector: "",
+        description: "Declarations applied to the class itself.",
+    },
+    G3cssBlockKeyword {
+        name: "hover",
+        selector: ":hover",
+        description: "Declarations applied while the pointer hovers the element.",
+    },
+    G3cssBlockKeyword {
+        name: "active",
+        selector: ":active",
+        description: "Declarations applied while the element is being activated.",
+    },
+    G3cssBlockKeyword {
+        name: "focus",
+        selector: ":focus",
+        description: "Declarations applied while the element has focus.",
+    },
+    G3cssBlockKeyword {
+        name: "first_child",
+        selector: ":first-child",
+        description: "Declarations applied when the element is the first of its siblings.",
+    },
+    G3cssBlockKeyword {
+        name: "last_child",
+        selector: ":last-child",
+        description: "Declarations applied when the element is the last of its siblings.",
+    },
+    G3cssBlockKeyword {
+        name: "first_of_type",
+        selector: ":first-of-type",
+        description:
+            "Declarations applied when the element is the first of its type among its siblings.",
+    },
+    G3cssBlockKeyword {
+        name: "last_of_type",
+        selector: ":last-of-type",
+        description:
+            "Declarations applied when the element is the last of its type among its siblings.",
+    },
+    G3cssBlockKeyword {
+        name: "only_child",
+        selector: ":only-child",
+        description: "Declarations applied when the element has no siblings.",
+    },
+    G3cssBlockKeyword {
+        name: "only_of_type",
+        selector: ":only-of-type",
+        description: "Declarations applied when the element has no siblings of its type.",
+    },
+    G3cssBlockKeyword {
+        name: "target_pseudo_class",
+        selector: ":target",
+        description: "Declarations applied when the element is the target of the URL fragment.",
+    },
+    G3cssBlockKeyword {
+        name: "visited",
+        selector: ":visited",
+        description: "Declarations applied to links that have been visited.",
+    },
+    G3cssBlockKeyword {
+        name: "checked",
+        selector: ":checked",
+        description: "Declarations applied to checked checkboxes, radio buttons and options.",
+    },
+    G3cssBlockKeyword {
+        name: "disabled",
+        selector: ":disabled",
+        description: "Declarations applied to disabled form elements.",
+    },
+    G3cssBlockKeyword {
+        name: "enabled",
+        selector: ":enabled",
+        description: "Declarations applied to enabled form elements.",
+    },
+    G3cssBlockKeyword {
+        name: "read_only",
+        selector: ":read-only",
+        description: "Declarations applied to elements the user cannot edit.",
+    },
+    G3cssBlockKeyword {
+        name: "read_write",
+        selector: ":read-write",
+        description: "Declarations applied to elements the user can edit.",
+    },
+    G3cssBlockKeyword {
+        name: "placeholder_shown",
+        selector: ":placeholder-shown",
+        description: "Declarations applied while an input shows its placeholder.",
+    },
+    G3cssBlockKeyword {
+        name: "valid",
+        selector: ":valid",
+        description: "Declarations applied to form elements whose value is valid.",
+    },
+    G3cssBlockKeyword {
+        name: "invalid",
+        selector: ":invalid",
+        description: "Declarations applied to form elements whose value is invalid.",
+    },
+    G3cssBlockKeyword {
+        name: "required",
+        selector: ":required",
+        description: "Declarations applied to required form elements.",
+    },
+    G3cssBlockKeyword {
+        name: "optional",
+        selector: ":optional",
+        description: "Declarations applied to optional form elements.",
+    },
+    G3cssBlockKeyword {
+        name: "fullscreen",
+        selector: ":fullscreen",
+        description: "Declarations applied while the element is displayed fullscreen.",
+    },
+    G3cssBlockKeyword {
+        name: "focus_within",
+        selector: ":focus-within",
+        description: "Declarations applied while the element or a descendant has focus.",
+    },
+    G3cssBlockKeyword {
+        name: "first_line",
+        selector: "::first-line",
+        description: "Declarations applied to the first line of the element's text.",
+    },
+    G3cssBlockKeyword {
+        name: "first_letter",
+        selector: "::first-letter",
+        description: "Declarations applied to the first letter of the element's text.",
+    },
+    G3cssBlockKeyword {
+        name: "before",
+        selector: "::before",
+        description: "Declarations of a pseudo-element inserted before the element's content.",
+    },
+    G3cssBlockKeyword {
+        name: "after",
+        selector: "::after",
+        description: "Declarations of a pseudo-element inserted after the element's content.",
+    },
+    G3cssBlockKeyword {
+        name: "out_of_range",
+        selector: ":out-of-range",
+        description: "Declarations applied to inputs whose value is outside their range.",
+    },
+    G3cssBlockKeyword {
+        name: "root",
+        selector: ":root",
+        description: "Declarations applied when the element is the document root.",
+    },
+    G3cssBlockKeyword {
+        name: "first_page",
+        selector: ":first",
+        description: "Declarations applied to the first printed page.",
+    },
+    G3cssBlockKeyword {
+        name: "left_page",
+        selector: ":left",
+        description: "Declarations applied to left-hand printed pages.",
+    },
+    G3cssBlockKeyword {
+        name: "right_page",
+        selector: ":right",
+        description: "Declarations applied to right-hand printed pages.",
+    },
+    G3cssBlockKeyword {
+        name: "empty",
+        selector: ":empty",
+        description: "Declarations applied when the element has no children.",
+    },
+    G3cssBlockKeyword {
+        name: "panoramic_viewer",
+        selector: "",
+        description: "Blocks applied only at given breakpoints (e.g. `mobile: { ... }`).",
+    },
+];
+
+/// Looks up a block keyword by name.
+///
+/// # Arguments
+///
+/// - `name` - The keyword (e.g. `first_of_type`).
+///
+/// # Returns
+///
+/// The matching `G3cssBlockKeyword`, or `None` if the grammar does not define it.
+pub fn find_g3css_block_keyword(name: &str) -> Option<&'static G3cssBlockKeyword> {
+    G3CSS_BLOCK_KEYWORDS
+        .iter()
+        .find(|keyword| keyword.name == name)
+}
@@ -0,0 +1,1305 @@
+/// Describes a property supported by the G3CSS `property` grammar rule.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct G3cssProperty {
+    /// Name of the property as written in `.g3css` files (e.g. `margin_top`).
+    pub name: &'static str,
+    /// Name of the CSS property emitted for it (e.g. `margin-top`).
+    pub css: &'static str,
+    /// Short description of what the property does, shown by editors.
+    pub description: &'static str,
+}
+
+/// Every property accepted by the G3CSS grammar, in grammar order.
+pub const G3CSS_PROPERTIES: &[G3cssProperty] = &[
+    G3cssProperty {
+        name: "aspect_ratio",
+        css: "aspect-ratio",
+        description: "Preferred width-to-height ratio of the box.",
+    },
+    G3cssProperty {
+        name: "accent_color",
+        css: "accent-color",
+        description: "Accent color of form controls such as checkboxes and radio buttons.",
+    },
+    G3cssProperty {
+        name: "backdrop_filter",
+        css: "backdrop-filter",
+        description:
+            "Graphical effects (blur, color shift) applied to the area behind the element.",
+    },
+    G3cssProperty {
+        name: "content",
+        css: "content",
+        description: "Content generated by ::before and ::after pseudo-elements.",
+    },
+    G3cssProperty {
+        name: "gap",
+        css: "gap",
+        description: "Gaps between rows and columns of flex and grid layouts.",
+    },
+    G3cssProperty {
+        name: "row_gap",
+        css: "row-gap",
+        description: "Gap between rows of flex and grid layouts.",
+    },
+    G3cssProperty {
+        name: "scale",
+        css: "scale",
+        description: "Scales the element independently of transform.",
+    },
+    G3cssProperty {
+        name: "order",
+        css: "order",
+        description: "Order of a flex or grid item within its container.",
+    },
+    G3cssProperty {
+        name: "pointer_events",
+        css: "pointer-events",
+        description: "Whether the element can be the target of pointer events.",
+    },
+    G3cssProperty {
+        name: "margin",
+        css: "margin",
+        description: "Margin on all four sides of the element.",
+    },
+    G3cssProperty {
+        name: "margin_bottom",
+        css: "margin-bottom",
+        description: "Margin below the element.",
+    },
+    G3cssProperty {
+        name: "margin_left",
+        css: "margin-left",
+        description: "Margin on the left of the element.",
+    },
+    G3cssProperty {
+        name: "margin_right",
+        css: "margin-right",
+        description: "Margin on the right of the element.",
+    },
+    G3cssProperty {
+        name: "margin_top",
+        css: "margin-top",
+        description: "Margin above the element.",
+    },
+    G3cssProperty {
+        name: "padding",
+        css: "padding",
+        description: "Padding on all four sides of the element.",
+    },
+    G3cssProperty {
+        name: "padding_bottom",
+        css: "padding-bottom",
+        description: "Padding at the bottom of the element.",
+    },
+    G3cssProperty {
+        name: "padding_left",
+        css: "padding-left",
+        description: "Padding on the left of the element.",
+    },
+    G3cssProperty {
+        name: "padding_right",
+        css: "padding-right",
+        description: "Padding on the right of the element.",
+    },
+    G3cssProperty {
+        name: "padding_top",
+        css: "padding-top",
+        description: "Padding at the top of the element.",
+    },
+    G3cssProperty {
+        name: "height",
+        css: "height",
+        description: "Height of the element.",
+    },
+    G3cssProperty {
+        name: "width",
+        css: "width",
+        description: "Width of the element.",
+    },
+    G3cssProperty {
+        name: "filter",
+        css: "filter",
+        description: "Graphical effects such as blur or color shift applied to the element.",
+    },
+    G3cssProperty {
+        name: "max_height",
+        css: "max-height",
+        description: "Maximum height of the element.",
+    },
+    G3cssProperty {
+        name: "max_width",
+        css: "max-width",
+        description: "Maximum width of the element.",
+    },
+    G3cssProperty {
+        name: "min_height",
+        css: "min-height",
+        description: "Minimum height of the element.",
+    },
+    G3cssProperty {
+        name: "min_width",
+        css: "min-width",
+        description: "Minimum width of the element.",
+    },
+    G3cssProperty {
+        name: "border",
+        css: "border",
+        description: "Width, style and color of all four borders.",
+    },
+    G3cssProperty {
+        name: "border_bottom",
+        css: "border-bottom",
+        description: "Width, style and color of the bottom border.",
+    },
+    G3cssProperty {
+        name: "border_bottom_color",
+        css: "border-bottom-color",
+        description: "Color of the bottom border.",
+    },
+    G3cssProperty {
+        name: "border_bottom_style",
+        css: "border-bottom-style",
+        description: "Line style of the bottom border.",
+    },
+    G3cssProperty {
+        name: "border_bottom_width",
+        css: "border-bottom-width",
+        description: "Width of the bottom border.",
+    },
+    G3cssProperty {
+        name: "border_color",
+        css: "border-color",
+        description: "Color of all four borders.",
+    },
+    G3cssProperty {
+        name: "border_left",
+        css: "border-left",
+        description: "Width, style and color of the left border.",
+    },
+    G3cssProperty {
+        name: "border_left_color",
+        css: "border-left-color",
+        description: "Color of the left border.",
+    },
+    G3cssProperty {
+        name: "border_left_style",
+        css: "border-left-style",
+        description: "Line style of the left border.",
+    },
+    G3cssProperty {
+        name: "border_left_width",
+        css: "border-left-width",
+        description: "Width of the left border.",
+    },
+    G3cssProperty {
+        name: "border_right",
+        css: "border-right",
+        description: "Width, style and color of the right border.",
+    },
+    G3cssProperty {
+        name: "border_right_color",
+        css: "border-right-color",
+        description: "Color of the right border.",
+    },
+    G3cssProperty {
+        name: "border_right_styles",
+        css: "border-right-style",
+        description: "Line style of the right border.",
+    },
+    G3cssProperty {
+        name: "border_right_width",
+        css: "border-right-width",
+        description: "Width of the right border.",
+    },
+    G3cssProperty {
+        name: "border_style",
+        css: "border-style",
+        description: "Line style of all four borders.",
+    },
+    G3cssProperty {
+        name: "border_top",
+        css: "border-top",
+        description: "Width, style and color of the top border.",
+    },
+    G3cssProperty {
+        name: "border_top_color",
+        css: "border-top-color",
+        description: "Color of the top border.",
+    },
+    G3cssProperty {
+        name: "border_top_style",
+        css: "border-top-style",
+        description: "Line style of the top border.",
+    },
+    G3cssProperty {
+        name: "border_top_width",
+        css: "border-top-width",
+        description: "Width of the top border.",
+    },
+    G3cssProperty {
+        name: "border_width",
+        css: "border-width",
+        description: "Width of all four borders.",
+    },
+    G3cssProperty {
+        name: "outline",
+        css: "outline",
+        description: "Width, style and color of the outline.",
+    },
+    G3cssProperty {
+        name: "outline_color",
+        css: "outline-color",
+        description: "Color of the outline.",
+    },
+    G3cssProperty {
+        name: "outline_style",
+        css: "outline-style",
+        description: "Line style of the outline.",
+    },
+    G3cssProperty {
+        name: "outline_width",
+        css: "outline-width",
+        description: "Width of the outline.",
+    },
+    G3cssProperty {
+        name: "border_bottom_left_radius",
+        css: "border-bottom-left-radius",
+        description: "Rounding of the bottom-left corner.",
+    },
+    G3cssProperty {
+        name: "border_bottom_right_radius",
+        css: "border-bottom-right-radius",
+        description: "Rounding of the bottom-right corner.",
+    },
+    G3cssProperty {
+        name: "border_image",
+        css: "border-image",
+        description: "Image drawn around the element instead of its border.",
+    },
+    G3cssProperty {
+        name: "border_image_outset",
+        css: "border-image-outset",
+        description: "Distance the border image extends beyond the border box.",
+    },
+    G3cssProperty {
+        name: "border_image_repeat",
+        css: "border-image-repeat",
+        description: "How the edges of the border image are repeated or stretched.",
+    },
+    G3cssProperty {
+        name: "border_image_slice",
+        css: "border-image-slice",
+        description: "How the border image is sliced into regions.",
+    },
+    G3cssProperty {
+        name: "border_image_source",
+        css: "border-image-source",
+        description: "Source image of the border image.",
+    },
+    G3cssProperty {
+        name: "border_image_width",
+        css: "border-image-width",
+        description: "Width of the border image.",
+    },
+    G3cssProperty {
+        name: "border_radius",
+        css: "border-radius",
+        description: "Rounding of all four corners.",
+    },
+    G3cssProperty {
+        name: "border_top_left_radius",
+        css: "border-top-left-radius",
+        description: "Rounding of the top-left corner.",
+    },
+    G3cssProperty {
+        name: "border_top_right_radius",
+        css: "border-top-right-radius",
+        description: "Rounding of the top-right corner.",
+    },
+    G3cssProperty {
+        name: "box_decoration_break",
+        css: "box-decoration-break",
+        description: "How the box is rendered when broken across lines, columns or pages.",
+    },
+    G3cssProperty {
+        name: "box_shadow",
+        css: "box-shadow",
+        description: "Shadows cast by the element's box.",
+    },
+    G3cssProperty {
+        name: "background",
+        css: "background",
+        description: "All background properties at once.",
+    },
+    G3cssProperty {
+        name: "background_attachment",
+        css: "background-attachment",
+        description: "Whether the background scrolls with the element or the viewport.",
+    },
+    G3cssProperty {
+        name: "background_color",
+        css: "background-color",
+        description: "Background color of the element.",
+    },
+    G3cssProperty {
+        name: "background_image",
+        css: "background-image",
+        description: "Background images of the element.",
+    },
+    G3cssProperty {
+        name: "background_position",
+        css: "background-position",
+        description: "Initial position of the background images.",
+    },
+    G3cssProperty {
+        name: "background_position_x",
+        css: "background-position-x",
+        description: "Horizontal position of the background images.",
+    },
+    G3cssProperty {
+        name: "background_position_y",
+        css: "background-position-y",
+        description: "Vertical position of the background images.",
+    },
+    G3cssProperty {
+        name: "background_repeat",
+        css: "background-repeat",
+        description: "How background images are repeated.",
+    },
+    G3cssProperty {
+        name: "background_clip",
+        css: "background-clip",
+        description: "Box the background extends to.",
+    },
+    G3cssProperty {
+        name: "background_origin",
+        css: "background-origin",
+        description: "Box the background is positioned relative to.",
+    },
+    G3cssProperty {
+        name: "background_size",
+        css: "background-size",
+        description: "Size of the background images.",
+    },
+    G3cssProperty {
+        name: "background_blend_mode",
+        css: "background-blend-mode",
+        description: "How background layers blend with each other.",
+    },
+    G3cssProperty {
+        name: "color_profile",
+        css: "color-profile",
+        description: "Color profile of images (obsolete).",
+    },
+    G3cssProperty {
+        name: "opacity",
+        css: "opacity",
+        description: "Opacity of the element.",
+    },
+    G3cssProperty {
+        name: "rendering_intent",
+        css: "rendering-intent",
+        description: "Color rendering intent of images (obsolete).",
+    },
+    G3cssProperty {
+        name: "font",
+        css: "font",
+        description: "All font properties at once.",
+    },
+    G3cssProperty {
+        name: "font_family",
+        css: "font-family",
+        description: "Prioritized list of font families.",
+    },
+    G3cssProperty {
+        name: "font_size",
+        css: "font-size",
+        description: "Size of the font.",
+    },
+    G3cssProperty {
+        name: "font_style",
+        css: "font-style",
+        description: "Normal, italic or oblique face of the font.",
+    },
+    G3cssProperty {
+        name: "font_variant",
+        css: "font-variant",
+        description: "Font variants such as small capitals.",
+    },
+    G3cssProperty {
+        name: "font_weight",
+        css: "font-weight",
+        description: "Weight (boldness) of the font.",
+    },
+    G3cssProperty {
+        name: "font_size_adjust",
+        css: "font-size-adjust",
+        description: "Adjusts the font size based on the height of lowercase letters.",
+    },
+    G3cssProperty {
+        name: "font_stretch",
+        css: "font-stretch",
+        description: "Condensed or expanded face of the font.",
+    },
+    G3cssProperty {
+        name: "positioning",
+        css: "positioning",
+        description: "Positioning scheme of the element (non-standard).",
+    },
+    G3cssProperty {
+        name: "bottom",
+        css: "bottom",
+        description: "Vertical offset from the bottom of the containing block.",
+    },
+    G3cssProperty {
+        name: "clear",
+        css: "clear",
+        description: "Whether the element moves below preceding floats.",
+    },
+    G3cssProperty {
+        name: "clip_path",
+        css: "clip-path",
+        description: "Clipping region defining which parts of the element are shown.",
+    },
+    G3cssProperty {
+        name: "cursor",
+        css: "cursor",
+        description: "Mouse cursor shown over the element.",
+    },
+    G3cssProperty {
+        name: "display",
+        css: "display",
+        description: "Display type of the element and layout of its children.",
+    },
+    G3cssProperty {
+        name: "float",
+        css: "float",
+        description: "Places the element on the left or right side of its container.",
+    },
+    G3cssProperty {
+        name: "left",
+        css: "left",
+        description: "Horizontal offset from the left of the containing block.",
+    },
+    G3cssProperty {
+        name: "overflow",
+        css: "overflow",
+        description: "What happens to content overflowing the element.",
+    },
+    G3cssProperty {
+        name: "position",
+        css: "position",
+        description: "Positioning scheme of the element.",
+    },
+    G3cssProperty {
+        name: "right",
+        css: "right",
+        description: "Horizontal offset from the right of the containing block.",
+    },
+    G3cssProperty {
+        name: "top",
+        css: "top",
+        description: "Vertical offset from the top of the containing block.",
+    },
+    G3cssProperty {
+        name: "visibility",
+        css: "visibility",
+        description: "Shows or hides the element without changing the layout.",
+    },
+    G3cssProperty {
+        name: "z_index",
+        css: "z-index",
+        description: "Stacking order of a positioned element.",
+    },
+    G3cssProperty {
+        name: "color",
+        css: "color",
+        description: "Foreground color of text and text decorations.",
+    },
+    G3cssProperty {
+        name: "direction",
+        css: "direction",
+        description: "Direction of text, table columns and horizontal overflow.",
+    },
+    G3cssProperty {
+        name: "flex_direction",
+        css: "flex-direction",
+        description: "Direction of the main axis of a flex container.",
+    },
+    G3cssProperty {
+        name: "flex_wrap",
+        css: "flex-wrap",
+        description: "Whether flex items wrap onto multiple lines.",
+    },
+    G3cssProperty {
+        name: "letter_spacing",
+        css: "letter-spacing",
+        description: "Spacing between characters.",
+    },
+    G3cssProperty {
+        name: "line_height",
+        css: "line-height",
+        description: "Height of a line box.",
+    },
+    G3cssProperty {
+        name: "line_break",
+        css: "line-break",
+        description: "How strictly lines break in CJK text.",
+    },
+    G3cssProperty {
+        name: "text_align",
+        css: "text-align",
+        description: "Horizontal alignment of inline content.",
+    },
+    G3cssProperty {
+        name: "text_decoration",
+        css: "text-decoration",
+        description: "Decorative lines on text, such as underlines.",
+    },
+    G3cssProperty {
+        name: "text_indent",
+        css: "text-indent",
+        description: "Indentation of the first line of text.",
+    },
+    G3cssProperty {
+        name: "text_transform",
+        css: "text-transform",
+        description: "Capitalization of text.",
+    },
+    G3cssProperty {
+        name: "unicode_bidi",
+        css: "unicode-bidi",
+        description: "Handling of bidirectional text.",
+    },
+    G3cssProperty {
+        name: "vertical_align",
+        css: "vertical-align",
+        description: "Vertical alignment of inline or table-cell boxes.",
+    },
+    G3cssProperty {
+        name: "white_space",
+        css: "white-space",
+        description: "Handling of white space and line wrapping.",
+    },
+    G3cssProperty {
+        name: "word_spacing",
+        css: "word-spacing",
+        description: "Spacing between words.",
+    },
+    G3cssProperty {
+        name: "text_outline",
+        css: "text-outline",
+        description: "Outline around text characters (non-standard).",
+    },
+    G3cssProperty {
+        name: "text_overflow",
+        css: "text-overflow",
+        description: "How hidden overflowing text is signaled, such as with an ellipsis.",
+    },
+    G3cssProperty {
+        name: "text_shadow",
+        css: "text-shadow",
+        description: "Shadows added to text.",
+    },
+    G3cssProperty {
+        name: "text_wrap",
+        css: "text-wrap",
+        description: "How text inside the element is wrapped.",
+    },
+    G3cssProperty {
+        name: "word_break",
+        css: "word-break",
+        description: "Where lines may break inside words.",
+    },
+    G3cssProperty {
+        name: "word_wrap",
+        css: "word-wrap",
+        description:
+            "Whether long words may break to prevent overflow (legacy name of overflow-wrap).",
+    },
+    G3cssProperty {
+        name: "list_style",
+        css: "list-style",
+        description: "All list style properties at once.",
+    },
+    G3cssProperty {
+        name: "list_style_image",
+        css: "list-style-image",
+        description: "Image used as the list item marker.",
+    },
+    G3cssProperty {
+        name: "list_style_position",
+        css: "list-style-position",
+        description: "Position of the list item marker.",
+    },
+    G3cssProperty {
+        name: "list_style_type",
+        css: "list-style-type",
+        description: "Type of the list item marker.",
+    },
+    G3cssProperty {
+        name: "border_collapse",
+        css: "border-collapse",
+        description: "Whether table cell borders are shared or separate.",
+    },
+    G3cssProperty {
+        name: "border_spacing",
+        css: "border-spacing",
+        description: "Distance between the borders of adjacent table cells.",
+    },
+    G3cssProperty {
+        name: "caption_side",
+        css: "caption-side",
+        description: "Placement of a table caption.",
+    },
+    G3cssProperty {
+        name: "empty_cells",
+        css: "empty-cells",
+        description: "Whether borders and backgrounds of empty table cells are shown.",
+    },
+    G3cssProperty {
+        name: "table_layout",
+        css: "table-layout",
+        description: "Algorithm used to lay out table cells, rows and columns.",
+    },
+    G3cssProperty {
+        name: "marquee_direction",
+        css: "marquee-direction",
+        description: "Direction of moving marquee content (obsolete).",
+    },
+    G3cssProperty {
+        name: "marquee_play_count",
+        css: "marquee-play-count",
+        description: "Number of times marquee content moves (obsolete).",
+    },
+    G3cssProperty {
+        name: "marquee_speed",
+        css: "marquee-speed",
+        description: "Speed of moving marquee content (obsolete).",
+    },
+    G3cssProperty {
+        name: "marquee_style",
+        css: "marquee-style",
+        description: "Style of marquee motion (obsolete).",
+    },
+    G3cssProperty {
+        name: "overflow_x",
+        css: "overflow-x",
+        description: "What happens to content overflowing the left and right edges.",
+    },
+    G3cssProperty {
+        name: "overflow_y",
+        css: "overflow-y",
+        description: "What happens to content overflowing the top and bottom edges.",
+    },
+    G3cssProperty {
+        name: "overflow_style",
+        css: "overflow-style",
+        description: "Preferred scrolling mechanism for overflowing content (obsolete).",
+    },
+    G3cssProperty {
+        name: "rotation",
+        css: "rotation",
+        description: "Rotation of the element around its rotation point (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_align",
+        css: "box-align",
+        description: "Alignment of children in a legacy flexible box (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_direction",
+        css: "box-direction",
+        description: "Direction of children in a legacy flexible box (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_flex",
+        css: "box-flex",
+        description: "Flexibility of a legacy flexible box child (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_flex_group",
+        css: "box-flex-group",
+        description: "Flex group of a legacy flexible box child (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_lines",
+        css: "box-lines",
+        description: "Whether a legacy flexible box wraps its children (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_ordinal_group",
+        css: "box-ordinal-group",
+        description: "Display order of a legacy flexible box child (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_orient",
+        css: "box-orient",
+        description: "Orientation of a legacy flexible box (obsolete).",
+    },
+    G3cssProperty {
+        name: "box_pack",
+        css: "box-pack",
+        description: "Packing of children along the main axis of a legacy flexible box (obsolete).",
+    },
+    G3cssProperty {
+        name: "alignment_adjust",
+        css: "alignment-adjust",
+        description: "Alignment point of an inline box (obsolete).",
+    },
+    G3cssProperty {
+        name: "alignment_baseline",
+        css: "alignment-baseline",
+        description: "Baseline an inline box is aligned to.",
+    },
+    G3cssProperty {
+        name: "baseline_shift",
+        css: "baseline-shift",
+        description: "Shift of the baseline, such as for subscripts.",
+    },
+    G3cssProperty {
+        name: "dominant_baseline",
+        css: "dominant-baseline",
+        description: "Baseline used to align the content of the box.",
+    },
+    G3cssProperty {
+        name: "drop_initial_after_adjust",
+        css: "drop-initial-after-adjust",
+        description: "Secondary alignment point of a drop initial (obsolete).",
+    },
+    G3cssProperty {
+        name: "drop_initial_after_align",
+        css: "drop-initial-after-align",
+        description: "Alignment line of a drop initial after adjustment (obsolete).",
+    },
+    G3cssProperty {
+        name: "drop_initial_before_adjust",
+        css: "drop-initial-before-adjust",
+        description: "Primary alignment point of a drop initial (obsolete).",
+    },
+    G3cssProperty {
+        name: "drop_initial_before_align",
+        css: "drop-initial-before-align",
+        description: "Alignment line of a drop initial before adjustment (obsolete).",
+    },
+    G3cssProperty {
+        name: "drop_initial_size",
+        css: "drop-initial-size",
+        description: "Size of a drop initial in lines (obsolete).",
+    },
+    G3cssProperty {
+        name: "drop_initial_value",
+        css: "drop-initial-value",
+        description: "Number of lines a drop initial spans (obsolete).",
+    },
+    G3cssProperty {
+        name: "inline_box_align",
+        css: "inline-box-align",
+        description:
+            "Line of a multi-line inline box aligned with the surrounding text (obsolete).",
+    },
+    G3cssProperty {
+        name: "line_stacking",
+        css: "line-stacking",
+        description: "All line stacking properties at once (obsolete).",
+    },
+    G3cssProperty {
+        name: "line_stacking_ruby",
+        css: "line-stacking-ruby",
+        description: "Line stacking of ruby annotations (obsolete).",
+    },
+    G3cssProperty {
+        name: "line_stacking_shift",
+        css: "line-stacking-shift",
+        description: "Line stacking of shifted elements (obsolete).",
+    },
+    G3cssProperty {
+        name: "line_stacking_strategy",
+        css: "line-stacking-strategy",
+        description: "Line stacking strategy (obsolete).",
+    },
+    G3cssProperty {
+        name: "text_height",
+        css: "text-height",
+        description: "Block-progression size of the text content area (obsolete).",
+    },
+    G3cssProperty {
+        name: "column_count",
+        css: "column-count",
+        description: "Number of columns of a multi-column layout.",
+    },
+    G3cssProperty {
+        name: "column_fill",
+        css: "column-fill",
+        description: "How content is balanced across columns.",
+    },
+    G3cssProperty {
+        name: "column_gap",
+        css: "column-gap",
+        description: "Gap between columns.",
+    },
+    G3cssProperty {
+        name: "column_rule",
+        css: "column-rule",
+        description: "Width, style and color of the line between columns.",
+    },
+    G3cssProperty {
+        name: "column_rule_color",
+        css: "column-rule-color",
+        description: "Color of the line between columns.",
+    },
+    G3cssProperty {
+        name: "column_rule_style",
+        css: "column-rule-style",
+        description: "Line style of the line between columns.",
+    },
+    G3cssProperty {
+        name: "column_rule_width",
+        css: "column-rule-width",
+        description: "Width of the line between columns.",
+    },
+    G3cssProperty {
+        name: "column_span",
+        css: "column-span",
+        description: "Whether the element spans all columns.",
+    },
+    G3cssProperty {
+        name: "column_width",
+        css: "column-width",
+        description: "Ideal width of the columns.",
+    },
+    G3cssProperty {
+        name: "columns",
+        css: "columns",
+        description: "Column width and count at once.",
+    },
+    G3cssProperty {
+        name: "animation",
+        css: "animation",
+        description: "All animation properties at once.",
+    },
+    G3cssProperty {
+        name: "animation_name",
+        css: "animation-name",
+        description: "Names of the keyframes animations applied to the element.",
+    },
+    G3cssProperty {
+        name: "animation_duration",
+        css: "animation-duration",
+        description: "Time an animation takes to complete one cycle.",
+    },
+    G3cssProperty {
+        name: "animation_timing_function",
+        css: "animation-timing-function",
+        description: "How an animation progresses through each cycle.",
+    },
+    G3cssProperty {
+        name: "animation_delay",
+        css: "animation-delay",
+        description: "Time to wait before starting an animation.",
+    },
+    G3cssProperty {
+        name: "animation_fill_mode",
+        css: "animation-fill-mode",
+        description: "Styles applied before and after an animation runs.",
+    },
+    G3cssProperty {
+        name: "animation_iteration_count",
+        css: "animation-iteration-count",
+        description: "Number of times an animation cycle plays.",
+    },
+    G3cssProperty {
+        name: "animation_direction",
+        css: "animation-direction",
+        description: "Whether an animation plays forwards, backwards or alternates.",
+    },
+    G3cssProperty {
+        name: "animation_play_state",
+        css: "animation-play-state",
+        description: "Whether an animation is running or paused.",
+    },
+    G3cssProperty {
+        name: "transform",
+        css: "transform",
+        description: "Rotation, scaling, skewing or translation of the element.",
+    },
+    G3cssProperty {
+        name: "transform_origin",
+        css: "transform-origin",
+        description: "Origin of the element's transformations.",
+    },
+    G3cssProperty {
+        name: "transform_style",
+        css: "transform-style",
+        description: "Whether children are positioned in 3D space or flattened.",
+    },
+    G3cssProperty {
+        name: "perspective",
+        css: "perspective",
+        description: "Distance between the viewer and the z=0 plane.",
+    },
+    G3cssProperty {
+        name: "perspective_origin",
+        css: "perspective-origin",
+        description: "Position the viewer is looking from.",
+    },
+    G3cssProperty {
+        name: "backface_visibility",
+        css: "backface-visibility",
+        description: "Whether the back face of the element is visible when turned.",
+    },
+    G3cssProperty {
+        name: "transition",
+        css: "transition",
+        description: "All transition properties at once.",
+    },
+    G3cssProperty {
+        name: "transition_property",
+        css: "transition-property",
+        description: "Properties a transition applies to.",
+    },
+    G3cssProperty {
+        name: "transition_duration",
+        css: "transition-duration",
+        description: "Time a transition takes to complete.",
+    },
+    G3cssProperty {
+        name: "transition_timing_function",
+        css: "transition-timing-function",
+        description: "How intermediate values of a transition are calculated.",
+    },
+    G3cssProperty {
+        name: "transition_delay",
+        css: "transition-delay",
+        description: "Time to wait before starting a transition.",
+    },
+    G3cssProperty {
+        name: "orphans",
+        css: "orphans",
+        description: "Minimum lines of a block left at the bottom of a page or column.",
+    },
+    G3cssProperty {
+        name: "page_break_after",
+        css: "page-break-after",
+        description: "Page break after the element (legacy, see break-after).",
+    },
+    G3cssProperty {
+        name: "page_break_before",
+        css: "page-break-before",
+        description: "Page break before the element (legacy, see break-before).",
+    },
+    G3cssProperty {
+        name: "page_break_inside",
+        css: "page-break-inside",
+        description: "Page break inside the element (legacy, see break-inside).",
+    },
+    G3cssProperty {
+        name: "widows",
+        css: "widows",
+        description: "Minimum lines of a block left at the top of a page or column.",
+    },
+    G3cssProperty {
+        name: "mark",
+        css: "mark",
+        description: "Audio cue marks before and after the element (obsolete).",
+    },
+    G3cssProperty {
+        name: "mark_after",
+        css: "mark-after",
+        description: "Audio cue mark after the element (obsolete).",
+    },
+    G3cssProperty {
+        name: "mark_before",
+        css: "mark-before",
+        description: "Audio cue mark before the element (obsolete).",
+    },
+    G3cssProperty {
+        name: "phonemes",
+        css: "phonemes",
+        description: "Pronunciation of the element's text (obsolete).",
+    },
+    G3cssProperty {
+        name: "rest",
+        css: "rest",
+        description: "Pauses before and after the element in speech (obsolete).",
+    },
+    G3cssProperty {
+        name: "rest_after",
+        css: "rest-after",
+        description: "Pause after the element in speech (obsolete).",
+    },
+    G3cssProperty {
+        name: "rest_before",
+        css: "rest-before",
+        description: "Pause before the element in speech (obsolete).",
+    },
+    G3cssProperty {
+        name: "voice_balance",
+        css: "voice-balance",
+        description: "Spatial balance of speech (obsolete).",
+    },
+    G3cssProperty {
+        name: "voice_duration",
+        css: "voice-duration",
+        description: "Time taken to speak the element (obsolete).",
+    },
+    G3cssProperty {
+        name: "voice_pitch",
+        css: "voice-pitch",
+        description: "Average pitch of speech (obsolete).",
+    },
+    G3cssProperty {
+        name: "voice_pitch_range",
+        css: "voice-pitch-range",
+        description: "Variation of speech pitch (obsolete).",
+    },
+    G3cssProperty {
+        name: "voice_rate",
+        css: "voice-rate",
+        description: "Speaking rate (obsolete).",
+    },
+    G3cssProperty {
+        name: "voice_stress",
+        css: "voice-stress",
+        description: "Strength of emphasis in speech (obsolete).",
+    },
+    G3cssProperty {
+        name: "voice_volume",
+        css: "voice-volume",
+        description: "Volume of speech (obsolete).",
+    },
+    G3cssProperty {
+        name: "appearance",
+        css: "appearance",
+        description: "Whether form controls use their native platform styling.",
+    },
+    G3cssProperty {
+        name: "box_sizing",
+        css: "box-sizing",
+        description: "Whether width and height include padding and border.",
+    },
+    G3cssProperty {
+        name: "icon",
+        css: "icon",
+        description: "Iconic equivalent of the element (obsolete).",
+    },
+    G3cssProperty {
+        name: "nav_down",
+        css: "nav-down",
+        description: "Element focused when navigating down (obsolete).",
+    },
+    G3cssProperty {
+        name: "nav_index",
+        css: "nav-index",
+        description: "Sequential navigation order (obsolete).",
+    },
+    G3cssProperty {
+        name: "nav_left",
+        css: "nav-left",
+        description: "Element focused when navigating left (obsolete).",
+    },
+    G3cssProperty {
+        name: "nav_right",
+        css: "nav-right",
+        description: "Element focused when navigating right (obsolete).",
+    },
+    G3cssProperty {
+        name: "nav_up",
+        css: "nav-up",
+        description: "Element focused when navigating up (obsolete).",
+    },
+    G3cssProperty {
+        name: "outline_offset",
+        css: "outline-offset",
+        description: "Space between the outline and the border.",
+    },
+    G3cssProperty {
+        name: "resize",
+        css: "resize",
+        description: "Whether and how the user can resize the element.",
+    },
+    G3cssProperty {
+        name: "quotes",
+        css: "quotes",
+        description: "Quotation marks used by generated quotes.",
+    },
+    G3cssProperty {
+        name: "rotate",
+        css: "rotate",
+        description: "Rotates the element independently of transform.",
+    },
+    G3cssProperty {
+        name: "translate",
+        css: "translate",
+        description: "Moves the element independently of transform.",
+    },
+    G3cssProperty {
+        name: "user_select",
+        css: "user-select",
+        description: "Whether the user can select the element's text.",
+    },
+    G3cssProperty {
+        name: "writing_mode",
+        css: "writing-mode",
+        description: "Whether lines of text are laid out horizontally or vertically.",
+    },
+    G3cssProperty {
+        name: "object_position",
+        css: "object-position",
+        description: "Alignment of replaced content, such as an image, within its box.",
+    },
+    G3cssProperty {
+        name: "object_fit",
+        css: "object-fit",
+        description: "How replaced content, such as an image, is resized to fit its box.",
+    },
+    G3cssProperty {
+        name: "justify_self",
+        css: "justify-self",
+        description: "Alignment of a box within its container along the inline axis.",
+    },
+    G3cssProperty {
+        name: "justify_content",
+        css: "justify-content",
+        description: "Distribution of space between and around items along the main axis.",
+    },
+    G3cssProperty {
+        name: "justify_items",
+        css: "justify-items",
+        description: "Default justify-self of every child.",
+    },
+    G3cssProperty {
+        name: "align_self",
+        css: "align-self",
+        description: "Alignment of an item along the cross axis, overriding align-items.",
+    },
+    G3cssProperty {
+        name: "align_content",
+        css: "align-content",
+        description: "Distribution of space between and around lines along the cross axis.",
+    },
+    G3cssProperty {
+        name: "align_items",
+        css: "align-items",
+        description: "Alignment of every item along the cross axis.",
+    },
+    G3cssProperty {
+        name: "grid",
+        css: "grid",
+        description: "All explicit and implicit grid properties at once.",
+    },
+    G3cssProperty {
+        name: "grid_area",
+        css: "grid-area",
+        description: "Grid item size and location within the grid.",
+    },
+    G3cssProperty {
+        name: "grid_auto_columns",
+        css: "grid-auto-columns",
+        description: "Size of implicitly created grid columns.",
+    },
+    G3cssProperty {
+        name: "grid_auto_flow",
+        css: "grid-auto-flow",
+        description: "How auto-placed items flow into the grid.",
+    },
+    G3cssProperty {
+        name: "grid_auto_rows",
+        css: "grid-auto-rows",
+        description: "Size of implicitly created grid rows.",
+    },
+    G3cssProperty {
+        name: "grid_column",
+        css: "grid-column",
+        description: "Grid item size and location within the grid columns.",
+    },
+    G3cssProperty {
+        name: "grid_column_end",
+        css: "grid-column-end",
+        description: "End line of a grid item within the grid columns.",
+    },
+    G3cssProperty {
+        name: "grid_column_start",
+        css: "grid-column-start",
+        description: "Start line of a grid item within the grid columns.",
+    },
+    G3cssProperty {
+        name: "grid_row",
+        css: "grid-row",
+        description: "Grid item size and location within the grid rows.",
+    },
+    G3cssProperty {
+        name: "grid_row_end",
+        css: "grid-row-end",
+        description: "End line of a grid item within the grid rows.",
+    },
+    G3cssProperty {
+        name: "grid_row_start",
+        css: "grid-row-start",
+        description: "Start line of a grid item within the grid rows.",
+    },
+    G3cssProperty {
+        name: "grid_template",
+        css: "grid-template",
+        description: "Grid columns, rows and areas at once.",
+    },
+    G3cssProperty {
+        name: "grid_template_areas",
+        css: "grid-template-areas",
+        description: "Named grid areas.",
+    },
+    G3cssProperty {
+        name: "grid_template_columns",
+        css: "grid-template-columns",
+        description: "Line names and sizes of the grid columns.",
+    },
+    G3cssProperty {
+        name: "grid_template_rows",
+        css: "grid-template-rows",
+        description: "Line names and sizes of the grid rows.",
+    },
+    G3cssProperty {
+        name: "scrollbar_color",
+        css: "scrollbar-color",
+        description: "Colors of the scrollbar thumb and track.",
+    },
+    G3cssProperty {
+        name: "scrollbar_width",
+        css: "scrollbar-width",
+        description: "Thickness of the scrollbars.",
+    },
+    G3cssProperty {
+        name: "scrollbar_gutter",
+        css: "scrollbar-gutter",
+        description: "Space reserved for the scrollbar.",
+    },
+];
+
+/// Looks up a G3CSS property by its grammar name.
+///
+/// # Arguments
+///
+/// - `name` - The G3CSS property name (e.g. `background_color`).
+///
+/// # Returns
+///
+/// The matching `G3cssProperty`, or `None` if the grammar does not define it.
+pub fn find_g3css_property(name: &str) -> Option<&'static G3cssProperty> {
+    G3CSS_PROPERTIES
+        .iter()
+        .find(|property| property.name == name)
+}
+
+/// Converts a G3CSS property name into the CSS property it emits.
+///
+/// # Arguments
+///
+/// - `name` - The G3CSS property name (e.g. `background_color`).
+///
+/// # Returns
+///
+/// The CSS property name (e.g. `background-color`), or `None` if the property is unknown.
+pub fn css_property_name(name: &str) -> Option<&'static str> {
+    find_g3css_property(name).map(|property| property.css)
+}
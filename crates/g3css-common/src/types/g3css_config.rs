@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+/// Enum representing how the transformer turns G3CSS classes into CSS classes.
+/// Static - emits one CSS class per G3CSS class
+/// Atomic - splits every declaration into a deduplicated single-declaration class
+#[derive(PartialEq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum G3cssOutputMode {
+    #[default]
+    Static,
+    Atomic,
+}
+
+/// Enum representing how the names of atomic classes are derived from their hash.
+/// Short - six base 36 digits, lengthened only when two declarations collide
+/// Full - every digit of the hash, so names never depend on the other declarations
+#[derive(PartialEq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum G3cssHashStrategy {
+    #[default]
+    Short,
+    Full,
+}
+
+/// Enum representing how the dark theme is selected in the generated CSS.
+/// Media - follows the `prefers-color-scheme` media query
+/// Class - applies when the root element has the `dark` class
+/// Attribute - applies when the root element has `data-theme="dark"`
+#[derive(PartialEq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum G3cssThemeStrategy {
+    #[default]
+    Media,
+    Class,
+    Attribute,
+}
+
+/// Where the `.g3css` files of a project are looked for.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct G3cssSourceConfig {
+    /// Directories scanned for sources, relative to the project root.
+    pub roots: Vec<String>,
+    /// Globs a source must match, relative to the project root.
+    pub include: Vec<String>,
+    /// Globs excluding sources, relative to the project root.
+    pub exclude: Vec<String>,
+}
+
+impl Default for G3cssSourceConfig {
+    fn default() -> Self {
+        Self {
+            roots: vec![".".to_string()],
+            include: vec!["**/*.g3css".to_string()],
+            exclude: vec![],
+        }
+    }
+}
+
+/// Where and how the generated CSS is written.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct G3cssOutputConfig {
+    /// Directory receiving the generated CSS, relative to the project root.
+    pub dir: String,
+    /// Directory of the build cache, relative to the project root.
+    pub cache_dir: String,
+    /// Selects between static and utility-first (atomic) output.
+    pub mode: G3cssOutputMode,
+    /// Whether the generated CSS is minified.
+    pub minify: bool,
+    /// Whether the optimization pass runs over the generated rules.
+    pub optimize: bool,
+}
+
+impl Default for G3cssOutputConfig {
+    fn default() -> Self {
+        Self {
+            dir: "dist".to_string(),
+            cache_dir: ".g3css-cache".to_string(),
+            mode: G3cssOutputMode::default(),
+            minify: false,
+            optimize: true,
+        }
+    }
+}
+
+/// Media query condition of a `panoramic_viewer` breakpoint.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct G3cssBreakpointConfig {
+    pub name: String,
+    pub media: String,
+}
+
+/// Configuration of a G3CSS project, read from `galadriel.toml` or `g3css.json`.
+///
+/// Every key is optional, missing keys taking their default value.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct G3cssConfig {
+    pub source: G3cssSourceConfig,
+    pub output: G3cssOutputConfig,
+    /// Breakpoints available to `panoramic_viewer`, in cascade order.
+    pub breakpoints: Vec<G3cssBreakpointConfig>,
+    /// Browserslist-style query selecting the browsers whose vendor prefixes are added
+    /// (e.g. `last 2 versions, safari >= 14`), or `None` to emit no prefixes.
+    pub browser_targets: Option<String>,
+    /// How atomic class names are derived from their hash.
+    pub hashing: G3cssHashStrategy,
+    /// How the dark theme is selected.
+    pub theme: G3cssThemeStrategy,
+    /// Names of the plugins to run.
+    pub plugins: Vec<String>,
+}
+
+impl Default for G3cssConfig {
+    fn default() -> Self {
+        let breakpoint = |name: &str, media: &str| G3cssBreakpointConfig {
+            name: name.to_string(),
+            media: media.to_string(),
+        };
+
+        Self {
+            source: G3cssSourceConfig::default(),
+            output: G3cssOutputConfig::default(),
+            breakpoints: vec![
+                breakpoint("mobile", "(max-width: 640px)"),
+                breakpoint("tablet", "(min-width: 641px) and (max-width: 1024px)"),
+                breakpoint("laptop", "(min-width: 1025px) and (max-width: 1440px)"),
+                breakpoint("desktop", "(min-width: 1441px)"),
+            ],
+            browser_targets: None,
+            hashing: G3cssHashStrategy::default(),
+            theme: G3cssThemeStrategy::default(),
+            plugins: vec![],
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::types::g3css_config::G3cssSourceConfig;
+
+/// Checks whether a path names a `.g3css` file.
+pub fn is_g3css_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "g3css")
+}
+
+/// Collects every `.g3css` file below a directory.
+///
+/// # Arguments
+///
+/// - `root` - The directory to scan.
+///
+/// # Returns
+///
+/// The paths of every `.g3css` file, in sorted order. Unreadable directories are skipped.
+pub fn scan_directory(root: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(directory) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            if path.is_dir() {
+                pending.push(path);
+            } else if is_g3css_file(&path) {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Matches one path segment against one glob segment, where `*` matches any run of
+/// characters and `?` matches a single character.
+fn segment_matches(pattern: &[char], segment: &[char]) -> bool {
+    match (pattern.first(), segment.first()) {
+        (None, None) => true,
+        (Some('*'), _) => {
+            segment_matches(&pattern[1..], segment)
+                || (!segment.is_empty() && segment_matches(pattern, &segment[1..]))
+        }
+        (Some('?'), Some(_)) => segment_matches(&pattern[1..], &segment[1..]),
+        (Some(expected), Some(actual)) if expected == actual => {
+            segment_matches(&pattern[1..], &segment[1..])
+        }
+        _ => false,
+    }
+}
+
+/// Matches path segments against glob segments, where `**` matches any number of segments.
+fn segments_match(pattern: &[&str], path: &[&str]) -> bool {
+    match (pattern.first(), path.first()) {
+        (None, None) => true,
+        (Some(&"**"), _) => {
+            segments_match(&pattern[1..], path)
+                || (!path.is_empty() && segments_match(pattern, &path[1..]))
+        }
+        (Some(expected), Some(actual)) => {
+            let expected = expected.chars().collect::<Vec<_>>();
+            let actual = actual.chars().collect::<Vec<_>>();
+
+            segment_matches(&expected, &actual) && segments_match(&pattern[1..], &path[1..])
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether a relative path matches a glob.
+///
+/// Globs are split on `/`: `**` matches any number of directories, `*` any run of
+/// characters within a segment and `?` a single character.
+///
+/// # Arguments
+///
+/// - `pattern` - The glob, such as `components/**/*.g3css`.
+/// - `path` - The path, relative to the directory the glob is written for.
+///
+/// # Returns
+///
+/// Whether `path` matches `pattern`.
+pub fn glob_matches(pattern: &str, path: &Path) -> bool {
+    let pattern = pattern.trim_start_matches("./");
+    let pattern = pattern.split('/').collect::<Vec<_>>();
+
+    let path = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    segments_match(&pattern, &path)
+}
+
+/// Checks whether a file is a source of a project.
+///
+/// # Arguments
+///
+/// - `root` - The project root, which the globs are relative to.
+/// - `sources` - The source configuration of the project.
+/// - `path` - The file to check.
+///
+/// # Returns
+///
+/// Whether `path` is a `.g3css` file below a source root, matching an include glob and no
+/// exclude glob.
+pub fn is_source_file(root: &Path, sources: &G3cssSourceConfig, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+
+    let in_root =
+        sources
+            .roots
+            .iter()
+            .any(|source_root| match source_root.trim_start_matches("./") {
+                "." | "" => true,
+                source_root => relative.starts_with(source_root),
+            });
+
+    is_g3css_file(path)
+        && in_root
+        && sources
+            .include
+            .iter()
+            .any(|pattern| glob_matches(pattern, relative))
+        && !sources
+            .exclude
+            .iter()
+            .any(|pattern| glob_matches(pattern, relative))
+}
+
+/// Collects the source files of a project.
+///
+/// # Arguments
+///
+/// - `root` - The project root.
+/// - `sources` - The source configuration of the project.
+///
+/// # Returns
+///
+/// The paths of every source file, in sorted order and without duplicates.
+pub fn source_files(root: &Path, sources: &G3cssSourceConfig) -> Vec<PathBuf> {
+    let mut files = sources
+        .roots
+        .iter()
+        .flat_map(|source_root| match source_root.trim_start_matches("./") {
+            "." | "" => scan_directory(root),
+            source_root => scan_directory(&root.join(source_root)),
+        })
+        .filter(|path| is_source_file(root, sources, path))
+        .collect::<Vec<_>>();
+
+    files.sort();
+    files.dedup();
+    files
+}
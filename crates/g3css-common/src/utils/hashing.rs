@@ -0,0 +1,53 @@
+/// FNV-1a 64-bit offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+/// FNV-1a 64-bit prime.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Hashes a byte slice with the FNV-1a 64-bit algorithm.
+///
+/// Unlike `std::collections::hash_map::DefaultHasher`, the result is stable across
+/// Rust releases and platforms, so it can be used for generated names and on-disk keys.
+///
+/// # Arguments
+///
+/// - `bytes` - The bytes to hash.
+///
+/// # Returns
+///
+/// The 64-bit FNV-1a hash of `bytes`.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    // Fold every byte into the hash using the FNV-1a xor-then-multiply step.
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Encodes a number using lowercase base 36 digits (`0-9a-z`).
+///
+/// # Arguments
+///
+/// - `value` - The number to encode.
+///
+/// # Returns
+///
+/// The base 36 representation of `value`.
+pub fn to_base36(mut value: u64) -> String {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    // Zero has no significant digits, so it is handled explicitly.
+    if value == 0 {
+        return "0".to_string();
+    }
+
+    // Collect the digits from least to most significant, then reverse them.
+    let mut digits = vec![];
+
+    while value > 0 {
+        digits.push(DIGITS[(value % 36) as usize]);
+        value /= 36;
+    }
+
+    digits.reverse();
+
+    String::from_utf8(digits).unwrap_or_default()
+}
@@ -0,0 +1,81 @@
+use g3css_parser::types::{
+    g3css_children::G3cssChildren, g3css_class::G3cssClass, g3css_node::G3cssNode,
+};
+
+/// Splits a global or component node into its name, extended global and children.
+///
+/// # Arguments
+///
+/// - `node` - The root `G3cssNode` of a parsed file.
+///
+/// # Returns
+///
+/// A tuple with the scope name, the name of the extended global (if any) and the children
+/// of the scope, or `None` if the node is not a global or component.
+pub fn scope_parts(node: &G3cssNode) -> Option<(&str, Option<&str>, &[G3cssChildren])> {
+    // Both globals and components hold their name, extends and children as inner nodes.
+    let inner = match node {
+        G3cssNode::Global(inner) | G3cssNode::Component(inner) => inner,
+        _ => return None,
+    };
+
+    let mut name = "";
+    let mut extends = None;
+    let mut children: &[G3cssChildren] = &[];
+
+    // Collect every part of the scope from its inner nodes.
+    for inner_node in inner {
+        match inner_node {
+            G3cssNode::Name(value) => name = value,
+            G3cssNode::Extends(value) => extends = Some(value.as_str()),
+            G3cssNode::Children(value) => children = value,
+            _ => (),
+        }
+    }
+
+    Some((name, extends, children))
+}
+
+/// Collects every class declared by the given children, in source order.
+///
+/// # Arguments
+///
+/// - `children` - The children of a global or component scope.
+///
+/// # Returns
+///
+/// References to the nodes of every class declared through `class` or `classes`.
+pub fn scope_classes(children: &[G3cssChildren]) -> Vec<&Vec<G3cssClass>> {
+    let mut classes = vec![];
+
+    for child in children {
+        match child {
+            // A single class declaration.
+            G3cssChildren::Class(class) => classes.push(class),
+            // A group of classes declared through `classes`.
+            G3cssChildren::Classes(group) => classes.extend(group.iter()),
+            _ => (),
+        }
+    }
+
+    classes
+}
+
+/// Retrieves the name of a class from its nodes.
+///
+/// # Arguments
+///
+/// - `class` - The nodes of a single class.
+///
+/// # Returns
+///
+/// The class name, or an empty string if the class has no name node.
+pub fn class_name(class: &[G3cssClass]) -> &str {
+    class
+        .iter()
+        .find_map(|node| match node {
+            G3cssClass::ClassName(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .unwrap_or("")
+}
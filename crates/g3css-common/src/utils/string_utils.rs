@@ -0,0 +1,65 @@
+/// Computes the Levenshtein distance between two words: the number of characters to insert,
+/// delete or substitute to turn one into the other.
+///
+/// # Arguments
+///
+/// - `a` - The first word.
+/// - `b` - The second word.
+///
+/// # Returns
+///
+/// The edit distance between `a` and `b`.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    // Distances from the prefix of `a` read so far to every prefix of `b`.
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Finds the candidates closest to a misspelled word.
+///
+/// # Arguments
+///
+/// - `word` - The misspelled word.
+/// - `candidates` - The known words.
+/// - `max_distance` - The largest distance at which a candidate is still suggested.
+///
+/// # Returns
+///
+/// Every candidate at the smallest distance from `word`, in the order given, or an empty
+/// list if none is within `max_distance`.
+pub fn closest_matches<'a>(
+    word: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+    max_distance: usize,
+) -> Vec<&'a str> {
+    let mut best = max_distance;
+    let mut matches = vec![];
+
+    for candidate in candidates {
+        let distance = levenshtein(word, candidate);
+
+        if distance < best {
+            best = distance;
+            matches.clear();
+        }
+
+        if distance == best {
+            matches.push(candidate);
+        }
+    }
+
+    matches
+}
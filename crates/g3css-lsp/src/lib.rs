@@ -1,3 +1,60 @@
-pub fn g3css_lsp() {
-    println!("Hello, world!");
+pub mod types {
+    pub mod cursor_context;
+    pub mod lexical_declarations;
+    pub mod reference_kind;
+    pub mod workspace;
+    pub mod workspace_document;
+}
+
+pub mod rustal {
+    pub mod client_harness;
+    pub mod code_action_handlers;
+    pub mod completion_handlers;
+    pub mod context_handlers;
+    pub mod diagnostic_handlers;
+    pub mod formatting_handlers;
+    pub mod hover_handlers;
+    pub mod navigation_handlers;
+    pub mod notification_handlers;
+    pub mod outline_handlers;
+    pub mod position_utils;
+    pub mod request_handlers;
+    pub mod semantic_token_handlers;
+    pub mod server_handlers;
+}
+
+use g3css_parser::types::g3css_error::G3cssError;
+use lsp_server::Connection;
+use rustal::server_handlers::{initialize, lsp_error, main_loop};
+
+/// Runs the G3CSS language server over stdio until the client shuts it down.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if the client broke the protocol or the connection
+///   was lost.
+pub fn g3css_lsp() -> Result<(), G3cssError> {
+    let (connection, io_threads) = Connection::stdio();
+
+    g3css_lsp_serve(&connection)?;
+    // The stdio threads only finish once the connection is dropped.
+    drop(connection);
+
+    io_threads.join().map_err(lsp_error)
+}
+
+/// Runs the G3CSS language server over an existing connection, such as the in-memory
+/// connection used by `G3cssLspClient`.
+///
+/// # Arguments
+///
+/// - `connection` - The connection to the client.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if the client broke the protocol or the connection
+///   was lost.
+pub fn g3css_lsp_serve(connection: &Connection) -> Result<(), G3cssError> {
+    let workspace = initialize(connection)?;
+    main_loop(connection, workspace)
 }
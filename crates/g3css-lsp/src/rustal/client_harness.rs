@@ -0,0 +1,307 @@
+use std::{
+    collections::VecDeque,
+    path::Path,
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use g3css_parser::types::g3css_error::G3cssError;
+use lsp_server::{Connection, Message, Notification, Request, RequestId};
+use lsp_types::{
+    notification::{DidOpenTextDocument, Exit, Initialized, Notification as LspNotification},
+    request::{Initialize, Request as LspRequest, Shutdown},
+    ClientCapabilities, DidOpenTextDocumentParams, InitializeParams, InitializeResult,
+    InitializedParams, TextDocumentItem, Url, WorkspaceFolder,
+};
+
+use crate::g3css_lsp_serve;
+
+use super::server_handlers::lsp_error;
+
+/// How long the client waits for the server before giving up.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// In-process client driving a language server running on another thread, so the server
+/// can be exercised without spawning a process or going through stdio.
+pub struct G3cssLspClient {
+    /// Client end of the in-memory connection.
+    connection: Connection,
+    /// Thread running the server.
+    server: Option<JoinHandle<Result<(), G3cssError>>>,
+    /// Identifier of the next request.
+    next_id: i32,
+    /// Notifications received while waiting for a response.
+    notifications: VecDeque<Notification>,
+}
+
+impl G3cssLspClient {
+    /// Starts a server and performs the `initialize` handshake.
+    ///
+    /// # Arguments
+    ///
+    /// - `root` - The workspace folder whose `.g3css` files the server loads, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(G3cssLspClient, InitializeResult), G3cssError>` - Ok containing the client
+    ///   and the capabilities of the server, or an Err if the handshake failed.
+    pub fn start(root: Option<&Path>) -> Result<(Self, InitializeResult), G3cssError> {
+        let (server_connection, connection) = Connection::memory();
+        let server = thread::spawn(move || g3css_lsp_serve(&server_connection));
+
+        let mut client = Self {
+            connection,
+            server: Some(server),
+            next_id: 0,
+            notifications: VecDeque::new(),
+        };
+
+        let workspace_folders = root
+            .and_then(|root| Url::from_directory_path(root).ok())
+            .map(|uri| {
+                vec![WorkspaceFolder {
+                    uri,
+                    name: "workspace".to_string(),
+                }]
+            });
+
+        let result = client.request::<Initialize>(InitializeParams {
+            capabilities: ClientCapabilities::default(),
+            workspace_folders,
+            ..InitializeParams::default()
+        })?;
+
+        client.notify::<Initialized>(InitializedParams {})?;
+
+        Ok((client, result))
+    }
+
+    /// Receives the next message from the server.
+    fn receive(&self) -> Result<Message, G3cssError> {
+        self.connection
+            .receiver
+            .recv_timeout(RESPONSE_TIMEOUT)
+            .map_err(lsp_error)
+    }
+
+    /// Sends a request and waits for its response.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<R::Result, G3cssError>` - Ok containing the result of the request, or an
+    ///   Err if the server answered with an error or did not answer in time.
+    pub fn request<R: LspRequest>(&mut self, params: R::Params) -> Result<R::Result, G3cssError> {
+        self.next_id += 1;
+        let id = RequestId::from(self.next_id);
+
+        self.connection
+            .sender
+            .send(Message::Request(Request::new(
+                id.clone(),
+                R::METHOD.to_string(),
+                params,
+            )))
+            .map_err(lsp_error)?;
+
+        loop {
+            match self.receive()? {
+                Message::Response(response) if response.id == id => {
+                    if let Some(error) = response.error {
+                        return Err(lsp_error(error.message));
+                    }
+
+                    let result = response.result.unwrap_or(serde_json::Value::Null);
+                    return serde_json::from_value(result).map_err(lsp_error);
+                }
+                Message::Notification(notification) => self.notifications.push_back(notification),
+                _ => (),
+            }
+        }
+    }
+
+    /// Sends a notification.
+    pub fn notify<N: LspNotification>(&self, params: N::Params) -> Result<(), G3cssError> {
+        self.connection
+            .sender
+            .send(Message::Notification(Notification::new(
+                N::METHOD.to_string(),
+                params,
+            )))
+            .map_err(lsp_error)
+    }
+
+    /// Waits for the next notification of a kind, skipping the notifications of other kinds.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<N::Params, G3cssError>` - Ok containing the parameters of the notification,
+    ///   or an Err if none arrived in time.
+    pub fn wait_notification<N: LspNotification>(&mut self) -> Result<N::Params, G3cssError> {
+        loop {
+            let notification = match self.notifications.pop_front() {
+                Some(notification) => notification,
+                None => match self.receive()? {
+                    Message::Notification(notification) => notification,
+                    _ => continue,
+                },
+            };
+
+            if notification.method == N::METHOD {
+                return serde_json::from_value(notification.params).map_err(lsp_error);
+            }
+        }
+    }
+
+    /// Opens a document with its initial text.
+    pub fn open_document(&self, uri: Url, text: &str) -> Result<(), G3cssError> {
+        self.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri,
+                language_id: "g3css".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+    }
+
+    /// Shuts the server down and waits for its thread to finish.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), G3cssError>` - The result of the server, or an Err if the shutdown
+    ///   sequence failed.
+    pub fn shutdown(mut self) -> Result<(), G3cssError> {
+        self.request::<Shutdown>(())?;
+        self.notify::<Exit>(())?;
+
+        match self.server.take().map(JoinHandle::join) {
+            Some(Ok(result)) => result,
+            Some(Err(_)) => Err(lsp_error("the server thread panicked")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{
+        notification::PublishDiagnostics, DiagnosticSeverity, HoverProviderCapability,
+        NumberOrString, PublishDiagnosticsParams,
+    };
+
+    use super::*;
+
+    const GLOBAL: &str = r#"define global scope "APP" {
+    define aliases {
+        bg: background_color,
+    },
+
+    define variables {
+        spacing: "8px",
+    },
+
+    /// Base card.
+    define class "CARD" {
+        properties: {
+            bg: "white",
+            padding: "${spacing}",
+        },
+    },
+}
+"#;
+
+    /// Opens a document and waits for the diagnostics published for it.
+    fn open(client: &mut G3cssLspClient, name: &str, text: &str) -> PublishDiagnosticsParams {
+        let uri = Url::parse(&format!("file:///workspace/{}", name)).unwrap();
+        client.open_document(uri.clone(), text).unwrap();
+
+        loop {
+            let params = client.wait_notification::<PublishDiagnostics>().unwrap();
+
+            if params.uri == uri {
+                return params;
+            }
+        }
+    }
+
+    /// Collects the codes of the error diagnostics.
+    fn error_codes(params: &PublishDiagnosticsParams) -> Vec<String> {
+        params
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Some(DiagnosticSeverity::ERROR))
+            .filter_map(|diagnostic| match &diagnostic.code {
+                Some(NumberOrString::String(code)) => Some(code.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn initialize_announces_the_capabilities() {
+        let (client, result) = G3cssLspClient::start(None).unwrap();
+        let capabilities = result.capabilities;
+
+        assert_eq!(
+            capabilities.hover_provider,
+            Some(HoverProviderCapability::Simple(true))
+        );
+        assert!(capabilities.definition_provider.is_some());
+        assert!(capabilities.rename_provider.is_some());
+        assert!(capabilities.document_formatting_provider.is_some());
+
+        client.shutdown().unwrap();
+    }
+
+    #[test]
+    fn valid_documents_have_no_errors() {
+        let (mut client, _) = G3cssLspClient::start(None).unwrap();
+        let params = open(&mut client, "app.g3css", GLOBAL);
+
+        assert_eq!(params.version, Some(1));
+        assert!(error_codes(&params).is_empty());
+
+        client.shutdown().unwrap();
+    }
+
+    #[test]
+    fn parse_errors_are_published() {
+        let (mut client, _) = G3cssLspClient::start(None).unwrap();
+        let params = open(&mut client, "broken.g3css", "define global scope \"APP\" {");
+
+        assert_eq!(error_codes(&params), vec!["parse-error"]);
+
+        client.shutdown().unwrap();
+    }
+
+    #[test]
+    fn references_resolve_through_the_open_global() {
+        let (mut client, _) = G3cssLspClient::start(None).unwrap();
+        open(&mut client, "app.g3css", GLOBAL);
+
+        let component = r#"define component scope "BUTTON" extends global "APP" {
+    define class "PRIMARY" inherits "CARD" {
+        properties: {
+            bg: "${spacing}",
+            fg: "${missing}",
+        },
+    },
+}
+"#;
+        let params = open(&mut client, "button.g3css", component);
+
+        assert_eq!(
+            error_codes(&params),
+            vec!["unknown-alias", "unknown-variable"]
+        );
+
+        client.shutdown().unwrap();
+    }
+
+    #[test]
+    fn shutdown_stops_the_server() {
+        let (client, _) = G3cssLspClient::start(None).unwrap();
+
+        assert!(client.shutdown().is_ok());
+    }
+}
@@ -0,0 +1,800 @@
+use std::collections::HashMap;
+
+use g3css_ast::{
+    rustal::{
+        cst_builder::syntax_tokens,
+        semantic_checks::document_diagnostics,
+        symbol_queries::{ancestor_symbol, child_symbol, named_symbols, symbol_at, variable_theme},
+    },
+    types::{
+        g3css_diagnostic::G3cssDiagnostic, g3css_document::G3cssDocument,
+        g3css_syntax::G3cssSyntaxKind,
+    },
+};
+use g3css_common::{
+    tables::{
+        deprecated_properties::find_g3css_deprecated_property,
+        g3css_keywords::G3CSS_BLOCK_KEYWORDS, g3css_properties::G3CSS_PROPERTIES,
+    },
+    utils::string_utils::closest_matches,
+};
+use g3css_parser::types::g3css_symbol::{G3cssSymbol, G3cssSymbolKind};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
+    Diagnostic, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::types::{cursor_context::CursorContext, workspace::G3cssWorkspace};
+
+use super::{
+    context_handlers::cursor_context,
+    diagnostic_handlers::to_lsp_diagnostic,
+    position_utils::{offset_to_position, position_to_offset, token_span},
+};
+
+/// Indentation of one nesting level in generated source.
+const INDENT: &str = "    ";
+
+/// Builds an edit replacing the source between two byte offsets.
+fn text_edit(source: &str, start: usize, end: usize, new_text: String) -> TextEdit {
+    TextEdit {
+        range: Range {
+            start: offset_to_position(source, start),
+            end: offset_to_position(source, end),
+        },
+        new_text,
+    }
+}
+
+/// Builds a code action applying edits to one or more documents.
+fn edit_action(
+    title: String,
+    kind: CodeActionKind,
+    changes: HashMap<Url, Vec<TextEdit>>,
+    diagnostics: Vec<Diagnostic>,
+    is_preferred: bool,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(kind),
+        diagnostics: (!diagnostics.is_empty()).then_some(diagnostics),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..WorkspaceEdit::default()
+        }),
+        is_preferred: is_preferred.then_some(true),
+        ..CodeAction::default()
+    })
+}
+
+/// Kind and byte offsets of the tokens of a document other than whitespace and comments.
+fn meaningful_tokens(document: &G3cssDocument) -> Vec<(G3cssSyntaxKind, usize, usize)> {
+    syntax_tokens(&document.syntax)
+        .filter(|token| !token.kind().is_trivia())
+        .map(|token| {
+            let (start, end) = token_span(&token);
+            (token.kind(), start, end)
+        })
+        .collect()
+}
+
+/// Leading whitespace of the line holding an offset.
+fn line_indent(source: &str, offset: usize) -> &str {
+    let line_start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line = &source[line_start..];
+
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Offset right after the `{` opening the body of the scope of a document.
+fn scope_body_start(document: &G3cssDocument) -> Option<usize> {
+    let scope = document
+        .symbols
+        .iter()
+        .find(|symbol| symbol.kind == G3cssSymbolKind::Scope)?;
+
+    syntax_tokens(&document.syntax)
+        .filter(|token| token.kind() == G3cssSyntaxKind::LeftBrace)
+        .map(|token| token_span(&token))
+        .find(|(start, _)| *start >= scope.span.end)
+        .map(|(_, end)| end)
+}
+
+/// Builds the edit adding an entry after the last of a list of symbols, or, when the list is
+/// empty, adding a new declaration block at the start of the scope body.
+///
+/// # Arguments
+///
+/// - `document` - The document to edit.
+/// - `last` - The last entry of the block the entry belongs to, if the block exists.
+/// - `entry` - The entry to add (e.g. `bg: background_color`).
+/// - `block` - Builds the declaration block holding the entry, given the indentation of the
+///   scope body, for when the block does not exist yet.
+fn entry_insertion(
+    document: &G3cssDocument,
+    last: Option<&G3cssSymbol>,
+    entry: &str,
+    block: impl FnOnce(&str) -> String,
+) -> Option<TextEdit> {
+    let source = &document.source;
+
+    match last {
+        Some(last) => {
+            let indent = line_indent(source, last.range.start);
+            let line_start = source[..last.range.start]
+                .rfind('\n')
+                .map_or(0, |index| index + 1);
+
+            // Entries written on one line (e.g. `{ a: "1", b: "2" }`) stay on one line.
+            let text = match source[line_start..last.range.start].trim().is_empty() {
+                true => format!(",\n{}{}", indent, entry),
+                false => format!(", {}", entry),
+            };
+
+            Some(text_edit(source, last.range.end, last.range.end, text))
+        }
+        None => {
+            let start = scope_body_start(document)?;
+            Some(text_edit(source, start, start, block(INDENT)))
+        }
+    }
+}
+
+/// Offers to create an alias used by a nickname but declared nowhere, and to replace the
+/// nickname with a known property or alias when it looks like a typo.
+fn unknown_alias_actions(
+    workspace: &G3cssWorkspace,
+    uri: &Url,
+    document: &G3cssDocument,
+    symbol: &G3cssSymbol,
+    diagnostic: Diagnostic,
+) -> Vec<CodeActionOrCommand> {
+    let mut actions = vec![];
+    let name = symbol.name.as_str();
+
+    // Known aliases and properties, for typo suggestions.
+    let mut aliases = document
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.kind == G3cssSymbolKind::Alias)
+        .map(|symbol| symbol.name.as_str())
+        .collect::<Vec<_>>();
+
+    if let Some((_, global)) = workspace.extended_global(document) {
+        aliases.extend(
+            global
+                .symbols
+                .iter()
+                .filter(|symbol| symbol.kind == G3cssSymbolKind::Alias)
+                .map(|symbol| symbol.name.as_str()),
+        );
+    }
+
+    let properties = G3CSS_PROPERTIES.iter().map(|property| property.name);
+    let max_distance = (name.len() / 3).max(1);
+
+    for suggestion in closest_matches(name, aliases.into_iter().chain(properties), max_distance) {
+        let edit = text_edit(
+            &document.source,
+            symbol.span.start,
+            symbol.span.end,
+            suggestion.to_string(),
+        );
+
+        actions.push(edit_action(
+            format!("Did you mean `{}`?", suggestion),
+            CodeActionKind::QUICKFIX,
+            HashMap::from([(uri.clone(), vec![edit])]),
+            vec![diagnostic.clone()],
+            true,
+        ));
+    }
+
+    // The new alias points to the property closest to its name, to be adjusted by the author.
+    let property = closest_matches(
+        name,
+        G3CSS_PROPERTIES.iter().map(|property| property.name),
+        usize::MAX,
+    )
+    .first()
+    .copied()
+    .unwrap_or("display");
+
+    let entry = format!("{}: {}", name, property);
+    let last = document
+        .symbols
+        .iter()
+        .rev()
+        .find(|symbol| symbol.kind == G3cssSymbolKind::Alias);
+
+    let edit = entry_insertion(document, last, &entry, |indent| {
+        format!(
+            "\n{indent}define aliases {{\n{indent}{indent}{entry},\n{indent}}},",
+            indent = indent,
+            entry = entry
+        )
+    });
+
+    if let Some(edit) = edit {
+        actions.push(edit_action(
+            format!("Create missing alias `{}` for `{}`", name, property),
+            CodeActionKind::QUICKFIX,
+            HashMap::from([(uri.clone(), vec![edit])]),
+            vec![diagnostic],
+            false,
+        ));
+    }
+
+    actions
+}
+
+/// Offers to declare a variable referenced but declared nowhere, either as a plain variable
+/// of the document or in both themes of its global.
+fn unknown_variable_actions(
+    workspace: &G3cssWorkspace,
+    uri: &Url,
+    document: &G3cssDocument,
+    symbol: &G3cssSymbol,
+    diagnostic: Diagnostic,
+) -> Vec<CodeActionOrCommand> {
+    let mut actions = vec![];
+    let entry = format!("{}: \"\"", symbol.name);
+
+    // A plain variable of the document itself.
+    let last = document
+        .symbols
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(index, symbol)| {
+            symbol.kind == G3cssSymbolKind::Variable
+                && variable_theme(&document.symbols, *index).is_none()
+        })
+        .map(|(_, symbol)| symbol);
+
+    let edit = entry_insertion(document, last, &entry, |indent| {
+        format!(
+            "\n{indent}define variables {{\n{indent}{indent}{entry},\n{indent}}},",
+            indent = indent,
+            entry = entry
+        )
+    });
+
+    if let Some(edit) = edit {
+        actions.push(edit_action(
+            format!("Declare missing variable `{}`", symbol.name),
+            CodeActionKind::QUICKFIX,
+            HashMap::from([(uri.clone(), vec![edit])]),
+            vec![diagnostic.clone()],
+            false,
+        ));
+    }
+
+    // Themes only exist in globals, so components declare the variable in their global.
+    let (target_uri, target) = match document.is_global() {
+        true => (uri, document),
+        false => match workspace.extended_global(document) {
+            Some(global) => global,
+            None => return actions,
+        },
+    };
+
+    let mut edits = vec![];
+    let mut missing_themes = String::new();
+
+    for theme in ["light", "dark"] {
+        let Some(theme_index) = named_symbols(&target.symbols, G3cssSymbolKind::Theme, theme)
+            .into_iter()
+            .next()
+        else {
+            let lines = [
+                format!("{}define {} theme {{", INDENT, theme),
+                format!("{}define variables {{", INDENT.repeat(2)),
+                format!("{}{},", INDENT.repeat(3), entry),
+                format!("{}}},", INDENT.repeat(2)),
+                format!("{}}},", INDENT),
+            ];
+
+            missing_themes.push_str(&format!("\n{}", lines.join("\n")));
+            continue;
+        };
+
+        let last = target.symbols.iter().rev().find(|symbol| {
+            symbol.kind == G3cssSymbolKind::Variable && symbol.parent == Some(theme_index)
+        });
+
+        if let Some(last) = last {
+            edits.extend(entry_insertion(target, Some(last), &entry, |_| {
+                String::new()
+            }));
+        }
+    }
+
+    if !missing_themes.is_empty() {
+        if let Some(start) = scope_body_start(target) {
+            edits.push(text_edit(&target.source, start, start, missing_themes));
+        }
+    }
+
+    if !edits.is_empty() {
+        actions.push(edit_action(
+            format!(
+                "Declare missing variable `{}` in light and dark theme",
+                symbol.name
+            ),
+            CodeActionKind::QUICKFIX,
+            HashMap::from([(target_uri.clone(), edits)]),
+            vec![diagnostic],
+            false,
+        ));
+    }
+
+    actions
+}
+
+/// Offers to replace a deprecated property with its replacement, rewriting its value when
+/// the replacement spells it differently.
+fn deprecated_property_actions(
+    uri: &Url,
+    document: &G3cssDocument,
+    index: usize,
+    diagnostic: Diagnostic,
+) -> Vec<CodeActionOrCommand> {
+    let symbol = &document.symbols[index];
+
+    let Some(deprecated) = find_g3css_deprecated_property(&symbol.name) else {
+        return vec![];
+    };
+
+    let Some(replacement) = deprecated.replacement else {
+        return vec![];
+    };
+
+    let source = &document.source;
+    let mut edits = vec![text_edit(
+        source,
+        symbol.span.start,
+        symbol.span.end,
+        replacement.to_string(),
+    )];
+
+    if let Some(value) = child_symbol(&document.symbols, index, G3cssSymbolKind::Value) {
+        if let Some((_, new_value)) = deprecated
+            .values
+            .iter()
+            .find(|(old_value, _)| *old_value == value.name.trim())
+        {
+            edits.push(text_edit(
+                source,
+                value.span.start,
+                value.span.end,
+                new_value.to_string(),
+            ));
+        }
+    }
+
+    vec![edit_action(
+        format!(
+            "Replace deprecated `{}` with `{}`",
+            symbol.name, replacement
+        ),
+        CodeActionKind::QUICKFIX,
+        HashMap::from([(uri.clone(), edits)]),
+        vec![diagnostic],
+        true,
+    )]
+}
+
+/// Offers to replace misspelled block keywords written on the lines of a range (e.g.
+/// `first_of_typ: {`) with the closest keywords of the grammar.
+fn misspelled_keyword_actions(
+    uri: &Url,
+    document: &G3cssDocument,
+    start: usize,
+    end: usize,
+    diagnostics: &[Diagnostic],
+) -> Vec<CodeActionOrCommand> {
+    let source = &document.source;
+    let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = source[end..]
+        .find('\n')
+        .map_or(source.len(), |index| end + index);
+
+    let tokens = meaningful_tokens(document);
+
+    let mut actions = vec![];
+
+    for (position, &(kind, token_start, token_end)) in tokens.iter().enumerate() {
+        if kind != G3cssSyntaxKind::Identifier || token_end < line_start || token_start > line_end {
+            continue;
+        }
+
+        // Block keywords are followed by a colon and an opening brace.
+        let opens_block = matches!(
+            tokens.get(position + 1..position + 3),
+            Some([
+                (G3cssSyntaxKind::Colon, ..),
+                (G3cssSyntaxKind::LeftBrace, ..)
+            ])
+        );
+
+        let CursorContext::ClassBody { in_breakpoint } =
+            cursor_context(&document.syntax, token_start)
+        else {
+            continue;
+        };
+
+        let word = &source[token_start..token_end];
+        let keywords = G3CSS_BLOCK_KEYWORDS
+            .iter()
+            .filter(|keyword| !(in_breakpoint && keyword.name == "panoramic_viewer"))
+            .map(|keyword| keyword.name);
+
+        if !opens_block || keywords.clone().any(|keyword| keyword == word) {
+            continue;
+        }
+
+        for suggestion in closest_matches(word, keywords, (word.len() / 3).max(1)) {
+            let edit = text_edit(source, token_start, token_end, suggestion.to_string());
+
+            actions.push(edit_action(
+                format!("Did you mean `{}`?", suggestion),
+                CodeActionKind::QUICKFIX,
+                HashMap::from([(uri.clone(), vec![edit])]),
+                diagnostics.to_vec(),
+                true,
+            ));
+        }
+    }
+
+    actions
+}
+
+/// Represents a declaration of a class, identified by where it is written and what it says.
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct ClassEntry {
+    /// Index of the property or nickname symbol.
+    index: usize,
+    /// Breakpoint holding the declaration, if any.
+    breakpoint: Option<String>,
+    /// Keyword of the block holding the declaration.
+    block: String,
+    /// Property or nickname key.
+    key: String,
+    /// Raw value.
+    value: String,
+}
+
+impl ClassEntry {
+    /// Whether two entries declare the same thing in the same block.
+    fn same_declaration(&self, other: &ClassEntry) -> bool {
+        self.breakpoint == other.breakpoint
+            && self.block == other.block
+            && self.key == other.key
+            && self.value == other.value
+    }
+}
+
+/// Collects the declarations of a class, including the ones of its breakpoints.
+fn class_entries(symbols: &[G3cssSymbol], class: usize) -> Vec<ClassEntry> {
+    symbols
+        .iter()
+        .enumerate()
+        .filter(|(index, symbol)| {
+            matches!(
+                symbol.kind,
+                G3cssSymbolKind::Property | G3cssSymbolKind::Nickname
+            ) && ancestor_symbol(symbols, *index, G3cssSymbolKind::Class) == Some(class)
+        })
+        .filter_map(|(index, symbol)| {
+            let block = &symbols[symbol.parent?];
+            let breakpoint = ancestor_symbol(symbols, index, G3cssSymbolKind::Breakpoint)
+                .map(|breakpoint| symbols[breakpoint].name.clone());
+            let value = child_symbol(symbols, index, G3cssSymbolKind::Value)
+                .map_or(String::new(), |value| value.name.clone());
+
+            Some(ClassEntry {
+                index,
+                breakpoint,
+                block: block.name.clone(),
+                key: symbol.name.clone(),
+                value,
+            })
+        })
+        .collect()
+}
+
+/// Whether a class inherits another class or marks its declarations as important, either
+/// of which changes what inheriting from it means.
+fn is_plain_class(document: &G3cssDocument, class: usize) -> bool {
+    let symbol = &document.symbols[class];
+
+    child_symbol(&document.symbols, class, G3cssSymbolKind::Inherits).is_none()
+        && !document.source[symbol.range.start..symbol.range.end].contains("!important")
+}
+
+/// Extends the span of a list item over the comma separating it from its neighbour, so the
+/// list stays well formed once the item is removed.
+fn removal_span(document: &G3cssDocument, start: usize, end: usize) -> (usize, usize) {
+    let tokens = meaningful_tokens(document);
+
+    let next = tokens
+        .iter()
+        .position(|(_, token_start, _)| *token_start >= end);
+
+    match next.map(|next| (tokens[next], tokens.get(next + 1))) {
+        // Remove the trailing comma and the space up to the next item.
+        Some(((G3cssSyntaxKind::Comma, _, comma_end), following)) => {
+            let end = match following {
+                Some((kind, following_start, _)) if *kind != G3cssSyntaxKind::RightBrace => {
+                    *following_start
+                }
+                _ => comma_end,
+            };
+
+            (start, end)
+        }
+        // The last item without a trailing comma takes the comma before it.
+        _ => match tokens
+            .iter()
+            .rev()
+            .find(|(_, _, token_end)| *token_end <= start)
+        {
+            Some((G3cssSyntaxKind::Comma, comma_start, _)) => (*comma_start, end),
+            _ => (start, end),
+        },
+    }
+}
+
+/// Offers to make a class inherit from a similar class, when every declaration of the other
+/// class is already written in this one, removing the declarations it would inherit.
+fn inherit_similar_class_actions(
+    workspace: &G3cssWorkspace,
+    uri: &Url,
+    document: &G3cssDocument,
+    class: usize,
+) -> Vec<CodeActionOrCommand> {
+    let symbols = &document.symbols;
+
+    if !is_plain_class(document, class) {
+        return vec![];
+    }
+
+    let entries = class_entries(symbols, class);
+
+    // Classes that can be inherited: the ones of the document and of its global.
+    let mut candidates = vec![document];
+    candidates.extend(
+        workspace
+            .extended_global(document)
+            .map(|(_, global)| global),
+    );
+
+    let mut best: Option<(&str, Vec<usize>)> = None;
+
+    for candidate in candidates {
+        for (index, symbol) in candidate.symbols.iter().enumerate() {
+            let same_class = std::ptr::eq(candidate, document) && index == class;
+
+            if symbol.kind != G3cssSymbolKind::Class
+                || same_class
+                || symbol.name == symbols[class].name
+                || !is_plain_class(candidate, index)
+            {
+                continue;
+            }
+
+            let parent_entries = class_entries(&candidate.symbols, index);
+            let mut matched: Vec<usize> = vec![];
+
+            for parent_entry in &parent_entries {
+                if let Some(entry) = entries.iter().find(|entry| {
+                    !matched.contains(&entry.index) && entry.same_declaration(parent_entry)
+                }) {
+                    matched.push(entry.index);
+                }
+            }
+
+            // The class must keep declarations of its own, none of them overriding an
+            // inherited one, since inherited declarations come first.
+            let remaining = entries
+                .iter()
+                .filter(|entry| !matched.contains(&entry.index))
+                .collect::<Vec<_>>();
+            let overrides = remaining.iter().any(|entry| {
+                parent_entries.iter().any(|parent_entry| {
+                    parent_entry.breakpoint == entry.breakpoint
+                        && parent_entry.block == entry.block
+                        && parent_entry.key == entry.key
+                })
+            });
+
+            if parent_entries.is_empty()
+                || matched.len() != parent_entries.len()
+                || remaining.is_empty()
+                || overrides
+            {
+                continue;
+            }
+
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_matched)| matched.len() > best_matched.len())
+            {
+                best = Some((symbol.name.as_str(), matched));
+            }
+        }
+    }
+
+    let Some((parent, mut removed)) = best else {
+        return vec![];
+    };
+
+    // Containers left without declarations are removed as a whole, innermost first.
+    for (index, symbol) in symbols.iter().enumerate().rev() {
+        let is_container = matches!(
+            symbol.kind,
+            G3cssSymbolKind::Block | G3cssSymbolKind::Breakpoint | G3cssSymbolKind::PanoramicViewer
+        );
+
+        if !is_container || ancestor_symbol(symbols, index, G3cssSymbolKind::Class) != Some(class) {
+            continue;
+        }
+
+        let children = symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| {
+                child.parent == Some(index) && !matches!(child.kind, G3cssSymbolKind::Value)
+            })
+            .map(|(child, _)| child)
+            .collect::<Vec<_>>();
+
+        if !children.is_empty() && children.iter().all(|child| removed.contains(child)) {
+            removed.retain(|removed| !children.contains(removed));
+            removed.push(index);
+        }
+    }
+
+    let mut spans = removed
+        .iter()
+        .map(|index| {
+            removal_span(
+                document,
+                symbols[*index].range.start,
+                symbols[*index].range.end,
+            )
+        })
+        .collect::<Vec<_>>();
+    spans.sort();
+
+    // Neighbouring items may claim the same comma, so overlapping spans are merged.
+    let mut merged: Vec<(usize, usize)> = vec![];
+
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let source = &document.source;
+    // The class name is followed by its closing quote.
+    let name_end = symbols[class].span.end + 1;
+    let mut edits = vec![text_edit(
+        source,
+        name_end,
+        name_end,
+        format!(" inherits \"{}\"", parent),
+    )];
+
+    edits.extend(
+        merged
+            .into_iter()
+            .map(|(start, end)| text_edit(source, start, end, String::new())),
+    );
+
+    vec![edit_action(
+        format!("Inherit from similar class `{}`", parent),
+        CodeActionKind::REFACTOR_REWRITE,
+        HashMap::from([(uri.clone(), edits)]),
+        vec![],
+        false,
+    )]
+}
+
+/// Whether a diagnostic overlaps the byte range `start..end`, touching included.
+fn overlaps(diagnostic: &G3cssDiagnostic, start: usize, end: usize) -> bool {
+    diagnostic.span.start <= end && start <= diagnostic.span.end
+}
+
+/// Answers a `textDocument/codeAction` request.
+///
+/// Quick fixes are offered for the diagnostics in the range: creating a missing alias,
+/// declaring a missing variable, replacing a deprecated property and fixing a misspelled
+/// block keyword or nickname. A class name in the range also offers to inherit from a
+/// similar class.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document and range, and the diagnostics the client knows about.
+///
+/// # Returns
+///
+/// The actions available in the range, or None if the document is unknown.
+pub fn code_actions(
+    workspace: &G3cssWorkspace,
+    params: CodeActionParams,
+) -> Option<CodeActionResponse> {
+    let uri = &params.text_document.uri;
+    let document = &workspace.get(uri)?.document;
+    let source = &document.source;
+    let start = position_to_offset(source, params.range.start);
+    let end = position_to_offset(source, params.range.end);
+
+    let mut actions = vec![];
+    let diagnostics = document_diagnostics(
+        document,
+        &workspace.all_documents(),
+        &workspace.breakpoint_names(),
+    );
+
+    for diagnostic in diagnostics
+        .iter()
+        .filter(|diagnostic| overlaps(diagnostic, start, end))
+    {
+        let lsp_diagnostic = to_lsp_diagnostic(source, diagnostic);
+        let index = symbol_at(&document.symbols, diagnostic.span.start);
+        let symbol = index.map(|index| &document.symbols[index]);
+
+        match (diagnostic.code, index, symbol) {
+            ("unknown-alias", _, Some(symbol)) => actions.extend(unknown_alias_actions(
+                workspace,
+                uri,
+                document,
+                symbol,
+                lsp_diagnostic,
+            )),
+            ("unknown-variable", _, Some(symbol)) => actions.extend(unknown_variable_actions(
+                workspace,
+                uri,
+                document,
+                symbol,
+                lsp_diagnostic,
+            )),
+            ("deprecated-property", Some(index), _) => actions.extend(deprecated_property_actions(
+                uri,
+                document,
+                index,
+                lsp_diagnostic,
+            )),
+            _ => (),
+        }
+    }
+
+    // Misspelled keywords usually break parsing, so they are found from the tokens.
+    let parse_errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.code == "parse-error")
+        .map(|diagnostic| to_lsp_diagnostic(source, diagnostic))
+        .collect::<Vec<_>>();
+
+    actions.extend(misspelled_keyword_actions(
+        uri,
+        document,
+        start,
+        end,
+        &parse_errors,
+    ));
+
+    if let Some(class) = symbol_at(&document.symbols, start)
+        .filter(|index| document.symbols[*index].kind == G3cssSymbolKind::Class)
+    {
+        actions.extend(inherit_similar_class_actions(
+            workspace, uri, document, class,
+        ));
+    }
+
+    Some(actions)
+}
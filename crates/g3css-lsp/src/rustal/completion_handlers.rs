@@ -0,0 +1,189 @@
+use g3css_ast::{rustal::semantic_checks::find_global, types::g3css_document::G3cssDocument};
+use g3css_common::tables::{
+    g3css_keywords::G3CSS_BLOCK_KEYWORDS,
+    g3css_properties::{find_g3css_property, G3CSS_PROPERTIES},
+};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, Documentation,
+};
+
+use crate::types::{cursor_context::CursorContext, workspace::G3cssWorkspace};
+
+use super::{
+    context_handlers::{cursor_context, lexical_declarations},
+    position_utils::position_to_offset,
+};
+
+/// Builds a completion item with a detail and a documentation string.
+fn completion_item(
+    label: &str,
+    kind: CompletionItemKind,
+    detail: String,
+    documentation: &str,
+) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        detail: Some(detail),
+        documentation: (!documentation.is_empty())
+            .then(|| Documentation::String(documentation.to_string())),
+        ..CompletionItem::default()
+    }
+}
+
+/// Every property of the property table.
+fn property_items() -> Vec<CompletionItem> {
+    G3CSS_PROPERTIES
+        .iter()
+        .map(|property| {
+            completion_item(
+                property.name,
+                CompletionItemKind::PROPERTY,
+                property.css.to_string(),
+                property.description,
+            )
+        })
+        .collect()
+}
+
+/// The block keywords accepted by a class, or by a breakpoint when `in_breakpoint` is set.
+fn block_keyword_items(in_breakpoint: bool) -> Vec<CompletionItem> {
+    let mut items = G3CSS_BLOCK_KEYWORDS
+        .iter()
+        // Breakpoints cannot be nested.
+        .filter(|keyword| !(in_breakpoint && keyword.name == "panoramic_viewer"))
+        .map(|keyword| {
+            let detail = match keyword.selector {
+                "" => keyword.name.to_string(),
+                selector => selector.to_string(),
+            };
+
+            completion_item(
+                keyword.name,
+                CompletionItemKind::KEYWORD,
+                detail,
+                keyword.description,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    items.push(completion_item(
+        "!important",
+        CompletionItemKind::KEYWORD,
+        "!important".to_string(),
+        "Marks every declaration of the class as important when set to true.",
+    ));
+
+    items
+}
+
+/// The breakpoints accepted inside `panoramic_viewer`.
+fn breakpoint_items(workspace: &G3cssWorkspace) -> Vec<CompletionItem> {
+    workspace
+        .options
+        .breakpoints
+        .iter()
+        .map(|(name, condition)| {
+            completion_item(name, CompletionItemKind::UNIT, condition.clone(), "")
+        })
+        .collect()
+}
+
+/// Collects the document and, if it extends one, its global scope.
+fn visible_documents<'a>(
+    workspace: &'a G3cssWorkspace,
+    document: &'a G3cssDocument,
+) -> Vec<&'a G3cssDocument> {
+    let mut visible = vec![document];
+    let documents = workspace.all_documents();
+
+    if let Some(global) = lexical_declarations(&document.syntax)
+        .extends
+        .and_then(|name| find_global(&documents, &name))
+    {
+        visible.push(global);
+    }
+
+    visible
+}
+
+/// The aliases declared by the document or by the global scope it extends.
+fn alias_items(documents: &[&G3cssDocument]) -> Vec<CompletionItem> {
+    let mut items: Vec<CompletionItem> = vec![];
+
+    for document in documents {
+        for (alias, property) in lexical_declarations(&document.syntax).aliases {
+            // Aliases of the component shadow the ones of its global scope.
+            if items.iter().any(|item| item.label == alias) {
+                continue;
+            }
+
+            let description = find_g3css_property(&property).map_or("", |found| found.description);
+
+            items.push(completion_item(
+                &alias,
+                CompletionItemKind::REFERENCE,
+                format!("alias of {}", property),
+                description,
+            ));
+        }
+    }
+
+    items
+}
+
+/// The variables declared by the document or by the global scope it extends, with every value
+/// they take across themes.
+fn variable_items(documents: &[&G3cssDocument]) -> Vec<CompletionItem> {
+    let mut variables: Vec<(String, Vec<String>)> = vec![];
+
+    for document in documents {
+        for (name, value) in lexical_declarations(&document.syntax).variables {
+            match variables.iter_mut().find(|(known, _)| *known == name) {
+                Some((_, values)) => values.push(value),
+                None => variables.push((name, vec![value])),
+            }
+        }
+    }
+
+    variables
+        .into_iter()
+        .map(|(name, values)| {
+            completion_item(&name, CompletionItemKind::VARIABLE, values.join(" | "), "")
+        })
+        .collect()
+}
+
+/// Answers a `textDocument/completion` request.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document and position to complete.
+///
+/// # Returns
+///
+/// The completions for the context of the cursor, or None if nothing can be completed there.
+pub fn completion(
+    workspace: &G3cssWorkspace,
+    params: CompletionParams,
+) -> Option<CompletionResponse> {
+    let position = params.text_document_position;
+    let document = &workspace.get(&position.text_document.uri)?.document;
+    let offset = position_to_offset(&document.source, position.position);
+
+    let items = match cursor_context(&document.syntax, offset) {
+        CursorContext::ClassBody { in_breakpoint } => block_keyword_items(in_breakpoint),
+        CursorContext::Block => {
+            let mut items = alias_items(&visible_documents(workspace, document));
+            items.extend(property_items());
+            items
+        }
+        CursorContext::Breakpoints => breakpoint_items(workspace),
+        CursorContext::AliasTarget => property_items(),
+        CursorContext::VariableReference => variable_items(&visible_documents(workspace, document)),
+        CursorContext::Other => return None,
+    };
+
+    Some(CompletionResponse::Array(items))
+}
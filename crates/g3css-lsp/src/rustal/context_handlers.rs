@@ -0,0 +1,224 @@
+use g3css_ast::{
+    rustal::{cst_builder::syntax_tokens, g3css_lexer::is_quote},
+    types::g3css_syntax::{G3cssSyntaxKind, G3cssSyntaxNode, G3cssSyntaxToken},
+};
+use g3css_common::tables::g3css_keywords::find_g3css_block_keyword;
+
+use crate::types::{cursor_context::CursorContext, lexical_declarations::LexicalDeclarations};
+
+use super::position_utils::token_span;
+
+/// Enum representing the kind of construct a brace or bracket opens.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum Frame {
+    Scope,
+    Aliases,
+    Variables,
+    Theme,
+    ClassList,
+    Class,
+    Block,
+    PanoramicViewer,
+    Breakpoint,
+    Other,
+}
+
+/// Tracks the nesting of a source while its tokens are read in order.
+#[derive(Default)]
+struct FrameTracker {
+    /// Constructs opened and not closed yet.
+    frames: Vec<Frame>,
+    /// Meaningful tokens read since the last brace, bracket or comma.
+    recent: Vec<G3cssSyntaxToken>,
+}
+
+impl FrameTracker {
+    /// Innermost open construct.
+    fn current(&self) -> Option<Frame> {
+        self.frames.last().copied()
+    }
+
+    /// Whether a recent token has the given text.
+    fn recent_contains(&self, text: &str) -> bool {
+        self.recent.iter().any(|token| token.text() == text)
+    }
+
+    /// Decides which construct a `{` opens from the tokens written before it.
+    fn brace_frame(&self) -> Frame {
+        let first = self.recent.first().map(|token| token.text());
+
+        if self.recent_contains("scope") {
+            Frame::Scope
+        } else if self.recent_contains("aliases") {
+            Frame::Aliases
+        } else if self.recent_contains("variables") {
+            Frame::Variables
+        } else if self.recent_contains("theme") {
+            Frame::Theme
+        } else if self.recent_contains("class") || self.current() == Some(Frame::ClassList) {
+            Frame::Class
+        } else if first == Some("panoramic_viewer") {
+            Frame::PanoramicViewer
+        } else if self.current() == Some(Frame::PanoramicViewer) {
+            Frame::Breakpoint
+        } else if first.is_some_and(|first| find_g3css_block_keyword(first).is_some()) {
+            Frame::Block
+        } else {
+            Frame::Other
+        }
+    }
+
+    /// Reads the next meaningful token.
+    fn push(&mut self, token: G3cssSyntaxToken) {
+        match token.kind() {
+            G3cssSyntaxKind::LeftBrace => {
+                let frame = self.brace_frame();
+                self.frames.push(frame);
+                self.recent.clear();
+            }
+            G3cssSyntaxKind::LeftBracket => {
+                let frame = if self.recent_contains("classes") {
+                    Frame::ClassList
+                } else {
+                    Frame::Other
+                };
+
+                self.frames.push(frame);
+                self.recent.clear();
+            }
+            G3cssSyntaxKind::RightBrace | G3cssSyntaxKind::RightBracket => {
+                self.frames.pop();
+                self.recent.clear();
+            }
+            G3cssSyntaxKind::Comma => self.recent.clear(),
+            _ => self.recent.push(token),
+        }
+    }
+}
+
+/// Finds the unclosed `${` before the cursor inside a string token.
+fn in_variable_reference(text_before_cursor: &str) -> bool {
+    match text_before_cursor.rfind("${") {
+        Some(start) => !text_before_cursor[start..].contains('}'),
+        None => false,
+    }
+}
+
+/// Works out what can be written at a position of a source.
+///
+/// The tokens of the concrete syntax tree (CST) are scanned rather than its nodes, so the
+/// context is found even while the source is incomplete and the tree holds no nodes.
+///
+/// # Arguments
+///
+/// - `root` - The `Root` node of the CST of the source, built by `syntax_tree`.
+/// - `offset` - The byte offset of the cursor.
+///
+/// # Returns
+///
+/// The context of the cursor.
+pub fn cursor_context(root: &G3cssSyntaxNode, offset: usize) -> CursorContext {
+    let mut tracker = FrameTracker::default();
+
+    for token in syntax_tokens(root) {
+        let (start, end) = token_span(&token);
+
+        if end >= offset && start < offset {
+            match token.kind() {
+                // Inside a value, only variable references can be completed.
+                G3cssSyntaxKind::String => {
+                    return if in_variable_reference(&token.text()[..offset - start]) {
+                        CursorContext::VariableReference
+                    } else {
+                        CursorContext::Other
+                    };
+                }
+                // The identifier being typed is the one completed.
+                G3cssSyntaxKind::Identifier => break,
+                G3cssSyntaxKind::LineComment | G3cssSyntaxKind::BlockComment if end > offset => {
+                    return CursorContext::Other;
+                }
+                _ => (),
+            }
+        }
+
+        if start >= offset {
+            break;
+        }
+
+        if !token.kind().is_trivia() {
+            tracker.push(token);
+        }
+    }
+
+    let recent = tracker
+        .recent
+        .iter()
+        .map(|token| token.kind())
+        .collect::<Vec<_>>();
+
+    match (tracker.current(), recent.as_slice()) {
+        (Some(Frame::Class), []) => CursorContext::ClassBody {
+            in_breakpoint: false,
+        },
+        (Some(Frame::Breakpoint), []) => CursorContext::ClassBody {
+            in_breakpoint: true,
+        },
+        (Some(Frame::Block), []) => CursorContext::Block,
+        (Some(Frame::PanoramicViewer), []) => CursorContext::Breakpoints,
+        (Some(Frame::Aliases), [G3cssSyntaxKind::Identifier, G3cssSyntaxKind::Colon]) => {
+            CursorContext::AliasTarget
+        }
+        _ => CursorContext::Other,
+    }
+}
+
+/// Removes the quotes around a string token.
+fn unquote(text: &str) -> &str {
+    let text = text.strip_prefix(is_quote).unwrap_or(text);
+    text.strip_suffix(is_quote).unwrap_or(text)
+}
+
+/// Collects the extended global, aliases and variables of a source by scanning the tokens
+/// of its concrete syntax tree (CST).
+///
+/// # Arguments
+///
+/// - `root` - The `Root` node of the CST of the source, built by `syntax_tree`.
+///
+/// # Returns
+///
+/// The declarations found in the source.
+pub fn lexical_declarations(root: &G3cssSyntaxNode) -> LexicalDeclarations {
+    let mut declarations = LexicalDeclarations::default();
+    let mut tracker = FrameTracker::default();
+
+    for token in syntax_tokens(root).filter(|token| !token.kind().is_trivia()) {
+        let texts = tracker
+            .recent
+            .iter()
+            .map(|token| token.text())
+            .collect::<Vec<_>>();
+
+        match (tracker.current(), texts.as_slice(), token.kind()) {
+            (_, [.., "extends", "global"], G3cssSyntaxKind::String) => {
+                declarations.extends = Some(unquote(token.text()).to_string());
+            }
+            (Some(Frame::Aliases), [alias, ":"], G3cssSyntaxKind::Identifier) => {
+                declarations
+                    .aliases
+                    .push((alias.to_string(), token.text().to_string()));
+            }
+            (Some(Frame::Variables), [name, ":"], G3cssSyntaxKind::String) => {
+                declarations
+                    .variables
+                    .push((name.to_string(), unquote(token.text()).to_string()));
+            }
+            _ => (),
+        }
+
+        tracker.push(token);
+    }
+
+    declarations
+}
@@ -0,0 +1,126 @@
+use g3css_ast::{
+    rustal::semantic_checks::document_diagnostics,
+    types::g3css_diagnostic::{G3cssDiagnostic, G3cssSeverity},
+};
+use g3css_parser::types::g3css_error::G3cssError;
+use lsp_server::{Connection, Message, Notification};
+use lsp_types::{
+    notification::{Notification as _, PublishDiagnostics},
+    Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString, PublishDiagnosticsParams, Url,
+};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::{position_utils::span_to_range, server_handlers::lsp_error};
+
+/// Converts a G3CSS diagnostic into an LSP diagnostic.
+///
+/// Diagnostics pointing at a single position (such as parse errors) are widened to the
+/// character at that position, so editors have something to underline.
+///
+/// # Arguments
+///
+/// - `text` - The text of the document.
+/// - `diagnostic` - The diagnostic to convert.
+///
+/// # Returns
+///
+/// The LSP diagnostic.
+pub fn to_lsp_diagnostic(text: &str, diagnostic: &G3cssDiagnostic) -> Diagnostic {
+    let mut span = diagnostic.span;
+
+    if span.start == span.end {
+        if let Some(character) = text.get(span.start..).and_then(|rest| rest.chars().next()) {
+            if character != '\n' {
+                span.end += character.len_utf8();
+            }
+        }
+    }
+
+    let severity = match diagnostic.severity {
+        G3cssSeverity::Error => DiagnosticSeverity::ERROR,
+        G3cssSeverity::Warning => DiagnosticSeverity::WARNING,
+        G3cssSeverity::Information => DiagnosticSeverity::INFORMATION,
+        G3cssSeverity::Hint => DiagnosticSeverity::HINT,
+    };
+
+    Diagnostic {
+        range: span_to_range(text, &span),
+        severity: Some(severity),
+        code: Some(NumberOrString::String(diagnostic.code.to_string())),
+        source: Some("g3css".to_string()),
+        message: diagnostic.message.clone(),
+        // Lets editors strike deprecated properties through.
+        tags: (diagnostic.code == "deprecated-property").then(|| vec![DiagnosticTag::DEPRECATED]),
+        ..Diagnostic::default()
+    }
+}
+
+/// Sends the diagnostics of a document to the client.
+///
+/// # Arguments
+///
+/// - `connection` - The connection to the client.
+/// - `uri` - The URI of the document.
+/// - `version` - The version of the document the diagnostics belong to.
+/// - `diagnostics` - The diagnostics, empty to clear the previous ones.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if the notification could not be sent.
+pub fn send_diagnostics(
+    connection: &Connection,
+    uri: Url,
+    version: Option<i32>,
+    diagnostics: Vec<Diagnostic>,
+) -> Result<(), G3cssError> {
+    let params = PublishDiagnosticsParams {
+        uri,
+        diagnostics,
+        version,
+    };
+
+    connection
+        .sender
+        .send(Message::Notification(Notification::new(
+            PublishDiagnostics::METHOD.to_string(),
+            params,
+        )))
+        .map_err(lsp_error)
+}
+
+/// Checks every open document and sends its diagnostics to the client.
+///
+/// Every open document is checked again, since a change to a global can fix or break any
+/// component extending it.
+///
+/// # Arguments
+///
+/// - `connection` - The connection to the client.
+/// - `workspace` - The workspace holding the documents.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if the diagnostics could not be sent.
+pub fn publish_diagnostics(
+    connection: &Connection,
+    workspace: &G3cssWorkspace,
+) -> Result<(), G3cssError> {
+    let documents = workspace.all_documents();
+    let breakpoints = workspace.breakpoint_names();
+
+    for uri in workspace.open_uris() {
+        let Some(entry) = workspace.get(&uri) else {
+            continue;
+        };
+
+        let diagnostics = document_diagnostics(&entry.document, &documents, &breakpoints)
+            .iter()
+            .map(|diagnostic| to_lsp_diagnostic(&entry.document.source, diagnostic))
+            .collect();
+
+        send_diagnostics(connection, uri, entry.version, diagnostics)?;
+    }
+
+    Ok(())
+}
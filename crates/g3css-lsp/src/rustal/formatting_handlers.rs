@@ -0,0 +1,38 @@
+use g3css_ast::rustal::g3css_formatter::format_g3css;
+use lsp_types::{DocumentFormattingParams, Range, TextEdit};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::position_utils::offset_to_position;
+
+/// Answers a `textDocument/formatting` request by replacing the whole document with its
+/// canonical formatting.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The parameters of the request.
+///
+/// # Returns
+///
+/// The edits formatting the document, none if it is already formatted, or `None` if the
+/// document is unknown or does not parse, since broken source is never formatted.
+pub fn formatting(
+    workspace: &G3cssWorkspace,
+    params: DocumentFormattingParams,
+) -> Option<Vec<TextEdit>> {
+    let source = &workspace.get(&params.text_document.uri)?.document.source;
+    let formatted = format_g3css(source).ok()?;
+
+    if formatted == *source {
+        return Some(vec![]);
+    }
+
+    Some(vec![TextEdit {
+        range: Range {
+            start: offset_to_position(source, 0),
+            end: offset_to_position(source, source.len()),
+        },
+        new_text: formatted,
+    }])
+}
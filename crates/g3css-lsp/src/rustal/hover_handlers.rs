@@ -0,0 +1,260 @@
+use g3css_ast::{
+    rustal::{
+        doc_comments::doc_comment,
+        semantic_checks::error_diagnostic,
+        symbol_queries::{child_symbol, named_symbols, symbol_at, variable_theme},
+    },
+    types::g3css_document::G3cssDocument,
+};
+use g3css_common::tables::g3css_properties::{css_property_name, find_g3css_property};
+use g3css_parser::types::g3css_symbol::G3cssSymbolKind;
+use g3css_transformer::{
+    g3css_transformer,
+    rustal::{
+        css_emitter::emit_css, scope_handlers::build_scope_context,
+        value_handlers::substitute_variables,
+    },
+    types::transformer_options::{G3cssCssStyle, G3cssOutputMode, G3cssTransformerOptions},
+};
+use lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind, Url};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::position_utils::{position_to_offset, span_to_range};
+
+/// Collects a document and the global it extends, which together hold every declaration
+/// visible from the document.
+fn declaring_documents<'a>(
+    workspace: &'a G3cssWorkspace,
+    uri: &'a Url,
+    document: &'a G3cssDocument,
+) -> Vec<(&'a Url, &'a G3cssDocument)> {
+    let mut documents = vec![(uri, document)];
+    documents.extend(workspace.extended_global(document));
+    documents
+}
+
+/// Describes where a symbol was declared (e.g. `` in `app.g3css`, line 4 ``).
+fn declared_at(
+    workspace: &G3cssWorkspace,
+    uri: &Url,
+    document: &G3cssDocument,
+    index: usize,
+) -> String {
+    format!(
+        "in `{}`, line {}",
+        workspace.display_path(uri),
+        document.symbols[index].span.line
+    )
+}
+
+/// Reads the `///` doc comment above the declaration of a symbol.
+fn symbol_doc(document: &G3cssDocument, index: usize) -> Option<String> {
+    doc_comment(&document.syntax, document.symbols[index].range.start)
+}
+
+/// Shows the doc comment and the CSS the transformer emits for a class declared by a
+/// document.
+fn class_hover(workspace: &G3cssWorkspace, document: &G3cssDocument, name: &str) -> Option<String> {
+    let ast = document.ast.as_deref()?;
+    let global = workspace
+        .extended_global(document)
+        .and_then(|(_, global)| global.ast.as_deref());
+
+    let mut header = format!("**class** `{}`", name);
+
+    let doc = named_symbols(&document.symbols, G3cssSymbolKind::Class, name)
+        .into_iter()
+        .find_map(|index| symbol_doc(document, index));
+
+    if let Some(doc) = doc {
+        header = format!("{}\n\n{}", header, doc);
+    }
+
+    // Atomic output would scatter the class, so the hover always shows its static rules.
+    let options = G3cssTransformerOptions {
+        output_mode: G3cssOutputMode::Static,
+        ..workspace.options.clone()
+    };
+
+    match g3css_transformer(ast, global, &options) {
+        Ok(mut stylesheet) => {
+            stylesheet
+                .rules
+                .retain(|rule| rule.class_name.as_deref() == Some(name));
+
+            Some(format!(
+                "{}\n\n```css\n{}```",
+                header,
+                emit_css(&stylesheet, G3cssCssStyle::Pretty)
+            ))
+        }
+        Err(error) => Some(format!(
+            "{}\n\nCannot be resolved: {}",
+            header,
+            error_diagnostic(&error).message
+        )),
+    }
+}
+
+/// Shows the CSS declaration a nickname expands to, and the alias it uses.
+fn nickname_hover(
+    workspace: &G3cssWorkspace,
+    uri: &Url,
+    document: &G3cssDocument,
+    index: usize,
+) -> Option<String> {
+    let name = &document.symbols[index].name;
+    let global = workspace
+        .extended_global(document)
+        .and_then(|(_, global)| global.ast.as_deref());
+    let context = build_scope_context(document.ast.as_deref()?, global).ok()?;
+
+    let property = context.aliases.get(name)?;
+    let css_property = css_property_name(property)?;
+    let value = child_symbol(&document.symbols, index, G3cssSymbolKind::Value)
+        .map_or("", |value| value.name.as_str());
+
+    // Unknown variables are reported as diagnostics, the raw value is shown meanwhile.
+    let value = substitute_variables(&context, value).unwrap_or_else(|_| value.to_string());
+
+    Some(format!(
+        "**nickname** `{}` → `{}`\n\n```css\n{}: {};\n```\n\n{}",
+        name,
+        property,
+        css_property,
+        value,
+        alias_hover(workspace, uri, document, name)?
+    ))
+}
+
+/// Shows the property an alias points to, its doc comment and where it was declared.
+fn alias_hover(
+    workspace: &G3cssWorkspace,
+    uri: &Url,
+    document: &G3cssDocument,
+    name: &str,
+) -> Option<String> {
+    let mut lines = vec![];
+
+    for (uri, document) in declaring_documents(workspace, uri, document) {
+        for index in named_symbols(&document.symbols, G3cssSymbolKind::Alias, name) {
+            let Some(target) = child_symbol(&document.symbols, index, G3cssSymbolKind::AliasTarget)
+            else {
+                continue;
+            };
+
+            let description =
+                find_g3css_property(&target.name).map_or("", |property| property.description);
+
+            let doc = symbol_doc(document, index).map_or(String::new(), |doc| doc + "\n\n");
+
+            lines.push(format!(
+                "**alias** `{}` → `{}`, declared {}\n\n{}{}",
+                name,
+                target.name,
+                declared_at(workspace, uri, document, index),
+                doc,
+                description
+            ));
+        }
+    }
+
+    (!lines.is_empty()).then(|| lines.join("\n\n---\n\n"))
+}
+
+/// Shows every value of a variable, per theme, where each was declared and their doc
+/// comments.
+fn variable_hover(
+    workspace: &G3cssWorkspace,
+    uri: &Url,
+    document: &G3cssDocument,
+    name: &str,
+) -> Option<String> {
+    let mut lines = vec![];
+    let mut docs = vec![];
+
+    for (uri, document) in declaring_documents(workspace, uri, document) {
+        for index in named_symbols(&document.symbols, G3cssSymbolKind::Variable, name) {
+            // Themes usually repeat the same documentation for each of their values.
+            if let Some(doc) = symbol_doc(document, index) {
+                if !docs.contains(&doc) {
+                    docs.push(doc);
+                }
+            }
+
+            let value = child_symbol(&document.symbols, index, G3cssSymbolKind::Value)
+                .map_or("", |value| value.name.as_str());
+
+            let label = match variable_theme(&document.symbols, index) {
+                Some(theme) => format!("{} theme", theme),
+                None => "value".to_string(),
+            };
+
+            lines.push(format!(
+                "- {}: `{}`, declared {}",
+                label,
+                value,
+                declared_at(workspace, uri, document, index)
+            ));
+        }
+    }
+
+    docs.push(lines.join("\n"));
+
+    (!lines.is_empty()).then(|| format!("**variable** `{}`\n\n{}", name, docs.join("\n\n")))
+}
+
+/// Answers a `textDocument/hover` request.
+///
+/// Classes (and inherited classes) show the CSS the transformer emits for them, nicknames
+/// show the declaration they expand to, and aliases and variables show their values and
+/// where they were declared. Classes, aliases and variables also show their `///` doc
+/// comments.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document and position hovered.
+///
+/// # Returns
+///
+/// The hover, or None if nothing is known about the hovered text.
+pub fn hover(workspace: &G3cssWorkspace, params: HoverParams) -> Option<Hover> {
+    let position = params.text_document_position_params;
+    let uri = &position.text_document.uri;
+    let document = &workspace.get(uri)?.document;
+    let offset = position_to_offset(&document.source, position.position);
+
+    let index = symbol_at(&document.symbols, offset)?;
+    let symbol = &document.symbols[index];
+    let name = symbol.name.as_str();
+
+    let contents = match symbol.kind {
+        G3cssSymbolKind::Class => class_hover(workspace, document, name)?,
+        G3cssSymbolKind::Inherits => {
+            // The inherited class is declared either by the document or by its global.
+            let declared_here =
+                !named_symbols(&document.symbols, G3cssSymbolKind::Class, name).is_empty();
+
+            match workspace.extended_global(document) {
+                Some((_, global)) if !declared_here => class_hover(workspace, global, name)?,
+                _ => class_hover(workspace, document, name)?,
+            }
+        }
+        G3cssSymbolKind::Nickname => nickname_hover(workspace, uri, document, index)?,
+        G3cssSymbolKind::Alias => alias_hover(workspace, uri, document, name)?,
+        G3cssSymbolKind::Variable | G3cssSymbolKind::VariableReference => {
+            variable_hover(workspace, uri, document, name)?
+        }
+        _ => return None,
+    };
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: contents,
+        }),
+        range: Some(span_to_range(&document.source, &symbol.span)),
+    })
+}
@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+
+use g3css_ast::{
+    rustal::symbol_queries::{named_symbols, symbol_at},
+    types::g3css_document::G3cssDocument,
+};
+use g3css_common::tables::{
+    g3css_keywords::find_g3css_block_keyword, g3css_properties::find_g3css_property,
+};
+use g3css_parser::types::g3css_symbol::{G3cssSymbol, G3cssSymbolKind};
+use lsp_types::{
+    GotoDefinitionParams, GotoDefinitionResponse, Location, PrepareRenameResponse, ReferenceParams,
+    RenameParams, TextDocumentPositionParams, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::types::{reference_kind::ReferenceKind, workspace::G3cssWorkspace};
+
+use super::position_utils::{position_to_offset, span_to_range};
+
+/// Finds the document and symbol at a position.
+fn symbol_at_position<'a>(
+    workspace: &'a G3cssWorkspace,
+    position: &'a TextDocumentPositionParams,
+) -> Option<(&'a Url, &'a G3cssDocument, &'a G3cssSymbol)> {
+    let uri = &position.text_document.uri;
+    let document = &workspace.get(uri)?.document;
+    let offset = position_to_offset(&document.source, position.position);
+    let index = symbol_at(&document.symbols, offset)?;
+
+    Some((uri, document, &document.symbols[index]))
+}
+
+/// Finds the document declaring what a name refers to when used in a document: the document
+/// itself if it declares the name, otherwise the global it extends.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `uri` - The URI of the document using the name.
+/// - `document` - The document using the name.
+/// - `kind` - What the name refers to.
+/// - `name` - The name.
+///
+/// # Returns
+///
+/// The URI and document declaring the name, or `None` if it is not declared.
+fn declaring_document<'a>(
+    workspace: &'a G3cssWorkspace,
+    uri: &'a Url,
+    document: &'a G3cssDocument,
+    kind: ReferenceKind,
+    name: &str,
+) -> Option<(&'a Url, &'a G3cssDocument)> {
+    let declares = |document: &G3cssDocument| {
+        !named_symbols(&document.symbols, kind.declaration(), name).is_empty()
+    };
+
+    if declares(document) {
+        return Some((uri, document));
+    }
+
+    workspace
+        .extended_global(document)
+        .filter(|(_, global)| declares(global))
+}
+
+/// Builds the location of a symbol.
+fn symbol_location(uri: &Url, document: &G3cssDocument, symbol: &G3cssSymbol) -> Location {
+    Location {
+        uri: uri.clone(),
+        range: span_to_range(&document.source, &symbol.span),
+    }
+}
+
+/// Collects every declaration and use, across the workspace, of the declaration a symbol
+/// refers to.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `position` - The document and position of the symbol.
+/// - `include_declaration` - Whether the declarations are collected along with the uses.
+///
+/// # Returns
+///
+/// The locations in document order, or `None` if the symbol does not refer to a known
+/// class, alias or variable.
+fn occurrences(
+    workspace: &G3cssWorkspace,
+    position: &TextDocumentPositionParams,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let (uri, document, symbol) = symbol_at_position(workspace, position)?;
+    let kind = ReferenceKind::of(symbol.kind)?;
+    let name = symbol.name.as_str();
+    let (owner, _) = declaring_document(workspace, uri, document, kind, name)?;
+
+    let mut locations = vec![];
+
+    for (other_uri, other) in &workspace.documents {
+        let other = &other.document;
+
+        for symbol in &other.symbols {
+            let wanted = symbol.kind == kind.reference()
+                || (include_declaration && symbol.kind == kind.declaration());
+
+            // Uses in other scopes only count when they resolve to the same declaration.
+            if wanted
+                && symbol.name == name
+                && declaring_document(workspace, other_uri, other, kind, name)
+                    .is_some_and(|(declarer, _)| declarer == owner)
+            {
+                locations.push(symbol_location(other_uri, other, symbol));
+            }
+        }
+    }
+
+    Some(locations)
+}
+
+/// Answers a `textDocument/definition` request.
+///
+/// Inherited classes lead to the class, extended globals to the global, nicknames to their
+/// alias and variable references to every declaration of the variable.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document and position of the reference.
+///
+/// # Returns
+///
+/// The declarations, or None if the position holds no known reference.
+pub fn definition(
+    workspace: &G3cssWorkspace,
+    params: GotoDefinitionParams,
+) -> Option<GotoDefinitionResponse> {
+    let position = &params.text_document_position_params;
+    let (uri, document, symbol) = symbol_at_position(workspace, position)?;
+
+    let locations = match symbol.kind {
+        G3cssSymbolKind::Extends => {
+            let (global_uri, global) = workspace.global(&symbol.name)?;
+            let scope = named_symbols(&global.symbols, G3cssSymbolKind::Scope, &symbol.name);
+
+            scope
+                .into_iter()
+                .map(|index| symbol_location(global_uri, global, &global.symbols[index]))
+                .collect::<Vec<_>>()
+        }
+        G3cssSymbolKind::Inherits
+        | G3cssSymbolKind::Nickname
+        | G3cssSymbolKind::VariableReference => {
+            let reference = ReferenceKind::of(symbol.kind)?;
+            let (owner_uri, owner) =
+                declaring_document(workspace, uri, document, reference, &symbol.name)?;
+
+            named_symbols(&owner.symbols, reference.declaration(), &symbol.name)
+                .into_iter()
+                .map(|index| symbol_location(owner_uri, owner, &owner.symbols[index]))
+                .collect()
+        }
+        _ => return None,
+    };
+
+    Some(GotoDefinitionResponse::Array(locations))
+}
+
+/// Answers a `textDocument/references` request for a class, alias or variable.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document and position of a declaration or use.
+///
+/// # Returns
+///
+/// Every use across the workspace, along with the declarations when asked for.
+pub fn references(workspace: &G3cssWorkspace, params: ReferenceParams) -> Option<Vec<Location>> {
+    occurrences(
+        workspace,
+        &params.text_document_position,
+        params.context.include_declaration,
+    )
+}
+
+/// Checks that a new name can be written where the renamed declaration is used.
+fn validate_name(kind: ReferenceKind, name: &str) -> Result<(), String> {
+    let valid = match kind {
+        ReferenceKind::Class => name
+            .chars()
+            .all(|character| matches!(character, 'A'..='Z' | '0'..='9' | '_')),
+        ReferenceKind::Alias | ReferenceKind::Variable => name
+            .chars()
+            .all(|character| matches!(character, 'a'..='z' | '0'..='9' | '_')),
+    };
+
+    if name.is_empty() || !valid {
+        let characters = match kind {
+            ReferenceKind::Class => "uppercase letters, digits and underscores",
+            _ => "lowercase letters, digits and underscores",
+        };
+
+        return Err(format!(
+            "\"{}\" is not a valid name, only {} are allowed",
+            name, characters
+        ));
+    }
+
+    // A nickname named after a property or a block would be read as that property or block.
+    if kind == ReferenceKind::Alias
+        && (find_g3css_property(name).is_some() || find_g3css_block_keyword(name).is_some())
+    {
+        return Err(format!(
+            "\"{}\" is a G3CSS keyword and cannot name an alias",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that a new name is not already declared where the renamed declaration is visible:
+/// in the document declaring it, the global it extends, or the components extending it.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `owner` - The URI of the document declaring the renamed declaration.
+/// - `kind` - What the renamed declaration declares.
+/// - `name` - The current name.
+/// - `new_name` - The new name.
+///
+/// # Returns
+///
+/// - `Result<(), String>` - An Err naming the scope already declaring the new name.
+fn check_collision(
+    workspace: &G3cssWorkspace,
+    owner: &Url,
+    kind: ReferenceKind,
+    name: &str,
+    new_name: &str,
+) -> Result<(), String> {
+    if name == new_name {
+        return Ok(());
+    }
+
+    for (uri, document) in &workspace.documents {
+        let document = &document.document;
+        let sees_renamed = declaring_document(workspace, uri, document, kind, name)
+            .is_some_and(|(declarer, _)| declarer == owner);
+
+        if !sees_renamed {
+            continue;
+        }
+
+        if let Some((_, declarer)) = declaring_document(workspace, uri, document, kind, new_name) {
+            return Err(format!(
+                "\"{}\" is already declared in scope \"{}\"",
+                new_name,
+                declarer.scope_name().unwrap_or_default()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Answers a `textDocument/prepareRename` request.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document and position to rename.
+///
+/// # Returns
+///
+/// The range of the name to rename, or None if the position holds no known class, alias
+/// or variable.
+pub fn prepare_rename(
+    workspace: &G3cssWorkspace,
+    params: TextDocumentPositionParams,
+) -> Option<PrepareRenameResponse> {
+    let (uri, document, symbol) = symbol_at_position(workspace, &params)?;
+    let kind = ReferenceKind::of(symbol.kind)?;
+
+    declaring_document(workspace, uri, document, kind, &symbol.name)?;
+
+    Some(PrepareRenameResponse::Range(span_to_range(
+        &document.source,
+        &symbol.span,
+    )))
+}
+
+/// Answers a `textDocument/rename` request by renaming a class, alias or variable along with
+/// every use of it across the workspace.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document and position to rename, and the new name.
+///
+/// # Returns
+///
+/// - `Result<Option<WorkspaceEdit>, String>` - Ok containing the edits of every affected
+///   document, or None if the position holds nothing to rename, or an Err if the new name
+///   is not valid or already declared where the renamed declaration is visible.
+pub fn rename(
+    workspace: &G3cssWorkspace,
+    params: RenameParams,
+) -> Result<Option<WorkspaceEdit>, String> {
+    let position = &params.text_document_position;
+
+    let Some((uri, document, symbol)) = symbol_at_position(workspace, position) else {
+        return Ok(None);
+    };
+
+    let Some(kind) = ReferenceKind::of(symbol.kind) else {
+        return Ok(None);
+    };
+
+    validate_name(kind, &params.new_name)?;
+
+    if let Some((owner, _)) = declaring_document(workspace, uri, document, kind, &symbol.name) {
+        check_collision(workspace, owner, kind, &symbol.name, &params.new_name)?;
+    }
+
+    let Some(locations) = occurrences(workspace, position, true) else {
+        return Ok(None);
+    };
+
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+
+    for location in locations {
+        changes.entry(location.uri).or_default().push(TextEdit {
+            range: location.range,
+            new_text: params.new_name.clone(),
+        });
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        ..WorkspaceEdit::default()
+    }))
+}
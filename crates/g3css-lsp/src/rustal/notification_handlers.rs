@@ -0,0 +1,77 @@
+use g3css_parser::types::g3css_error::G3cssError;
+use lsp_server::{Connection, Notification};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as LspNotification,
+    },
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::{
+    diagnostic_handlers::{publish_diagnostics, send_diagnostics},
+    server_handlers::lsp_error,
+};
+
+/// Deserializes the parameters of a notification.
+fn notification_params<N: LspNotification>(
+    notification: Notification,
+) -> Result<N::Params, G3cssError> {
+    serde_json::from_value(notification.params).map_err(lsp_error)
+}
+
+/// Handles a notification of the client.
+///
+/// Opening, changing or closing a document updates the workspace and publishes the
+/// diagnostics of every open document again. Unknown notifications are ignored, as the
+/// protocol requires.
+///
+/// # Arguments
+///
+/// - `connection` - The connection to the client.
+/// - `workspace` - The workspace holding the documents.
+/// - `notification` - The notification to handle.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if the parameters were malformed or the diagnostics
+///   could not be sent.
+pub fn handle_notification(
+    connection: &Connection,
+    workspace: &mut G3cssWorkspace,
+    notification: Notification,
+) -> Result<(), G3cssError> {
+    match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams =
+                notification_params::<DidOpenTextDocument>(notification)?;
+            let document = params.text_document;
+
+            workspace.update(document.uri, document.version, document.text);
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: DidChangeTextDocumentParams =
+                notification_params::<DidChangeTextDocument>(notification)?;
+
+            // The server asks for full synchronization, so the last change holds the text.
+            if let Some(change) = params.content_changes.into_iter().last() {
+                let document = params.text_document;
+                workspace.update(document.uri, document.version, change.text);
+            }
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams =
+                notification_params::<DidCloseTextDocument>(notification)?;
+            let uri = params.text_document.uri;
+
+            workspace.close(&uri);
+            // Closed documents keep no diagnostics in the editor.
+            send_diagnostics(connection, uri, None, vec![])?;
+        }
+        _ => return Ok(()),
+    }
+
+    publish_diagnostics(connection, workspace)
+}
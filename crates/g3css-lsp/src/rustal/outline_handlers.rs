@@ -0,0 +1,104 @@
+use g3css_ast::types::g3css_document::G3cssDocument;
+use g3css_common::tables::g3css_keywords::find_g3css_block_keyword;
+use g3css_parser::types::g3css_symbol::G3cssSymbolKind;
+use lsp_types::{DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, SymbolKind};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::position_utils::span_to_range;
+
+/// Describes a symbol of the outline, returning its kind and detail, or `None` for symbols
+/// left out of the outline.
+fn outline_entry(document: &G3cssDocument, index: usize) -> Option<(SymbolKind, Option<String>)> {
+    let symbol = &document.symbols[index];
+
+    // The first child names the parent class or extended global, when there is one.
+    let first_child = document
+        .symbols
+        .get(index + 1)
+        .filter(|child| child.parent == Some(index));
+
+    match symbol.kind {
+        G3cssSymbolKind::Scope => {
+            let detail = match (document.is_global(), first_child) {
+                (true, _) => "global".to_string(),
+                (false, Some(child)) if child.kind == G3cssSymbolKind::Extends => {
+                    format!("component extends {}", child.name)
+                }
+                (false, _) => "component".to_string(),
+            };
+
+            Some((SymbolKind::NAMESPACE, Some(detail)))
+        }
+        G3cssSymbolKind::Class => {
+            let detail = first_child
+                .filter(|child| child.kind == G3cssSymbolKind::Inherits)
+                .map(|child| format!("inherits {}", child.name));
+
+            Some((SymbolKind::CLASS, detail))
+        }
+        G3cssSymbolKind::Block => {
+            let detail = find_g3css_block_keyword(&symbol.name)
+                .map(|keyword| keyword.selector)
+                .filter(|selector| !selector.is_empty())
+                .map(str::to_string);
+
+            Some((SymbolKind::OBJECT, detail))
+        }
+        G3cssSymbolKind::PanoramicViewer => Some((SymbolKind::OBJECT, None)),
+        G3cssSymbolKind::Breakpoint => Some((SymbolKind::ENUM_MEMBER, None)),
+        _ => None,
+    }
+}
+
+/// Builds the outline entries whose parent is the symbol at `parent`, or the top-level
+/// entries when `parent` is `None`.
+fn outline_children(document: &G3cssDocument, parent: Option<usize>) -> Vec<DocumentSymbol> {
+    let mut children = vec![];
+
+    for (index, symbol) in document.symbols.iter().enumerate() {
+        if symbol.parent != parent {
+            continue;
+        }
+
+        let Some((kind, detail)) = outline_entry(document, index) else {
+            continue;
+        };
+
+        #[allow(deprecated)]
+        children.push(DocumentSymbol {
+            name: symbol.name.clone(),
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: span_to_range(&document.source, &symbol.range),
+            selection_range: span_to_range(&document.source, &symbol.span),
+            children: Some(outline_children(document, Some(index))),
+        });
+    }
+
+    children
+}
+
+/// Answers a `textDocument/documentSymbol` request with the outline of the document: its
+/// global or component, then its classes, then their blocks and breakpoints.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document to outline.
+///
+/// # Returns
+///
+/// The outline, empty if the document fails to parse, or None if the document is unknown.
+pub fn document_symbols(
+    workspace: &G3cssWorkspace,
+    params: DocumentSymbolParams,
+) -> Option<DocumentSymbolResponse> {
+    let document = &workspace.get(&params.text_document.uri)?.document;
+
+    Some(DocumentSymbolResponse::Nested(outline_children(
+        document, None,
+    )))
+}
@@ -0,0 +1,87 @@
+use g3css_ast::types::g3css_syntax::G3cssSyntaxToken;
+use g3css_parser::types::g3css_span::G3cssSpan;
+use lsp_types::{Position, Range};
+
+/// Converts a byte offset into an LSP position, whose character counts UTF-16 code units.
+///
+/// # Arguments
+///
+/// - `text` - The text of the document.
+/// - `offset` - The byte offset, clamped to the text.
+///
+/// # Returns
+///
+/// The zero-based line and character of the offset.
+pub fn offset_to_position(text: &str, offset: usize) -> Position {
+    let mut offset = offset.min(text.len());
+
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+/// Converts an LSP position into a byte offset.
+///
+/// # Arguments
+///
+/// - `text` - The text of the document.
+/// - `position` - The zero-based line and UTF-16 character.
+///
+/// # Returns
+///
+/// The byte offset of the position, clamped to the end of its line or of the text.
+pub fn position_to_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(index) => line_start += index + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |index| line_start + index);
+    let mut units = 0;
+
+    for (index, character) in text[line_start..line_end].char_indices() {
+        if units >= position.character as usize {
+            return line_start + index;
+        }
+
+        units += character.len_utf16();
+    }
+
+    line_end
+}
+
+/// Converts a span into an LSP range.
+pub fn span_to_range(text: &str, span: &G3cssSpan) -> Range {
+    Range {
+        start: offset_to_position(text, span.start),
+        end: offset_to_position(text, span.end),
+    }
+}
+
+/// Finds the byte offsets where a token of a concrete syntax tree (CST) starts and ends.
+///
+/// # Arguments
+///
+/// - `token` - The token.
+///
+/// # Returns
+///
+/// The offsets of the first character of the token and just past its last character.
+pub fn token_span(token: &G3cssSyntaxToken) -> (usize, usize) {
+    let range = token.text_range();
+    (range.start().into(), range.end().into())
+}
@@ -0,0 +1,116 @@
+use g3css_parser::types::g3css_error::G3cssError;
+use lsp_server::{Connection, ErrorCode, Message, Request, Response};
+use lsp_types::request::{
+    CodeActionRequest, Completion, DocumentSymbolRequest, Formatting, GotoDefinition, HoverRequest,
+    PrepareRenameRequest, References, Rename, Request as LspRequest, SemanticTokensFullRequest,
+};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::{
+    code_action_handlers::code_actions,
+    completion_handlers::completion,
+    formatting_handlers::formatting,
+    hover_handlers::hover,
+    navigation_handlers::{definition, prepare_rename, references, rename},
+    outline_handlers::document_symbols,
+    semantic_token_handlers::semantic_tokens_full,
+    server_handlers::lsp_error,
+};
+
+/// Deserializes the parameters of a request, runs its handler and builds the response.
+///
+/// # Arguments
+///
+/// - `request` - The request to answer.
+/// - `handler` - Computes the result from the parameters, or the message of a failure.
+///
+/// # Returns
+///
+/// The response holding the result, an InvalidParams error if the parameters were malformed,
+/// or a RequestFailed error if the handler failed.
+fn respond<R: LspRequest>(
+    request: Request,
+    handler: impl FnOnce(R::Params) -> Result<R::Result, String>,
+) -> Response {
+    let params = match serde_json::from_value::<R::Params>(request.params) {
+        Ok(params) => params,
+        Err(error) => {
+            return Response::new_err(
+                request.id,
+                ErrorCode::InvalidParams as i32,
+                error.to_string(),
+            )
+        }
+    };
+
+    match handler(params).map(serde_json::to_value) {
+        Ok(Ok(result)) => Response::new_ok(request.id, result),
+        Ok(Err(error)) => Response::new_err(
+            request.id,
+            ErrorCode::InternalError as i32,
+            error.to_string(),
+        ),
+        Err(message) => Response::new_err(request.id, ErrorCode::RequestFailed as i32, message),
+    }
+}
+
+/// Answers a request of the client.
+///
+/// # Arguments
+///
+/// - `connection` - The connection to the client.
+/// - `workspace` - The workspace holding the documents.
+/// - `request` - The request to answer.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if the response could not be sent.
+pub fn handle_request(
+    connection: &Connection,
+    workspace: &mut G3cssWorkspace,
+    request: Request,
+) -> Result<(), G3cssError> {
+    let response = match request.method.as_str() {
+        Completion::METHOD => {
+            respond::<Completion>(request, |params| Ok(completion(workspace, params)))
+        }
+        HoverRequest::METHOD => {
+            respond::<HoverRequest>(request, |params| Ok(hover(workspace, params)))
+        }
+        GotoDefinition::METHOD => {
+            respond::<GotoDefinition>(request, |params| Ok(definition(workspace, params)))
+        }
+        References::METHOD => {
+            respond::<References>(request, |params| Ok(references(workspace, params)))
+        }
+        PrepareRenameRequest::METHOD => {
+            respond::<PrepareRenameRequest>(request, |params| Ok(prepare_rename(workspace, params)))
+        }
+        Rename::METHOD => respond::<Rename>(request, |params| rename(workspace, params)),
+        SemanticTokensFullRequest::METHOD => {
+            respond::<SemanticTokensFullRequest>(request, |params| {
+                Ok(semantic_tokens_full(workspace, params))
+            })
+        }
+        CodeActionRequest::METHOD => {
+            respond::<CodeActionRequest>(request, |params| Ok(code_actions(workspace, params)))
+        }
+        Formatting::METHOD => {
+            respond::<Formatting>(request, |params| Ok(formatting(workspace, params)))
+        }
+        DocumentSymbolRequest::METHOD => respond::<DocumentSymbolRequest>(request, |params| {
+            Ok(document_symbols(workspace, params))
+        }),
+        _ => Response::new_err(
+            request.id,
+            ErrorCode::MethodNotFound as i32,
+            format!("Unsupported request \"{}\"", request.method),
+        ),
+    };
+
+    connection
+        .sender
+        .send(Message::Response(response))
+        .map_err(lsp_error)
+}
@@ -0,0 +1,230 @@
+use g3css_ast::{
+    rustal::cst_builder::syntax_tokens, types::g3css_document::G3cssDocument,
+    types::g3css_syntax::G3cssSyntaxKind,
+};
+use g3css_parser::types::g3css_symbol::G3cssSymbolKind;
+use lsp_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens, SemanticTokensLegend,
+    SemanticTokensParams, SemanticTokensResult,
+};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::position_utils::{offset_to_position, token_span};
+
+/// Token types reported by the server, indexed by the tokens it sends.
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::MACRO,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::STRING,
+    SemanticTokenType::COMMENT,
+];
+
+/// Token modifiers reported by the server, indexed by bit.
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[SemanticTokenModifier::DECLARATION];
+
+/// Words of the G3CSS grammar highlighted as keywords wherever they appear.
+const KEYWORDS: &[&str] = &[
+    "define",
+    "global",
+    "component",
+    "scope",
+    "extends",
+    "class",
+    "classes",
+    "inherits",
+    "aliases",
+    "variables",
+    "light",
+    "dark",
+    "theme",
+    "!important",
+    "true",
+    "false",
+];
+
+/// Describes the token types and modifiers the server sends.
+pub fn semantic_tokens_legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Finds the index of a token type in the legend.
+fn type_index(token_type: SemanticTokenType) -> u32 {
+    TOKEN_TYPES
+        .iter()
+        .position(|known| *known == token_type)
+        .unwrap_or_default() as u32
+}
+
+/// Classifies a symbol, returning its token type and whether it is a declaration.
+fn classify_symbol(kind: G3cssSymbolKind) -> Option<(SemanticTokenType, bool)> {
+    match kind {
+        G3cssSymbolKind::Scope => Some((SemanticTokenType::NAMESPACE, true)),
+        G3cssSymbolKind::Extends => Some((SemanticTokenType::NAMESPACE, false)),
+        G3cssSymbolKind::Class => Some((SemanticTokenType::CLASS, true)),
+        G3cssSymbolKind::Inherits => Some((SemanticTokenType::CLASS, false)),
+        G3cssSymbolKind::Property | G3cssSymbolKind::AliasTarget => {
+            Some((SemanticTokenType::PROPERTY, false))
+        }
+        G3cssSymbolKind::Theme | G3cssSymbolKind::Block | G3cssSymbolKind::PanoramicViewer => {
+            Some((SemanticTokenType::KEYWORD, false))
+        }
+        G3cssSymbolKind::Breakpoint => Some((SemanticTokenType::ENUM_MEMBER, false)),
+        G3cssSymbolKind::Alias => Some((SemanticTokenType::MACRO, true)),
+        G3cssSymbolKind::Nickname => Some((SemanticTokenType::MACRO, false)),
+        G3cssSymbolKind::Variable => Some((SemanticTokenType::VARIABLE, true)),
+        G3cssSymbolKind::VariableReference => Some((SemanticTokenType::VARIABLE, false)),
+        // Values are highlighted from the string tokens holding them, quotes included.
+        G3cssSymbolKind::Value => None,
+    }
+}
+
+/// Represents a classified piece of source before it is encoded.
+struct Highlight {
+    start: usize,
+    end: usize,
+    token_type: u32,
+    modifiers: u32,
+}
+
+/// Classifies every piece of a document.
+///
+/// Names come from the symbols of the document, while comments, strings and grammar
+/// keywords come from its tokens, so a document that fails to parse is still highlighted.
+/// Strings are split around the names written inside them (class names, variable
+/// references), since highlights cannot overlap.
+fn highlights(document: &G3cssDocument) -> Vec<Highlight> {
+    let mut highlights = document
+        .symbols
+        .iter()
+        .filter_map(|symbol| {
+            let (token_type, declaration) = classify_symbol(symbol.kind)?;
+
+            Some(Highlight {
+                start: symbol.span.start,
+                end: symbol.span.end,
+                token_type: type_index(token_type),
+                modifiers: declaration as u32,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let names = highlights
+        .iter()
+        .map(|highlight| (highlight.start, highlight.end))
+        .collect::<Vec<_>>();
+    let overlaps_name = |start: usize, end: usize| {
+        names
+            .iter()
+            .any(|(name_start, name_end)| *name_start < end && start < *name_end)
+    };
+
+    for token in syntax_tokens(&document.syntax) {
+        let (token_start, token_end) = token_span(&token);
+        let token_type = match token.kind() {
+            G3cssSyntaxKind::LineComment | G3cssSyntaxKind::BlockComment => {
+                SemanticTokenType::COMMENT
+            }
+            G3cssSyntaxKind::String => SemanticTokenType::STRING,
+            G3cssSyntaxKind::Identifier if KEYWORDS.contains(&token.text()) => {
+                SemanticTokenType::KEYWORD
+            }
+            _ => continue,
+        };
+
+        // Keep the parts of the token that are not names already classified.
+        let mut start = token_start;
+
+        for (name_start, name_end) in names
+            .iter()
+            .filter(|(name_start, name_end)| token_start <= *name_start && *name_end <= token_end)
+            .chain([(token_end, token_end)].iter())
+        {
+            if start < *name_start && !overlaps_name(start, *name_start) {
+                highlights.push(Highlight {
+                    start,
+                    end: *name_start,
+                    token_type: type_index(token_type.clone()),
+                    modifiers: 0,
+                });
+            }
+
+            start = start.max(*name_end);
+        }
+    }
+
+    highlights.sort_by_key(|highlight| highlight.start);
+    highlights
+}
+
+/// Encodes highlights relative to each other, as the protocol requires. Highlights spanning
+/// several lines are split into one token per line.
+fn encode(source: &str, highlights: &[Highlight]) -> Vec<SemanticToken> {
+    let mut tokens = vec![];
+    let (mut previous_line, mut previous_start) = (0, 0);
+
+    for highlight in highlights {
+        let mut line_start = highlight.start;
+
+        for line in source[highlight.start..highlight.end].split('\n') {
+            let line_end = line_start + line.len();
+            let length = source[line_start..line_end]
+                .trim_end_matches('\r')
+                .encode_utf16()
+                .count() as u32;
+
+            if length > 0 {
+                let position = offset_to_position(source, line_start);
+                let delta_line = position.line - previous_line;
+                let delta_start = match delta_line {
+                    0 => position.character - previous_start,
+                    _ => position.character,
+                };
+
+                tokens.push(SemanticToken {
+                    delta_line,
+                    delta_start,
+                    length,
+                    token_type: highlight.token_type,
+                    token_modifiers_bitset: highlight.modifiers,
+                });
+
+                (previous_line, previous_start) = (position.line, position.character);
+            }
+
+            line_start = line_end + 1;
+        }
+    }
+
+    tokens
+}
+
+/// Answers a `textDocument/semanticTokens/full` request.
+///
+/// # Arguments
+///
+/// - `workspace` - The workspace holding the documents.
+/// - `params` - The document to highlight.
+///
+/// # Returns
+///
+/// The semantic tokens of the document, or None if the document is unknown.
+pub fn semantic_tokens_full(
+    workspace: &G3cssWorkspace,
+    params: SemanticTokensParams,
+) -> Option<SemanticTokensResult> {
+    let document = &workspace.get(&params.text_document.uri)?.document;
+
+    Some(SemanticTokensResult::Tokens(SemanticTokens {
+        result_id: None,
+        data: encode(&document.source, &highlights(document)),
+    }))
+}
@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use g3css_parser::types::g3css_error::G3cssError;
+use lsp_server::{Connection, Message};
+use lsp_types::{
+    CodeActionKind, CodeActionOptions, CodeActionProviderCapability, CompletionOptions,
+    HoverProviderCapability, InitializeParams, InitializeResult, OneOf, RenameOptions,
+    SemanticTokensFullOptions, SemanticTokensOptions, ServerCapabilities, ServerInfo,
+    TextDocumentSyncCapability, TextDocumentSyncKind,
+};
+
+use crate::types::workspace::G3cssWorkspace;
+
+use super::{
+    diagnostic_handlers::publish_diagnostics, notification_handlers::handle_notification,
+    request_handlers::handle_request, semantic_token_handlers::semantic_tokens_legend,
+};
+
+/// Converts a protocol, transport or serialization error into a G3CSS error.
+pub fn lsp_error(error: impl Display) -> G3cssError {
+    G3cssError::OtherError(format!("Language server error: {}", error))
+}
+
+/// Describes the features supported by the server.
+pub fn server_capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["{".to_string(), "$".to_string()]),
+            ..CompletionOptions::default()
+        }),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![
+                CodeActionKind::QUICKFIX,
+                CodeActionKind::REFACTOR_REWRITE,
+            ]),
+            ..CodeActionOptions::default()
+        })),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        semantic_tokens_provider: Some(
+            SemanticTokensOptions {
+                legend: semantic_tokens_legend(),
+                full: Some(SemanticTokensFullOptions::Bool(true)),
+                ..SemanticTokensOptions::default()
+            }
+            .into(),
+        ),
+        rename_provider: Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: Default::default(),
+        })),
+        ..ServerCapabilities::default()
+    }
+}
+
+/// Performs the `initialize` handshake and loads the `.g3css` files of the workspace.
+///
+/// # Arguments
+///
+/// - `connection` - The connection to the client.
+///
+/// # Returns
+///
+/// - `Result<G3cssWorkspace, G3cssError>` - Ok containing the workspace holding every file
+///   below the workspace folders (or root) given by the client, or an Err if the handshake
+///   failed.
+pub fn initialize(connection: &Connection) -> Result<G3cssWorkspace, G3cssError> {
+    let (id, params) = connection.initialize_start().map_err(lsp_error)?;
+    let params: InitializeParams = serde_json::from_value(params).map_err(lsp_error)?;
+
+    let result = InitializeResult {
+        capabilities: server_capabilities(),
+        server_info: Some(ServerInfo {
+            name: "g3css-lsp".to_string(),
+            version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        }),
+    };
+
+    connection
+        .initialize_finish(id, serde_json::to_value(result).map_err(lsp_error)?)
+        .map_err(lsp_error)?;
+
+    // Workspace folders supersede the deprecated root URI when the client sends both.
+    #[allow(deprecated)]
+    let roots = match params.workspace_folders {
+        Some(folders) if !folders.is_empty() => {
+            folders.into_iter().map(|folder| folder.uri).collect()
+        }
+        _ => params.root_uri.into_iter().collect::<Vec<_>>(),
+    };
+
+    let mut workspace = G3cssWorkspace::new();
+
+    for root in roots {
+        if let Ok(path) = root.to_file_path() {
+            workspace.load_directory(&path);
+        }
+    }
+
+    Ok(workspace)
+}
+
+/// Handles the messages of the client until it shuts the server down.
+///
+/// # Arguments
+///
+/// - `connection` - The connection to the client.
+/// - `workspace` - The workspace loaded during initialization.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if a message could not be received or answered.
+pub fn main_loop(connection: &Connection, mut workspace: G3cssWorkspace) -> Result<(), G3cssError> {
+    publish_diagnostics(connection, &workspace)?;
+
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request).map_err(lsp_error)? {
+                    return Ok(());
+                }
+
+                handle_request(connection, &mut workspace, request)?;
+            }
+            Message::Notification(notification) => {
+                handle_notification(connection, &mut workspace, notification)?;
+            }
+            // The server sends no requests, so responses are unexpected.
+            Message::Response(_) => (),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+/// Enum representing what can be written at the cursor, used to pick completions.
+/// ClassBody - a block keyword of a class or, inside `panoramic_viewer`, of a breakpoint
+/// Block - a property or nickname key inside a properties or pseudo block
+/// Breakpoints - a breakpoint name inside `panoramic_viewer`
+/// AliasTarget - the property an alias points to
+/// VariableReference - the name of a variable inside `${...}` in a value
+/// Other - anything else, for which no completion is offered
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CursorContext {
+    ClassBody { in_breakpoint: bool },
+    Block,
+    Breakpoints,
+    AliasTarget,
+    VariableReference,
+    Other,
+}
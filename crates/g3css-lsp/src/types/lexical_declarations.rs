@@ -0,0 +1,12 @@
+/// Declarations found by scanning the tokens of a source, available even when the source
+/// does not parse, which is the common case while it is being edited.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct LexicalDeclarations {
+    /// Name of the global extended by the source, if any.
+    pub extends: Option<String>,
+    /// Every alias as `(alias, property)`, in source order.
+    pub aliases: Vec<(String, String)>,
+    /// Every variable as `(name, value)`, in source order. Theme variables appear once per
+    /// theme declaring them.
+    pub variables: Vec<(String, String)>,
+}
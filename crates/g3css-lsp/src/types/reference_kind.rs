@@ -0,0 +1,42 @@
+use g3css_parser::types::g3css_symbol::G3cssSymbolKind;
+
+/// Enum representing the declarations that can be referenced, and renamed, across files.
+/// Class - a class, referenced by `inherits`
+/// Alias - an alias, referenced by nicknames
+/// Variable - a plain or theme variable, referenced by `${name}` inside values
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ReferenceKind {
+    Class,
+    Alias,
+    Variable,
+}
+
+impl ReferenceKind {
+    /// Finds what a symbol declares or references, if it takes part in references.
+    pub fn of(kind: G3cssSymbolKind) -> Option<Self> {
+        match kind {
+            G3cssSymbolKind::Class | G3cssSymbolKind::Inherits => Some(Self::Class),
+            G3cssSymbolKind::Alias | G3cssSymbolKind::Nickname => Some(Self::Alias),
+            G3cssSymbolKind::Variable | G3cssSymbolKind::VariableReference => Some(Self::Variable),
+            _ => None,
+        }
+    }
+
+    /// Kind of the symbols declaring it.
+    pub fn declaration(&self) -> G3cssSymbolKind {
+        match self {
+            Self::Class => G3cssSymbolKind::Class,
+            Self::Alias => G3cssSymbolKind::Alias,
+            Self::Variable => G3cssSymbolKind::Variable,
+        }
+    }
+
+    /// Kind of the symbols referencing it.
+    pub fn reference(&self) -> G3cssSymbolKind {
+        match self {
+            Self::Class => G3cssSymbolKind::Inherits,
+            Self::Alias => G3cssSymbolKind::Nickname,
+            Self::Variable => G3cssSymbolKind::VariableReference,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use g3css_ast::types::g3css_document::G3cssDocument;
+use g3css_common::{
+    rustal::config_loader::{env_overrides, find_config, load_config},
+    utils::file_utils::scan_directory,
+};
+use g3css_transformer::types::transformer_options::G3cssTransformerOptions;
+use lsp_types::Url;
+
+use super::workspace_document::WorkspaceDocument;
+
+/// Keeps every `.g3css` file of the workspace: the open ones with the text sent by the
+/// client, and the others as read from disk, so references across files can be resolved.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct G3cssWorkspace {
+    /// Root directories of the workspace.
+    pub roots: Vec<PathBuf>,
+    /// Every known document, by URI.
+    pub documents: BTreeMap<Url, WorkspaceDocument>,
+    /// Options of the first root holding a configuration file, used to resolve breakpoints
+    /// and generate the CSS shown on hover.
+    pub options: G3cssTransformerOptions,
+}
+
+impl G3cssWorkspace {
+    /// Creates an empty workspace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads every `.g3css` file below a root directory, keeping the open documents as sent
+    /// by the client.
+    pub fn load_directory(&mut self, root: &Path) {
+        // An invalid configuration leaves the defaults in place, since the server has no
+        // better place than the build to report it.
+        if self.roots.iter().all(|root| find_config(root).is_none()) {
+            if let Ok((config, Some(_))) = load_config(root, None, &env_overrides()) {
+                self.options = G3cssTransformerOptions::from(&config);
+            }
+        }
+
+        self.roots.push(root.to_path_buf());
+
+        for path in scan_directory(root) {
+            let Ok(uri) = Url::from_file_path(&path) else {
+                continue;
+            };
+
+            if self
+                .documents
+                .get(&uri)
+                .is_some_and(|document| document.open)
+            {
+                continue;
+            }
+
+            if let Ok(source) = fs::read_to_string(&path) {
+                self.documents.insert(
+                    uri,
+                    WorkspaceDocument {
+                        version: None,
+                        open: false,
+                        document: G3cssDocument::parse(source),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Stores the text of a document opened or changed by the client.
+    pub fn update(&mut self, uri: Url, version: i32, text: String) {
+        self.documents.insert(
+            uri,
+            WorkspaceDocument {
+                version: Some(version),
+                open: true,
+                document: G3cssDocument::parse(text),
+            },
+        );
+    }
+
+    /// Forgets the text of a document closed by the client, falling back to the file on
+    /// disk if there is one.
+    pub fn close(&mut self, uri: &Url) {
+        let source = uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok());
+
+        match source {
+            Some(source) => {
+                self.documents.insert(
+                    uri.clone(),
+                    WorkspaceDocument {
+                        version: None,
+                        open: false,
+                        document: G3cssDocument::parse(source),
+                    },
+                );
+            }
+            None => {
+                self.documents.remove(uri);
+            }
+        }
+    }
+
+    /// Looks up a document.
+    pub fn get(&self, uri: &Url) -> Option<&WorkspaceDocument> {
+        self.documents.get(uri)
+    }
+
+    /// Every parsed document of the workspace.
+    pub fn all_documents(&self) -> Vec<&G3cssDocument> {
+        self.documents
+            .values()
+            .map(|document| &document.document)
+            .collect()
+    }
+
+    /// Names of the breakpoints available to `panoramic_viewer`.
+    pub fn breakpoint_names(&self) -> Vec<&str> {
+        self.options
+            .breakpoints
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Finds the document declaring the global named `name`, along with its URI.
+    pub fn global(&self, name: &str) -> Option<(&Url, &G3cssDocument)> {
+        self.documents
+            .iter()
+            .map(|(uri, document)| (uri, &document.document))
+            .find(|(_, document)| document.is_global() && document.scope_name() == Some(name))
+    }
+
+    /// Finds the global extended by a document, along with its URI.
+    pub fn extended_global(&self, document: &G3cssDocument) -> Option<(&Url, &G3cssDocument)> {
+        self.global(document.extends()?)
+    }
+
+    /// Path of a document relative to the workspace root holding it, or its full path when
+    /// it lies outside every root.
+    pub fn display_path(&self, uri: &Url) -> String {
+        let Ok(path) = uri.to_file_path() else {
+            return uri.to_string();
+        };
+
+        self.roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())
+            .unwrap_or(&path)
+            .display()
+            .to_string()
+    }
+
+    /// URIs of every document opened by the client.
+    pub fn open_uris(&self) -> Vec<Url> {
+        self.documents
+            .iter()
+            .filter(|(_, document)| document.open)
+            .map(|(uri, _)| uri.clone())
+            .collect()
+    }
+}
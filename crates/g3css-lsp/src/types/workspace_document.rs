@@ -0,0 +1,13 @@
+use g3css_ast::types::g3css_document::G3cssDocument;
+
+/// Represents a `.g3css` file known to the language server.
+#[derive(PartialEq, Debug, Clone)]
+pub struct WorkspaceDocument {
+    /// Version of the document sent by the client, or `None` if it was read from disk.
+    pub version: Option<i32>,
+    /// Whether the client has the document open, in which case its text comes from the
+    /// client instead of the disk.
+    pub open: bool,
+    /// The parsed document.
+    pub document: G3cssDocument,
+}
@@ -1,3 +1,105 @@
-pub fn g3css_observer() {
-    println!("Hello, world!");
+pub mod types {
+    pub mod observed_scope;
+    pub mod observer_event;
+    pub mod observer_options;
+}
+
+pub mod rustal {
+    pub mod project_handlers;
+    pub mod watch_handlers;
+}
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use g3css_common::utils::file_utils::{is_source_file, scan_directory, source_files};
+use g3css_parser::types::g3css_error::G3cssError;
+use rustal::{
+    project_handlers::G3cssProject,
+    watch_handlers::{DirectoryWatcher, WatchChanges},
+};
+use types::{observer_event::G3cssObserverEvent, observer_options::G3cssObserverOptions};
+
+/// Watches a project directory and rebuilds its source files whenever they change.
+///
+/// Every file is built once when the observer starts. After that, each burst of created,
+/// modified, deleted or renamed files only rebuilds the affected files: the changed files
+/// themselves and, when a global changes, every component extending it. Every outcome is
+/// reported through `on_event`. This function only returns on error.
+///
+/// # Arguments
+///
+/// - `root` - The project directory to watch.
+/// - `options` - The options controlling the debounce period and the generated CSS.
+/// - `on_event` - Called with the outcome of every rebuilt or removed file.
+///
+/// # Returns
+///
+/// - `Result<(), G3cssError>` - An Err if the directory could not be watched or the file
+///   system events could not be read.
+pub fn g3css_observer(
+    root: &str,
+    options: &G3cssObserverOptions,
+    mut on_event: impl FnMut(G3cssObserverEvent),
+) -> Result<(), G3cssError> {
+    let root = Path::new(root);
+    // Watches are set up before the initial build so no change is missed in between.
+    let mut watcher = DirectoryWatcher::new(root)?;
+    let mut project = G3cssProject::new();
+
+    let mut build = |project: &G3cssProject, paths: BTreeSet<PathBuf>| {
+        for path in paths {
+            on_event(project.build_file(&path, &options.transformer));
+        }
+    };
+
+    let mut affected = BTreeSet::new();
+
+    for path in source_files(root, &options.sources) {
+        affected.append(&mut project.update_file(&path));
+    }
+
+    build(&project, affected);
+
+    loop {
+        let changes = match watcher.wait_for_changes(options.debounce)? {
+            WatchChanges::Changed(changes) => changes,
+            // Events were lost, so every known and existing file is considered changed.
+            WatchChanges::Rescan => {
+                watcher.watch_tree(root);
+
+                let (mut changes, _) = project.remove_path(root);
+                changes.extend(source_files(root, &options.sources));
+                changes.into_iter().collect()
+            }
+        };
+
+        let mut affected = BTreeSet::new();
+
+        for path in changes {
+            if path.is_dir() {
+                // A created or moved-in directory brings its own files along.
+                watcher.watch_tree(&path);
+
+                for file in scan_directory(&path) {
+                    if is_source_file(root, &options.sources, &file) {
+                        affected.append(&mut project.update_file(&file));
+                    }
+                }
+            } else if path.is_file() {
+                if is_source_file(root, &options.sources, &path) {
+                    affected.append(&mut project.update_file(&path));
+                }
+            } else {
+                // Deleted or moved-out files and directories.
+                let (removed, mut dependents) = project.remove_path(&path);
+                affected.extend(removed);
+                affected.append(&mut dependents);
+            }
+        }
+
+        build(&project, affected);
+    }
 }
@@ -0,0 +1,197 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use g3css_ast::{
+    rustal::graph_handlers::build_dependency_graph, types::graph_node::G3cssGraphNode,
+};
+use g3css_common::utils::node_utils::scope_parts;
+use g3css_parser::{
+    g3css_parser,
+    types::{g3css_error::G3cssError, g3css_node::G3cssNode},
+};
+use g3css_transformer::{g3css_transformer, types::transformer_options::G3cssTransformerOptions};
+
+use crate::types::{observed_scope::ObservedScope, observer_event::G3cssObserverEvent};
+
+/// Keeps the parsed state of every `.g3css` file of a project, so a change only rebuilds
+/// the files it affects.
+#[derive(Debug, Default)]
+pub struct G3cssProject {
+    /// Every successfully parsed file, by path.
+    scopes: BTreeMap<PathBuf, ObservedScope>,
+    /// Every file that failed to parse, with its error, so it is still reported on rebuild.
+    broken: BTreeMap<PathBuf, G3cssError>,
+}
+
+impl G3cssProject {
+    /// Creates an empty project.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the files of the components depending on any of the given globals, as found
+    /// by the dependency graph of the project.
+    fn dependents_of(&self, globals: &BTreeSet<String>) -> Vec<PathBuf> {
+        let asts = self
+            .scopes
+            .values()
+            .map(|scope| scope.ast.as_ref())
+            .collect::<Vec<_>>();
+        let graph = build_dependency_graph(&asts);
+
+        let components = globals
+            .iter()
+            .flat_map(|name| graph.dependents(&G3cssGraphNode::Global { name: name.clone() }))
+            .filter_map(|node| match node {
+                G3cssGraphNode::Component { name } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+
+        self.scopes
+            .iter()
+            .filter(|(_, scope)| !scope.is_global && components.contains(scope.name.as_str()))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    /// Name of the global declared by a file, if the file declares one.
+    fn global_name(&self, path: &Path) -> Option<String> {
+        self.scopes
+            .get(path)
+            .filter(|scope| scope.is_global)
+            .map(|scope| scope.name.clone())
+    }
+
+    /// Reparses a created or modified file.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the file.
+    ///
+    /// # Returns
+    ///
+    /// The files to rebuild: the file itself and, when it declares (or used to declare) a
+    /// global, every file extending that global.
+    pub fn update_file(&mut self, path: &Path) -> BTreeSet<PathBuf> {
+        let mut globals = BTreeSet::new();
+        globals.extend(self.global_name(path));
+
+        match g3css_parser(&path.to_string_lossy()) {
+            Ok(ast) => {
+                self.broken.remove(path);
+
+                if let Some(scope) = observed_scope(ast) {
+                    if scope.is_global {
+                        globals.insert(scope.name.clone());
+                    }
+
+                    self.scopes.insert(path.to_path_buf(), scope);
+                }
+            }
+            Err(error) => {
+                self.scopes.remove(path);
+                self.broken.insert(path.to_path_buf(), error);
+            }
+        }
+
+        let mut affected = BTreeSet::from([path.to_path_buf()]);
+        affected.extend(self.dependents_of(&globals));
+
+        affected
+    }
+
+    /// Forgets a deleted file, or every file below a deleted directory.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the deleted file or directory.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the forgotten files and the remaining files extending a global that was
+    /// forgotten.
+    pub fn remove_path(&mut self, path: &Path) -> (Vec<PathBuf>, BTreeSet<PathBuf>) {
+        let removed = self
+            .scopes
+            .keys()
+            .chain(self.broken.keys())
+            .filter(|file| file.starts_with(path))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut globals = BTreeSet::new();
+
+        for file in &removed {
+            globals.extend(self.global_name(file));
+            self.scopes.remove(file);
+            self.broken.remove(file);
+        }
+
+        (removed, self.dependents_of(&globals).into_iter().collect())
+    }
+
+    /// Parses and transforms a file against the global it extends.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the file.
+    /// - `options` - The options passed to the transformer.
+    ///
+    /// # Returns
+    ///
+    /// The event describing the outcome of the rebuild.
+    pub fn build_file(&self, path: &Path, options: &G3cssTransformerOptions) -> G3cssObserverEvent {
+        let Some(scope) = self.scopes.get(path) else {
+            // Files that failed to parse report their parse error, unknown files are gone.
+            return match self.broken.get(path) {
+                Some(error) => G3cssObserverEvent::Failed {
+                    path: path.to_path_buf(),
+                    error: error.clone(),
+                },
+                None => G3cssObserverEvent::Removed {
+                    path: path.to_path_buf(),
+                },
+            };
+        };
+
+        // The extended global is looked up by name among every global of the project.
+        let global = scope.extends.as_ref().and_then(|extends| {
+            self.scopes
+                .iter()
+                .find(|(_, other)| other.is_global && &other.name == extends)
+        });
+
+        match g3css_transformer(
+            &scope.ast,
+            global.map(|(_, other)| other.ast.as_ref()),
+            options,
+        ) {
+            Ok(stylesheet) => G3cssObserverEvent::Built {
+                path: path.to_path_buf(),
+                global: global.map(|(path, _)| path.clone()),
+                stylesheet,
+            },
+            Err(error) => G3cssObserverEvent::Failed {
+                path: path.to_path_buf(),
+                error,
+            },
+        }
+    }
+}
+
+/// Extracts the name, extended global and kind of a parsed file.
+fn observed_scope(ast: Rc<G3cssNode>) -> Option<ObservedScope> {
+    let (name, extends) = scope_parts(&ast)
+        .map(|(name, extends, _)| (name.to_string(), extends.map(str::to_string)))?;
+
+    Some(ObservedScope {
+        name,
+        extends,
+        is_global: matches!(ast.as_ref(), G3cssNode::Global(_)),
+        ast,
+    })
+}
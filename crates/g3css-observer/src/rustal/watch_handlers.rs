@@ -0,0 +1,305 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use g3css_parser::types::g3css_error::G3cssError;
+use inotify::{EventMask, Events, Inotify, WatchDescriptor, WatchMask};
+
+/// Interval between two reads of pending events while a burst is being debounced.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Size of the buffer events are read into.
+const EVENT_BUFFER_SIZE: usize = 4096;
+
+/// Enum representing what the observer must do after a burst of file system events.
+/// Changed - rebuild from the given paths, which may be files or directories
+/// Rescan - events were lost, so the whole project must be rescanned
+#[derive(PartialEq, Debug, Clone)]
+pub enum WatchChanges {
+    Changed(BTreeSet<PathBuf>),
+    Rescan,
+}
+
+/// Watches a directory tree with inotify, which only watches single directories, by keeping
+/// one watch per directory.
+pub struct DirectoryWatcher {
+    /// The inotify instance receiving the events.
+    inotify: Inotify,
+    /// Directory of every watch, to rebuild the full path of an event.
+    directories: HashMap<WatchDescriptor, PathBuf>,
+}
+
+/// Converts an I/O error of the watcher into a G3CSS error.
+fn watch_error(action: &str, error: std::io::Error) -> G3cssError {
+    G3cssError::OtherError(format!("Failed to {}: {}", action, error))
+}
+
+impl DirectoryWatcher {
+    /// Creates a watcher for every directory below `root`.
+    ///
+    /// # Arguments
+    ///
+    /// - `root` - The directory to watch.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<DirectoryWatcher, G3cssError>` - Ok containing the watcher, or an Err if
+    ///   inotify could not be initialized or `root` could not be watched.
+    pub fn new(root: &Path) -> Result<Self, G3cssError> {
+        let inotify = Inotify::init().map_err(|error| watch_error("initialize inotify", error))?;
+
+        let mut watcher = Self {
+            inotify,
+            directories: HashMap::new(),
+        };
+
+        watcher
+            .watch_directory(root)
+            .map_err(|error| watch_error(&format!("watch {}", root.display()), error))?;
+        watcher.watch_subdirectories(root);
+
+        Ok(watcher)
+    }
+
+    /// Adds a watch for a single directory.
+    fn watch_directory(&mut self, directory: &Path) -> std::io::Result<()> {
+        let mask = WatchMask::CREATE
+            | WatchMask::MODIFY
+            | WatchMask::CLOSE_WRITE
+            | WatchMask::DELETE
+            | WatchMask::MOVED_FROM
+            | WatchMask::MOVED_TO;
+
+        let descriptor = self.inotify.watches().add(directory, mask)?;
+        self.directories.insert(descriptor, directory.to_path_buf());
+
+        Ok(())
+    }
+
+    /// Adds a watch for `root` and every directory below it, skipping the ones that vanished.
+    /// Watching a directory again keeps its existing watch.
+    pub fn watch_tree(&mut self, root: &Path) {
+        if self.watch_directory(root).is_ok() {
+            self.watch_subdirectories(root);
+        }
+    }
+
+    /// Adds a watch for every directory below `root`, skipping the ones that vanished.
+    fn watch_subdirectories(&mut self, root: &Path) {
+        let mut pending = vec![root.to_path_buf()];
+
+        while let Some(directory) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&directory) else {
+                continue;
+            };
+
+            for path in entries.flatten().map(|entry| entry.path()) {
+                if path.is_dir() && self.watch_directory(&path).is_ok() {
+                    pending.push(path);
+                }
+            }
+        }
+    }
+
+    /// Records the changed paths of a batch of events.
+    ///
+    /// # Arguments
+    ///
+    /// - `events` - The events read from inotify.
+    /// - `changes` - The set the changed paths are added to.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the number of events recorded and whether events were lost.
+    fn record_events(&mut self, events: Events, changes: &mut BTreeSet<PathBuf>) -> (usize, bool) {
+        let mut count = 0;
+        let mut overflowed = false;
+
+        for event in events {
+            count += 1;
+
+            if event.mask.contains(EventMask::Q_OVERFLOW) {
+                overflowed = true;
+            } else if event.mask.contains(EventMask::IGNORED) {
+                // The watched directory itself is gone.
+                self.directories.remove(&event.wd);
+            } else if let (Some(directory), Some(name)) =
+                (self.directories.get(&event.wd), event.name)
+            {
+                changes.insert(directory.join(name));
+            }
+        }
+
+        (count, overflowed)
+    }
+
+    /// Reads the pending events without blocking.
+    ///
+    /// # Arguments
+    ///
+    /// - `changes` - The set the changed paths are added to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(usize, bool), G3cssError>` - Ok containing the number of events read and
+    ///   whether events were lost, or an Err if the events could not be read.
+    fn read_pending(
+        &mut self,
+        changes: &mut BTreeSet<PathBuf>,
+    ) -> Result<(usize, bool), G3cssError> {
+        let mut buffer = [0; EVENT_BUFFER_SIZE];
+        let mut count = 0;
+        let mut overflowed = false;
+
+        loop {
+            let events = match self.inotify.read_events(&mut buffer) {
+                Ok(events) => events,
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                Err(error) => return Err(watch_error("read file system events", error)),
+            };
+
+            let (read, lost) = self.record_events(events, changes);
+            overflowed |= lost;
+
+            if read == 0 {
+                break;
+            }
+
+            count += read;
+        }
+
+        Ok((count, overflowed))
+    }
+
+    /// Waits for the next burst of changes.
+    ///
+    /// Blocks until an event arrives, then keeps collecting events until none arrived for
+    /// `debounce`, so the many events of one editor save are handled as a single change.
+    ///
+    /// # Arguments
+    ///
+    /// - `debounce` - The quiet period ending a burst.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<WatchChanges, G3cssError>` - Ok containing the changes of the burst, or an
+    ///   Err if the events could not be read.
+    pub fn wait_for_changes(&mut self, debounce: Duration) -> Result<WatchChanges, G3cssError> {
+        let mut changes = BTreeSet::new();
+        let mut buffer = [0; EVENT_BUFFER_SIZE];
+
+        // Block until the burst starts.
+        let events = self
+            .inotify
+            .read_events_blocking(&mut buffer)
+            .map_err(|error| watch_error("read file system events", error))?;
+        let (_, mut overflowed) = self.record_events(events, &mut changes);
+
+        let mut deadline = Instant::now() + debounce;
+
+        // Keep collecting until the burst has been quiet for the whole debounce period.
+        while Instant::now() < deadline {
+            thread::sleep(POLL_INTERVAL.min(debounce));
+
+            let (count, lost) = self.read_pending(&mut changes)?;
+            overflowed |= lost;
+
+            if count > 0 {
+                deadline = Instant::now() + debounce;
+            }
+        }
+
+        if overflowed {
+            Ok(WatchChanges::Rescan)
+        } else {
+            Ok(WatchChanges::Changed(changes))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    /// Creates an empty directory for a test, removing what a previous run left.
+    fn test_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("g3css-watch-{}-{}", std::process::id(), name));
+
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn a_burst_of_events_is_merged_into_one_change() {
+        let directory = test_directory("burst");
+        let mut watcher = DirectoryWatcher::new(&directory).unwrap();
+
+        // Each write comes well within the debounce period of the previous one.
+        let writer = {
+            let directory = directory.clone();
+
+            thread::spawn(move || {
+                for name in ["a.g3css", "b.g3css", "a.g3css", "c.g3css"] {
+                    fs::write(directory.join(name), "").unwrap();
+                    thread::sleep(Duration::from_millis(20));
+                }
+            })
+        };
+
+        let changes = watcher
+            .wait_for_changes(Duration::from_millis(200))
+            .unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(
+            changes,
+            WatchChanges::Changed(
+                ["a.g3css", "b.g3css", "c.g3css"]
+                    .map(|name| directory.join(name))
+                    .into()
+            )
+        );
+
+        // A write after the quiet period starts the next change.
+        fs::write(directory.join("d.g3css"), "").unwrap();
+
+        assert_eq!(
+            watcher.wait_for_changes(Duration::from_millis(50)).unwrap(),
+            WatchChanges::Changed([directory.join("d.g3css")].into())
+        );
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn files_of_a_new_directory_are_seen_once_it_is_watched() {
+        let directory = test_directory("tree");
+        let mut watcher = DirectoryWatcher::new(&directory).unwrap();
+        let nested = directory.join("nested");
+
+        fs::create_dir(&nested).unwrap();
+
+        assert_eq!(
+            watcher.wait_for_changes(Duration::from_millis(50)).unwrap(),
+            WatchChanges::Changed([nested.clone()].into())
+        );
+
+        watcher.watch_tree(&nested);
+        fs::write(nested.join("button.g3css"), "").unwrap();
+
+        assert_eq!(
+            watcher.wait_for_changes(Duration::from_millis(50)).unwrap(),
+            WatchChanges::Changed([nested.join("button.g3css")].into())
+        );
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+use std::rc::Rc;
+
+use g3css_parser::types::g3css_node::G3cssNode;
+
+/// Represents a parsed `.g3css` file known to the observer.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ObservedScope {
+    /// AST of the file.
+    pub ast: Rc<G3cssNode>,
+    /// Name of the global or component declared by the file.
+    pub name: String,
+    /// Name of the global extended by the file, if any.
+    pub extends: Option<String>,
+    /// Whether the file declares a global rather than a component.
+    pub is_global: bool,
+}
@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use g3css_parser::types::g3css_error::G3cssError;
+use g3css_transformer::types::css_stylesheet::CssStylesheet;
+
+/// Enum representing the outcome of rebuilding a single `.g3css` file.
+/// Built - the file was parsed and transformed into a stylesheet, along with the path of the
+/// global it extends
+/// Removed - the file no longer exists, so its generated CSS is stale
+/// Failed - the file could not be parsed or transformed
+#[derive(PartialEq, Debug, Clone)]
+pub enum G3cssObserverEvent {
+    Built {
+        path: PathBuf,
+        global: Option<PathBuf>,
+        stylesheet: CssStylesheet,
+    },
+    Removed {
+        path: PathBuf,
+    },
+    Failed {
+        path: PathBuf,
+        error: G3cssError,
+    },
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use g3css_common::types::g3css_config::G3cssSourceConfig;
+use g3css_transformer::types::transformer_options::G3cssTransformerOptions;
+
+/// Options controlling how the observer watches and rebuilds a project.
+#[derive(PartialEq, Debug, Clone)]
+pub struct G3cssObserverOptions {
+    /// Quiet period that must follow the last change before a rebuild starts, so a burst of
+    /// editor saves only triggers one rebuild.
+    pub debounce: Duration,
+    /// Which files below the watched directory are sources.
+    pub sources: G3cssSourceConfig,
+    /// Options passed to the transformer for every rebuilt file.
+    pub transformer: G3cssTransformerOptions,
+}
+
+impl Default for G3cssObserverOptions {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(100),
+            sources: G3cssSourceConfig::default(),
+            transformer: G3cssTransformerOptions::default(),
+        }
+    }
+}
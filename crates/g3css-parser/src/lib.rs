@@ -1,11 +1,14 @@
 pub mod types {
     pub mod g3css_alias;
+    pub mod g3css_ast_document;
     pub mod g3css_children;
     pub mod g3css_class;
     pub mod g3css_elements;
     pub mod g3css_error;
     pub mod g3css_node;
     pub mod g3css_panoramic;
+    pub mod g3css_span;
+    pub mod g3css_symbol;
     pub mod g3css_theme;
     pub mod g3css_variable;
 }
@@ -13,22 +16,27 @@ pub mod types {
 pub mod rustal {
     pub mod alias_handlers;
     pub mod ast_handlers;
+    pub mod ast_printer;
+    pub mod ast_serialization;
     pub mod build_ast_from_elements;
     pub mod children_handler;
     pub mod class_handler;
     pub mod elements_handler;
     pub mod panoramic_handlers;
+    pub mod symbol_handlers;
     pub mod theme_handlers;
     pub mod utils;
     pub mod variable_handler;
 }
 
-use pest::{error::Error, Parser};
+use pest::{error::Error, iterators::Pairs, Parser};
 use pest_derive::Parser;
 use rustal::ast_handlers::build_ast_from_rule;
+use rustal::symbol_handlers::build_symbols;
 use std::rc::Rc;
 use types::g3css_error::G3cssError;
 use types::g3css_node::G3cssNode;
+use types::g3css_symbol::G3cssSymbol;
 
 #[derive(Parser)]
 #[grammar = "grammar/g3css.pest"]
@@ -43,9 +51,9 @@ struct G3cssParser;
 ///
 /// # Returns
 ///
-/// - `Result<Rc<G3cssNode>, Error<Rule>>` - Ok containing a reference-counted G3cssNode
+/// - `Result<Rc<G3cssNode>, Box<Error<Rule>>>` - Ok containing a reference-counted G3cssNode
 ///   if parsing is successful, or an Err containing an Error if parsing fails.
-fn parse(src: &str) -> Result<Rc<G3cssNode>, Error<Rule>> {
+fn parse(src: &str) -> Result<Rc<G3cssNode>, Box<Error<Rule>>> {
     // Attempt to parse the source string using the G3cssParser and Rule::program.
     match G3cssParser::parse(Rule::program, src) {
         // If parsing is successful, process the parsed pairs.
@@ -68,7 +76,7 @@ fn parse(src: &str) -> Result<Rc<G3cssNode>, Error<Rule>> {
         }
         // If parsing fails, return the error.
         Err(error) => {
-            return Err(error);
+            return Err(Box::new(error));
         }
     }
 
@@ -88,18 +96,66 @@ pub fn g3css_parser(file_path: &str) -> Result<Rc<G3cssNode>, G3cssError> {
             // Attempt to parse the raw file into an abstract syntax tree (AST).
             match parse(&raw_file) {
                 // Return the AST wrapped in Ok if parsing is successful.
-                Ok(ast) => {
-                    return Ok(ast);
-                }
+                Ok(ast) => Ok(ast),
                 // Return a G3cssError::ParseError if there is an error during parsing.
-                Err(error) => {
-                    return Err(G3cssError::ParseError(error));
-                }
+                Err(error) => Err(G3cssError::ParseError(error)),
             }
         }
         // Return a G3cssError::OtherError if there is an error reading the file.
-        Err(error) => {
-            return Err(G3cssError::OtherError(error.to_string()));
+        Err(error) => Err(G3cssError::OtherError(error.to_string())),
+    }
+}
+
+/// Parses G3CSS source into an abstract syntax tree (AST) if successful.
+///
+/// # Parameters
+/// - `src`: The G3CSS source to parse.
+///
+/// # Returns
+/// - `Result<Rc<G3cssNode>, G3cssError>` - Ok containing the AST if parsing is successful,
+///   or a G3cssError::ParseError if parsing fails.
+pub fn g3css_parse_source(src: &str) -> Result<Rc<G3cssNode>, G3cssError> {
+    parse(src).map_err(G3cssError::ParseError)
+}
+
+/// Parses G3CSS source into the pairs of the grammar, which keep the span of every rule.
+///
+/// # Parameters
+/// - `src`: The G3CSS source to parse.
+///
+/// # Returns
+/// - `Result<Pairs<Rule>, G3cssError>` - Ok containing the pairs of the `program` rule, or a
+///   G3cssError::ParseError if parsing fails.
+pub fn g3css_parse_pairs(src: &str) -> Result<Pairs<'_, Rule>, G3cssError> {
+    G3cssParser::parse(Rule::program, src).map_err(|error| G3cssError::ParseError(Box::new(error)))
+}
+
+/// Parses G3CSS source into an abstract syntax tree (AST) along with the symbols of the
+/// source, which record where every named element was written.
+///
+/// # Parameters
+/// - `src`: The G3CSS source to parse.
+///
+/// # Returns
+/// - `Result<(Rc<G3cssNode>, Vec<G3cssSymbol>), G3cssError>` - Ok containing the AST and the
+///   symbols in source order, or a G3cssError::ParseError if parsing fails.
+pub fn g3css_parse_with_symbols(
+    src: &str,
+) -> Result<(Rc<G3cssNode>, Vec<G3cssSymbol>), G3cssError> {
+    match G3cssParser::parse(Rule::program, src) {
+        Ok(pairs) => {
+            // Build both the AST and the symbols from the global or component rule.
+            for pair in pairs {
+                if let Rule::global | Rule::component = pair.as_rule() {
+                    let symbols = build_symbols(src, pair.clone());
+
+                    return Ok((Rc::new(build_ast_from_rule(pair).unwrap()), symbols));
+                }
+            }
+
+            // If no rules match, return a unit type without symbols.
+            Ok((Rc::new(G3cssNode::Unit), vec![]))
         }
+        Err(error) => Err(G3cssError::ParseError(Box::new(error))),
     }
 }
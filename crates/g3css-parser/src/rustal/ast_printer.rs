@@ -0,0 +1,679 @@
+use pest::unicode::QUOTATION_MARK;
+
+use crate::{
+    g3css_parse_source,
+    types::{
+        g3css_alias::G3cssAlias, g3css_children::G3cssChildren, g3css_class::G3cssClass,
+        g3css_elements::G3cssElements, g3css_error::G3cssError, g3css_node::G3cssNode,
+        g3css_panoramic::G3cssPanoramic, g3css_theme::G3cssTheme, g3css_variable::G3cssVariable,
+    },
+};
+
+/// Indentation of one nesting level.
+const INDENT: &str = "    ";
+
+/// Builds the error of an AST that has no G3CSS source.
+fn unprintable(message: String) -> G3cssError {
+    G3cssError::OtherError(format!("Cannot print the AST: {}", message))
+}
+
+/// Characters the grammar accepts as quotation marks, which is the Unicode `Quotation_Mark`
+/// property.
+fn is_quotation_mark(c: char) -> bool {
+    QUOTATION_MARK(c)
+}
+
+/// Quotes a name or value, returning it with its quotation marks and the text between them.
+///
+/// The parser only strips double quotes, so text read from `'...'` keeps its quotation
+/// marks and is written back as is.
+fn quote(text: &str) -> Option<(String, &str)> {
+    let mut chars = text.chars();
+    let kept = match (chars.next(), chars.next_back()) {
+        (Some(first), Some(last)) if first != '"' && is_quotation_mark(first) => {
+            is_quotation_mark(last).then_some(chars.as_str())
+        }
+        _ => None,
+    };
+
+    match kept {
+        Some(inner) if !inner.contains(is_quotation_mark) => Some((text.to_string(), inner)),
+        _ if !text.contains(is_quotation_mark) => Some((format!("\"{}\"", text), text)),
+        _ => None,
+    }
+}
+
+/// Quotes a scope or class name, which only uses uppercase letters, digits and underscores.
+fn quote_name(kind: &str, name: &str) -> Result<String, G3cssError> {
+    match quote(name) {
+        Some((quoted, inner))
+            if !inner.is_empty()
+                && inner
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') =>
+        {
+            Ok(quoted)
+        }
+        _ => Err(unprintable(format!(
+            "{} \"{}\" must be made of uppercase letters, digits and underscores",
+            kind, name
+        ))),
+    }
+}
+
+/// Checks that a key only uses lowercase letters, digits and underscores.
+fn check_key(kind: &str, key: &str) -> Result<(), G3cssError> {
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(unprintable(format!(
+            "{} \"{}\" must be made of lowercase letters, digits and underscores",
+            kind, key
+        )));
+    }
+
+    Ok(())
+}
+
+/// Quotes a value, which cannot hold quotation marks other than the ones around it.
+fn quote_value(key: &str, value: &str) -> Result<String, G3cssError> {
+    quote(value).map(|(quoted, _)| quoted).ok_or_else(|| {
+        unprintable(format!(
+            "the value of \"{}\" contains a quotation mark",
+            key
+        ))
+    })
+}
+
+/// Checks that a list holds at least one entry, as every list of the grammar does.
+fn check_not_empty<T>(kind: &str, items: &[T]) -> Result<(), G3cssError> {
+    if items.is_empty() {
+        return Err(unprintable(format!("{} cannot be empty", kind)));
+    }
+
+    Ok(())
+}
+
+/// Writes G3CSS source one line at a time.
+#[derive(Default)]
+struct Printer {
+    output: String,
+    depth: usize,
+}
+
+impl Printer {
+    /// Writes a line at the current depth.
+    fn line(&mut self, text: &str) {
+        self.output.push_str(&INDENT.repeat(self.depth));
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    /// Writes a line opening a group, the following lines being indented once more.
+    fn open(&mut self, text: &str) {
+        self.line(text);
+        self.depth += 1;
+    }
+
+    /// Writes the line closing a group opened by `open`.
+    fn close(&mut self, text: &str) {
+        self.depth -= 1;
+        self.line(text);
+    }
+
+    /// Writes a scope, its name, the global it extends and its children.
+    fn scope(&mut self, kind: &str, nodes: &[G3cssNode]) -> Result<(), G3cssError> {
+        let (name, extends, children) = match nodes {
+            [G3cssNode::Name(name), G3cssNode::Children(children)] => (name, None, children),
+            [G3cssNode::Name(name), G3cssNode::Extends(extends), G3cssNode::Children(children)]
+                if kind == "component" =>
+            {
+                (name, Some(extends), children)
+            }
+            _ => {
+                return Err(unprintable(format!(
+                    "a {} scope must hold a name, {}and its children",
+                    kind,
+                    if kind == "component" {
+                        "the global it extends "
+                    } else {
+                        ""
+                    }
+                )))
+            }
+        };
+
+        let name = quote_name("scope name", name)?;
+        check_not_empty("a scope", children)?;
+
+        let header = match extends {
+            Some(extends) => format!(
+                "define {} scope {} extends global {} {{",
+                kind,
+                name,
+                quote_name("extended global", extends)?
+            ),
+            None => format!("define {} scope {} {{", kind, name),
+        };
+
+        self.open(&header);
+
+        for (index, child) in children.iter().enumerate() {
+            if index > 0 {
+                self.output.push('\n');
+            }
+
+            self.scope_element(kind, child)?;
+        }
+
+        self.close("}");
+        Ok(())
+    }
+
+    /// Writes an element of a scope.
+    fn scope_element(&mut self, kind: &str, child: &G3cssChildren) -> Result<(), G3cssError> {
+        match child {
+            G3cssChildren::Aliases(aliases) => {
+                check_not_empty("an aliases block", aliases)?;
+                self.open("define aliases {");
+
+                for G3cssAlias::Alias(alias) in aliases {
+                    let [key, target] = alias.as_slice() else {
+                        return Err(unprintable("an alias must hold a key and a target".into()));
+                    };
+
+                    check_key("alias", key)?;
+                    check_key("alias target", target)?;
+                    self.line(&format!("{}: {},", key, target));
+                }
+
+                self.close("},");
+            }
+            G3cssChildren::Variables(variables) => self.variables(variables)?,
+            G3cssChildren::LightTheme(themes) | G3cssChildren::DarkTheme(themes) => {
+                let theme = match child {
+                    G3cssChildren::LightTheme(_) => "light",
+                    _ => "dark",
+                };
+
+                if kind != "global" {
+                    return Err(unprintable(format!(
+                        "the {} theme can only be defined in a global scope",
+                        theme
+                    )));
+                }
+
+                let [G3cssTheme::Variables(variables)] = themes.as_slice() else {
+                    return Err(unprintable(format!(
+                        "the {} theme must hold a single variables block",
+                        theme
+                    )));
+                };
+
+                self.open(&format!("define {} theme {{", theme));
+                self.variables(variables)?;
+                self.close("},");
+            }
+            G3cssChildren::Class(class) => {
+                let header = class_header(class)?;
+                self.open(&format!("define class {} {{", header));
+                self.class_children(&class[class_children_start(class)..], true)?;
+                self.close("},");
+            }
+            G3cssChildren::Classes(classes) => {
+                check_not_empty("a classes block", classes)?;
+                self.open("define classes [");
+
+                for class in classes {
+                    let header = class_header(class)?;
+                    self.open(&format!("{} {{", header));
+                    self.class_children(&class[class_children_start(class)..], true)?;
+                    self.close("},");
+                }
+
+                self.close("],");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a variables block.
+    fn variables(&mut self, variables: &[G3cssVariable]) -> Result<(), G3cssError> {
+        check_not_empty("a variables block", variables)?;
+        self.open("define variables {");
+
+        for G3cssVariable::Variable(variable) in variables {
+            let [key, value] = variable.as_slice() else {
+                return Err(unprintable("a variable must hold a key and a value".into()));
+            };
+
+            check_key("variable", key)?;
+            let quoted = quote_value(key, value)?;
+
+            // Variable values are read up to their first whitespace.
+            if value.contains(char::is_whitespace) {
+                return Err(unprintable(format!(
+                    "the value of variable \"{}\" contains whitespace",
+                    key
+                )));
+            }
+
+            self.line(&format!("{}: {},", key, quoted));
+        }
+
+        self.close("},");
+        Ok(())
+    }
+
+    /// Writes the children of a class or of a breakpoint.
+    fn class_children(
+        &mut self,
+        children: &[G3cssClass],
+        allows_panoramic: bool,
+    ) -> Result<(), G3cssError> {
+        check_not_empty("a class", children)?;
+
+        for child in children {
+            match child {
+                G3cssClass::Important(value) => {
+                    if value != "true" && value != "false" {
+                        return Err(unprintable(format!(
+                            "!important must be \"true\" or \"false\", found \"{}\"",
+                            value
+                        )));
+                    }
+
+                    self.line(&format!("!important: {},", value));
+                }
+                G3cssClass::PanoramicViewer(breakpoints) if allows_panoramic => {
+                    check_not_empty("a panoramic_viewer block", breakpoints)?;
+                    self.open("panoramic_viewer: {");
+
+                    for breakpoint in breakpoints {
+                        let [G3cssPanoramic::Breakpoint(name), G3cssPanoramic::Children(children)] =
+                            breakpoint.as_slice()
+                        else {
+                            return Err(unprintable(
+                                "a breakpoint must hold a name and its children".into(),
+                            ));
+                        };
+
+                        check_key("breakpoint", name)?;
+                        self.open(&format!("{}: {{", name));
+                        self.class_children(children, false)?;
+                        self.close("},");
+                    }
+
+                    self.close("},");
+                }
+                G3cssClass::PanoramicViewer(_) => {
+                    return Err(unprintable(
+                        "panoramic_viewer cannot be nested in a breakpoint".into(),
+                    ))
+                }
+                G3cssClass::ClassName(_) | G3cssClass::Inherits(_) => {
+                    return Err(unprintable(
+                        "a class name or inherits must start its class".into(),
+                    ))
+                }
+                block => {
+                    if let Some((keyword, elements)) = block.block() {
+                        check_not_empty(&format!("a {} block", keyword), elements)?;
+                        self.open(&format!("{}: {{", keyword));
+
+                        for element in elements {
+                            self.element(element)?;
+                        }
+
+                        self.close("},");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a property or a nickname.
+    fn element(&mut self, element: &G3cssElements) -> Result<(), G3cssError> {
+        let (key, value) = match element {
+            G3cssElements::Nickname(nickname) => match nickname.as_slice() {
+                [key, value] => {
+                    check_key("nickname", key)?;
+                    (key.as_str(), value.as_str())
+                }
+                _ => {
+                    return Err(unprintable("a nickname must hold a key and a value".into()));
+                }
+            },
+            element => element.property().unwrap_or_default(),
+        };
+
+        let quoted = quote_value(key, value)?;
+        self.line(&format!("{}: {},", key, quoted));
+        Ok(())
+    }
+}
+
+/// Builds the quoted name of a class, followed by the class it inherits.
+fn class_header(class: &[G3cssClass]) -> Result<String, G3cssError> {
+    match class {
+        [G3cssClass::ClassName(name), G3cssClass::Inherits(inherits), ..] => Ok(format!(
+            "{} inherits {}",
+            quote_name("class name", name)?,
+            quote_name("inherited class", inherits)?
+        )),
+        [G3cssClass::ClassName(name), ..] => quote_name("class name", name),
+        _ => Err(unprintable("a class must start with its name".into())),
+    }
+}
+
+/// Finds where the children of a class start, after its name and the class it inherits.
+fn class_children_start(class: &[G3cssClass]) -> usize {
+    match class {
+        [G3cssClass::ClassName(_), G3cssClass::Inherits(_), ..] => 2,
+        _ => 1,
+    }
+}
+
+/// Prints an AST as G3CSS source, in the layout of the formatter.
+///
+/// The elements of the scope are written in the order of the AST, so parsing the printed
+/// source gives back an AST equal to `ast`. Comments are not part of the AST and are lost.
+///
+/// # Arguments
+///
+/// - `ast` - The AST to print, parsed, deserialized or built by hand.
+///
+/// # Returns
+///
+/// - `Result<String, G3cssError>` - Ok containing the source, or an Err if the AST has no
+///   G3CSS source, such as an empty block, a name with lowercase letters, a value holding a
+///   quotation mark or a nickname named after a property.
+pub fn print_ast(ast: &G3cssNode) -> Result<String, G3cssError> {
+    let mut printer = Printer::default();
+
+    match ast {
+        G3cssNode::Global(nodes) => printer.scope("global", nodes)?,
+        G3cssNode::Component(nodes) => printer.scope("component", nodes)?,
+        _ => {
+            return Err(unprintable(
+                "the root of the AST must be a global or component scope".into(),
+            ))
+        }
+    }
+
+    // The checks above cover the grammar, anything they miss (such as a nickname read back
+    // as the property of the same name) is caught by parsing the source back.
+    match g3css_parse_source(&printer.output) {
+        Ok(parsed) if *parsed == *ast => Ok(printer.output),
+        _ => Err(unprintable(
+            "the printed source does not parse back to the same AST".into(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sample files, with comments, single quotes and Unicode quotation marks among them.
+    const SAMPLES: [(&str, &str); 4] = [
+        ("app", include_str!("../../tests/samples/app.g3css")),
+        ("button", include_str!("../../tests/samples/button.g3css")),
+        ("layout", include_str!("../../tests/samples/layout.g3css")),
+        (
+            "commented",
+            include_str!("../../tests/samples/commented.g3css"),
+        ),
+    ];
+
+    #[test]
+    fn printed_samples_parse_back_to_the_same_ast() {
+        for (name, source) in SAMPLES {
+            let ast = g3css_parse_source(source).unwrap();
+            let printed = print_ast(&ast).unwrap();
+            let reparsed = g3css_parse_source(&printed).unwrap();
+
+            assert_eq!(*reparsed, *ast, "{} changed when printed", name);
+        }
+    }
+
+    #[test]
+    fn printing_is_stable() {
+        for (name, source) in SAMPLES {
+            let printed = print_ast(&g3css_parse_source(source).unwrap()).unwrap();
+            let reprinted = print_ast(&g3css_parse_source(&printed).unwrap()).unwrap();
+
+            assert_eq!(reprinted, printed, "{} printed differently twice", name);
+        }
+    }
+
+    #[test]
+    fn comments_are_dropped() {
+        let (_, source) = SAMPLES[3];
+        let printed = print_ast(&g3css_parse_source(source).unwrap()).unwrap();
+
+        assert!(!printed.contains("//") && !printed.contains("/*"));
+    }
+
+    #[test]
+    fn quoted_values_keep_their_quotation_marks() {
+        let (_, source) = SAMPLES[3];
+        let printed = print_ast(&g3css_parse_source(source).unwrap()).unwrap();
+
+        assert!(printed.contains("define global scope 'APP' {"));
+        assert!(printed.contains("display: 'flex',"));
+        assert!(printed.contains("border: “1px solid red”,"));
+        assert!(printed.contains("gap: \"${gap}\","));
+    }
+
+    #[test]
+    fn empty_blocks_are_rejected() {
+        let ast = G3cssNode::Global(vec![
+            G3cssNode::Name("APP".to_string()),
+            G3cssNode::Children(vec![G3cssChildren::Aliases(vec![])]),
+        ]);
+
+        assert!(print_ast(&ast).is_err());
+    }
+
+    #[test]
+    fn values_holding_quotation_marks_are_rejected() {
+        let ast = G3cssNode::Global(vec![
+            G3cssNode::Name("APP".to_string()),
+            G3cssNode::Children(vec![G3cssChildren::Variables(vec![
+                G3cssVariable::Variable(vec!["gap".to_string(), "4\"px".to_string()]),
+            ])]),
+        ]);
+
+        assert!(print_ast(&ast).is_err());
+    }
+
+    /// Number of ASTs generated by the round trip test.
+    const GENERATED_ASTS: u64 = 500;
+
+    /// Deterministic xorshift generator of the shapes the grammar accepts, so a failing AST
+    /// is reproduced by its seed.
+    struct AstGenerator(u64);
+
+    impl AstGenerator {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// A number in `min..=max`.
+        fn between(&mut self, min: usize, max: usize) -> usize {
+            min + (self.next() % (max - min + 1) as u64) as usize
+        }
+
+        fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+            items[self.between(0, items.len() - 1)]
+        }
+
+        /// A word of 1 to 8 characters taken from `alphabet`.
+        fn word(&mut self, alphabet: &str) -> String {
+            let characters: Vec<char> = alphabet.chars().collect();
+
+            (0..self.between(1, 8))
+                .map(|_| self.pick(&characters))
+                .collect()
+        }
+
+        /// Between `min` and `max` items built by `build`.
+        fn many<T>(&mut self, min: usize, max: usize, build: fn(&mut Self) -> T) -> Vec<T> {
+            (0..self.between(min, max)).map(|_| build(self)).collect()
+        }
+
+        /// A scope or class name, which the grammar limits to uppercase letters, digits and
+        /// underscores.
+        fn name(&mut self) -> String {
+            self.word("ABCXYZ019_")
+        }
+
+        /// An alias, variable, nickname or breakpoint key.
+        fn key(&mut self) -> String {
+            self.word("abcxyz019_")
+        }
+
+        /// A property value, which may hold spaces, punctuation and non-ASCII text. Slashes
+        /// are left out, as the grammar reads `//` and `/*` inside a value as a comment.
+        fn value(&mut self) -> String {
+            let value = self.word("abc 019#%.,()-${}é");
+
+            match value.trim() {
+                "" => "0".to_string(),
+                trimmed => trimmed.to_string(),
+            }
+        }
+
+        fn variable(&mut self) -> G3cssVariable {
+            // The parser drops the whitespace of variable values.
+            G3cssVariable::Variable(vec![self.key(), self.value().replace(' ', "")])
+        }
+
+        fn element(&mut self) -> G3cssElements {
+            let properties: [fn(String) -> G3cssElements; 6] = [
+                G3cssElements::Margin,
+                G3cssElements::PaddingTop,
+                G3cssElements::BorderTop,
+                G3cssElements::Content,
+                G3cssElements::Gap,
+                G3cssElements::GridTemplateAreas,
+            ];
+
+            match self.between(0, properties.len()) {
+                0 => G3cssElements::Nickname(vec![self.key(), self.value()]),
+                index => properties[index - 1](self.value()),
+            }
+        }
+
+        /// A block, or an `!important` flag, of a class or breakpoint.
+        fn block(&mut self) -> G3cssClass {
+            let blocks: [fn(Vec<G3cssElements>) -> G3cssClass; 5] = [
+                G3cssClass::Properties,
+                G3cssClass::Hover,
+                G3cssClass::FirstOfType,
+                G3cssClass::Before,
+                G3cssClass::Empty,
+            ];
+
+            match self.between(0, blocks.len()) {
+                0 => G3cssClass::Important(self.pick(&["true", "false"]).to_string()),
+                index => blocks[index - 1](self.many(1, 3, Self::element)),
+            }
+        }
+
+        fn breakpoint(&mut self) -> Vec<G3cssPanoramic> {
+            vec![
+                G3cssPanoramic::Breakpoint(self.key()),
+                G3cssPanoramic::Children(self.many(1, 2, Self::block)),
+            ]
+        }
+
+        fn class(&mut self) -> Vec<G3cssClass> {
+            let mut class = vec![G3cssClass::ClassName(self.name())];
+
+            if self.between(0, 1) == 1 {
+                class.push(G3cssClass::Inherits(self.name()));
+            }
+
+            for _ in 0..self.between(1, 3) {
+                match self.between(0, 3) {
+                    0 => class.push(G3cssClass::PanoramicViewer(self.many(
+                        1,
+                        2,
+                        Self::breakpoint,
+                    ))),
+                    _ => class.push(self.block()),
+                }
+            }
+
+            class
+        }
+
+        /// An element of a scope, themes included only for a global.
+        fn child(&mut self, is_global: bool) -> G3cssChildren {
+            match self.between(0, if is_global { 5 } else { 3 }) {
+                0 => G3cssChildren::Aliases(self.many(1, 3, |generator| {
+                    G3cssAlias::Alias(vec![generator.key(), generator.key()])
+                })),
+                1 => G3cssChildren::Variables(self.many(1, 3, Self::variable)),
+                2 => G3cssChildren::Class(self.class()),
+                3 => G3cssChildren::Classes(self.many(1, 2, Self::class)),
+                4 => G3cssChildren::LightTheme(vec![G3cssTheme::Variables(self.many(
+                    1,
+                    3,
+                    Self::variable,
+                ))]),
+                _ => G3cssChildren::DarkTheme(vec![G3cssTheme::Variables(self.many(
+                    1,
+                    3,
+                    Self::variable,
+                ))]),
+            }
+        }
+
+        fn scope(&mut self) -> G3cssNode {
+            let is_global = self.between(0, 1) == 1;
+            let mut nodes = vec![G3cssNode::Name(self.name())];
+
+            if !is_global && self.between(0, 1) == 1 {
+                nodes.push(G3cssNode::Extends(self.name()));
+            }
+
+            nodes.push(G3cssNode::Children(
+                (0..self.between(1, 4))
+                    .map(|_| self.child(is_global))
+                    .collect(),
+            ));
+
+            match is_global {
+                true => G3cssNode::Global(nodes),
+                false => G3cssNode::Component(nodes),
+            }
+        }
+    }
+
+    #[test]
+    fn printed_generated_asts_parse_back_to_the_same_ast() {
+        for seed in 1..=GENERATED_ASTS {
+            let ast = AstGenerator(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15)).scope();
+            let printed = print_ast(&ast).unwrap();
+            let reparsed = g3css_parse_source(&printed)
+                .unwrap_or_else(|error| panic!("seed {}: {:?}\n{}", seed, error, printed));
+
+            assert_eq!(
+                *reparsed, ast,
+                "seed {} changed when printed:\n{}",
+                seed, printed
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::rc::Rc;
+
+use crate::types::{
+    g3css_ast_document::{G3cssAstDocument, G3cssAstHeader, G3CSS_AST_SCHEMA, G3CSS_AST_VERSION},
+    g3css_error::G3cssError,
+    g3css_node::G3cssNode,
+};
+
+/// Wraps an AST in a document of the current schema version.
+fn ast_document(ast: &G3cssNode) -> G3cssAstDocument {
+    G3cssAstDocument {
+        schema: G3CSS_AST_SCHEMA.to_string(),
+        version: G3CSS_AST_VERSION,
+        ast: ast.clone(),
+    }
+}
+
+/// Checks that a serialized AST was written with a schema this build reads.
+fn check_header(header: G3cssAstHeader) -> Result<(), G3cssError> {
+    if header.schema != G3CSS_AST_SCHEMA {
+        return Err(G3cssError::OtherError(format!(
+            "Unknown AST schema \"{}\", expected \"{}\"",
+            header.schema, G3CSS_AST_SCHEMA
+        )));
+    }
+
+    if header.version == 0 || header.version > G3CSS_AST_VERSION {
+        return Err(G3cssError::OtherError(format!(
+            "Unsupported AST schema version {}, this build reads versions up to {}",
+            header.version, G3CSS_AST_VERSION
+        )));
+    }
+
+    Ok(())
+}
+
+/// Serializes an AST to JSON, wrapped in a versioned document.
+///
+/// # Arguments
+///
+/// - `ast` - The AST to serialize.
+/// - `pretty` - Whether the JSON is indented.
+///
+/// # Returns
+///
+/// - `Result<String, G3cssError>` - Ok containing the JSON document, or an Err if the AST
+///   could not be serialized.
+pub fn ast_to_json(ast: &G3cssNode, pretty: bool) -> Result<String, G3cssError> {
+    let document = ast_document(ast);
+
+    let json = match pretty {
+        true => serde_json::to_string_pretty(&document),
+        false => serde_json::to_string(&document),
+    };
+
+    json.map_err(|error| G3cssError::OtherError(error.to_string()))
+}
+
+/// Deserializes an AST from a JSON document written by `ast_to_json`.
+///
+/// # Arguments
+///
+/// - `json` - The JSON document.
+///
+/// # Returns
+///
+/// - `Result<Rc<G3cssNode>, G3cssError>` - Ok containing the AST, or an Err if the document
+///   is malformed or was written with an unsupported schema version.
+pub fn ast_from_json(json: &str) -> Result<Rc<G3cssNode>, G3cssError> {
+    let invalid = |error: serde_json::Error| {
+        G3cssError::OtherError(format!("Invalid AST document: {}", error))
+    };
+
+    check_header(serde_json::from_str::<G3cssAstHeader>(json).map_err(invalid)?)?;
+
+    let document = serde_json::from_str::<G3cssAstDocument>(json).map_err(invalid)?;
+    Ok(Rc::new(document.ast))
+}
+
+/// Serializes an AST to MessagePack, wrapped in a versioned document.
+///
+/// # Arguments
+///
+/// - `ast` - The AST to serialize.
+///
+/// # Returns
+///
+/// - `Result<Vec<u8>, G3cssError>` - Ok containing the encoded document, or an Err if the
+///   AST could not be serialized.
+#[cfg(feature = "msgpack")]
+pub fn ast_to_msgpack(ast: &G3cssNode) -> Result<Vec<u8>, G3cssError> {
+    // Fields are written by name so the header can be read on its own.
+    rmp_serde::to_vec_named(&ast_document(ast))
+        .map_err(|error| G3cssError::OtherError(error.to_string()))
+}
+
+/// Deserializes an AST from a MessagePack document written by `ast_to_msgpack`.
+///
+/// # Arguments
+///
+/// - `bytes` - The encoded document.
+///
+/// # Returns
+///
+/// - `Result<Rc<G3cssNode>, G3cssError>` - Ok containing the AST, or an Err if the document
+///   is malformed or was written with an unsupported schema version.
+#[cfg(feature = "msgpack")]
+pub fn ast_from_msgpack(bytes: &[u8]) -> Result<Rc<G3cssNode>, G3cssError> {
+    let invalid = |error: rmp_serde::decode::Error| {
+        G3cssError::OtherError(format!("Invalid AST document: {}", error))
+    };
+
+    check_header(rmp_serde::from_slice::<G3cssAstHeader>(bytes).map_err(invalid)?)?;
+
+    let document = rmp_serde::from_slice::<G3cssAstDocument>(bytes).map_err(invalid)?;
+    Ok(Rc::new(document.ast))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::g3css_parse_source;
+
+    /// Sample files covering every kind of scope element.
+    const SAMPLES: [&str; 4] = [
+        include_str!("../../tests/samples/app.g3css"),
+        include_str!("../../tests/samples/button.g3css"),
+        include_str!("../../tests/samples/layout.g3css"),
+        include_str!("../../tests/samples/commented.g3css"),
+    ];
+
+    /// Builds a document of the given schema and version around the first sample.
+    fn document(schema: &str, version: u32) -> G3cssAstDocument {
+        G3cssAstDocument {
+            schema: schema.to_string(),
+            version,
+            ast: (*g3css_parse_source(SAMPLES[0]).unwrap()).clone(),
+        }
+    }
+
+    #[test]
+    fn json_round_trip_keeps_the_ast() {
+        for source in SAMPLES {
+            let ast = g3css_parse_source(source).unwrap();
+
+            for pretty in [false, true] {
+                let json = ast_to_json(&ast, pretty).unwrap();
+                assert_eq!(*ast_from_json(&json).unwrap(), *ast);
+            }
+        }
+    }
+
+    #[test]
+    fn json_documents_carry_the_schema_and_version() {
+        let ast = g3css_parse_source(SAMPLES[0]).unwrap();
+        let json = ast_to_json(&ast, false).unwrap();
+        let document: G3cssAstDocument = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(document.schema, G3CSS_AST_SCHEMA);
+        assert_eq!(document.version, G3CSS_AST_VERSION);
+    }
+
+    #[test]
+    fn json_with_a_newer_version_is_rejected() {
+        let json =
+            serde_json::to_string(&document(G3CSS_AST_SCHEMA, G3CSS_AST_VERSION + 1)).unwrap();
+        let error = ast_from_json(&json).unwrap_err();
+
+        assert!(matches!(error, G3cssError::OtherError(message)
+            if message.starts_with("Unsupported AST schema version")));
+    }
+
+    #[test]
+    fn json_with_version_zero_is_rejected() {
+        let json = serde_json::to_string(&document(G3CSS_AST_SCHEMA, 0)).unwrap();
+
+        assert!(ast_from_json(&json).is_err());
+    }
+
+    #[test]
+    fn json_with_another_schema_is_rejected() {
+        let json = serde_json::to_string(&document("other", G3CSS_AST_VERSION)).unwrap();
+        let error = ast_from_json(&json).unwrap_err();
+
+        assert!(matches!(error, G3cssError::OtherError(message)
+            if message.starts_with("Unknown AST schema")));
+    }
+
+    #[test]
+    fn json_without_a_header_is_rejected() {
+        assert!(ast_from_json("{\"ast\":\"Unit\"}").is_err());
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn msgpack_round_trip_keeps_the_ast() {
+        for source in SAMPLES {
+            let ast = g3css_parse_source(source).unwrap();
+            let bytes = ast_to_msgpack(&ast).unwrap();
+
+            assert_eq!(*ast_from_msgpack(&bytes).unwrap(), *ast);
+        }
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn msgpack_with_a_newer_version_is_rejected() {
+        let bytes =
+            rmp_serde::to_vec_named(&document(G3CSS_AST_SCHEMA, G3CSS_AST_VERSION + 1)).unwrap();
+        let error = ast_from_msgpack(&bytes).unwrap_err();
+
+        assert!(matches!(error, G3cssError::OtherError(message)
+            if message.starts_with("Unsupported AST schema version")));
+    }
+}
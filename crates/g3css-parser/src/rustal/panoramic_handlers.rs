@@ -75,17 +75,12 @@ pub fn build_nodes_from_panoramic_viewer(
 
     // Iterate over each inner pair within the provided pair
     for inner_pair in pair.into_inner() {
-        // Match the rule of the inner pair to determine the type of node
-        match inner_pair.as_rule() {
-            // If the inner pair matches Rule::breakpoint,
-            // build nodes from breakpoints and push them to nodes
-            Rule::breakpoint => {
-                if let Some(node) = build_nodes_from_breakpoints(inner_pair) {
-                    nodes.push(node);
-                }
+        // If the inner pair matches Rule::breakpoint,
+        // build nodes from breakpoints and push them to nodes; ignore other rules
+        if inner_pair.as_rule() == Rule::breakpoint {
+            if let Some(node) = build_nodes_from_breakpoints(inner_pair) {
+                nodes.push(node);
             }
-            // Ignore other rules
-            _ => (),
         }
     }
 
@@ -0,0 +1,357 @@
+use pest::{iterators::Pair, Position};
+
+use crate::{
+    types::{
+        g3css_span::G3cssSpan,
+        g3css_symbol::{G3cssSymbol, G3cssSymbolKind},
+    },
+    Rule,
+};
+
+/// Collects the symbols of a G3CSS source while walking its parsing pairs.
+struct SymbolCollector<'a> {
+    src: &'a str,
+    symbols: Vec<G3cssSymbol>,
+}
+
+impl<'a> SymbolCollector<'a> {
+    /// Builds a span between two byte offsets of the source.
+    fn span(&self, start: usize, end: usize) -> G3cssSpan {
+        let (line, column) = Position::new(self.src, start)
+            .map(|position| position.line_col())
+            .unwrap_or((1, 1));
+
+        G3cssSpan {
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+
+    /// Builds the span covered by a parsing pair.
+    fn pair_span(&self, pair: &Pair<Rule>) -> G3cssSpan {
+        self.span(pair.as_span().start(), pair.as_span().end())
+    }
+
+    /// Builds the span of a quoted pair, excluding its quotes.
+    fn unquoted_span(&self, pair: &Pair<Rule>) -> G3cssSpan {
+        let text = pair.as_str();
+        let start = pair.as_span().start();
+
+        // Quotes may be any Unicode quotation mark, so their width is measured.
+        match (text.chars().next(), text.chars().last()) {
+            (Some(first), Some(last)) if text.chars().count() >= 2 => self.span(
+                start + first.len_utf8(),
+                start + text.len() - last.len_utf8(),
+            ),
+            _ => self.pair_span(pair),
+        }
+    }
+
+    /// Builds the span of a keyword found at or after the start of a pair.
+    fn keyword_span(&self, pair: &Pair<Rule>, keyword: &str) -> G3cssSpan {
+        let start = pair.as_span().start() + pair.as_str().find(keyword).unwrap_or(0);
+
+        self.span(start, start + keyword.len())
+    }
+
+    /// Pushes a symbol and returns its index.
+    fn push(
+        &mut self,
+        kind: G3cssSymbolKind,
+        name: &str,
+        span: G3cssSpan,
+        range: G3cssSpan,
+        parent: Option<usize>,
+    ) -> usize {
+        self.symbols.push(G3cssSymbol {
+            kind,
+            name: name.to_string(),
+            span,
+            range,
+            parent,
+        });
+
+        self.symbols.len() - 1
+    }
+
+    /// Collects a global or component scope.
+    fn visit_scope(&mut self, pair: Pair<Rule>) {
+        let range = self.pair_span(&pair);
+        let mut scope = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                // The name always comes first, so it becomes the parent of everything else.
+                Rule::name => {
+                    let span = self.unquoted_span(&inner_pair);
+                    let name = inner_pair.as_str().trim_matches('"');
+                    scope = Some(self.push(G3cssSymbolKind::Scope, name, span, range, None));
+                }
+                Rule::extends => {
+                    let span = self.unquoted_span(&inner_pair);
+                    let name = inner_pair.as_str().trim_matches('"');
+                    self.push(G3cssSymbolKind::Extends, name, span, span, scope);
+                }
+                Rule::global_children | Rule::component_children => {
+                    self.visit_children(inner_pair, scope);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Collects the aliases, variables, themes and classes of a scope.
+    fn visit_children(&mut self, pair: Pair<Rule>, parent: Option<usize>) {
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::aliases => {
+                    for alias in inner_pair.into_inner() {
+                        self.visit_alias(alias, parent);
+                    }
+                }
+                Rule::variables => self.visit_variables(inner_pair, parent),
+                Rule::light_theme | Rule::dark_theme => {
+                    let name = match inner_pair.as_rule() {
+                        Rule::light_theme => "light",
+                        _ => "dark",
+                    };
+                    let span = self.keyword_span(&inner_pair, name);
+                    let range = self.pair_span(&inner_pair);
+                    let theme = self.push(G3cssSymbolKind::Theme, name, span, range, parent);
+
+                    for variables in inner_pair.into_inner() {
+                        self.visit_variables(variables, Some(theme));
+                    }
+                }
+                Rule::class => self.visit_class(inner_pair, parent),
+                Rule::classes => {
+                    for class in inner_pair.into_inner() {
+                        self.visit_class(class, parent);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Collects an alias and the property it points to.
+    fn visit_alias(&mut self, pair: Pair<Rule>, parent: Option<usize>) {
+        let range = self.pair_span(&pair);
+        let mut alias = parent;
+
+        for inner_pair in pair.into_inner() {
+            let span = self.pair_span(&inner_pair);
+            let name = inner_pair.as_str().trim();
+
+            match inner_pair.as_rule() {
+                Rule::leading => {
+                    alias = Some(self.push(G3cssSymbolKind::Alias, name, span, range, parent));
+                }
+                Rule::importance => {
+                    self.push(G3cssSymbolKind::AliasTarget, name, span, span, alias);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Collects every variable of a variables block and their values.
+    fn visit_variables(&mut self, pair: Pair<Rule>, parent: Option<usize>) {
+        for variable in pair.into_inner() {
+            let range = self.pair_span(&variable);
+            let mut key = parent;
+
+            for inner_pair in variable.into_inner() {
+                match inner_pair.as_rule() {
+                    Rule::leading => {
+                        let span = self.pair_span(&inner_pair);
+                        let name = inner_pair.as_str().trim();
+                        key = Some(self.push(G3cssSymbolKind::Variable, name, span, range, parent));
+                    }
+                    Rule::worth => self.visit_value(inner_pair, key),
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    /// Collects a quoted value and the variable references it contains.
+    fn visit_value(&mut self, pair: Pair<Rule>, parent: Option<usize>) {
+        let span = self.unquoted_span(&pair);
+        let text = &self.src[span.start..span.end];
+        let value = self.push(G3cssSymbolKind::Value, text, span, span, parent);
+
+        // Record every `${name}` reference, pointing at the name itself.
+        let mut offset = 0;
+
+        while let Some(start) = text[offset..].find("${") {
+            let name_start = offset + start + 2;
+
+            let Some(length) = text[name_start..].find('}') else {
+                break;
+            };
+
+            let name = &text[name_start..name_start + length];
+            let trimmed_start = name_start + (name.len() - name.trim_start().len());
+            let reference = self.span(
+                span.start + trimmed_start,
+                span.start + trimmed_start + name.trim().len(),
+            );
+            let range = self.span(
+                span.start + name_start - 2,
+                span.start + name_start + length + 1,
+            );
+
+            self.push(
+                G3cssSymbolKind::VariableReference,
+                name.trim(),
+                reference,
+                range,
+                Some(value),
+            );
+
+            offset = name_start + length + 1;
+        }
+    }
+
+    /// Collects a class, its parent and its blocks.
+    fn visit_class(&mut self, pair: Pair<Rule>, parent: Option<usize>) {
+        let range = self.pair_span(&pair);
+        let mut class = parent;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::class_name => {
+                    let span = self.unquoted_span(&inner_pair);
+                    let name = inner_pair.as_str().trim_matches('"');
+                    class = Some(self.push(G3cssSymbolKind::Class, name, span, range, parent));
+                }
+                Rule::inherits => {
+                    let span = self.unquoted_span(&inner_pair);
+                    let name = inner_pair.as_str().trim_matches('"');
+                    self.push(G3cssSymbolKind::Inherits, name, span, span, class);
+                }
+                Rule::important => (),
+                Rule::panoramic_viewer => self.visit_panoramic_viewer(inner_pair, class),
+                _ => self.visit_block(inner_pair, class),
+            }
+        }
+    }
+
+    /// Collects a `panoramic_viewer` block, its breakpoints and their blocks.
+    fn visit_panoramic_viewer(&mut self, pair: Pair<Rule>, parent: Option<usize>) {
+        let span = self.keyword_span(&pair, "panoramic_viewer");
+        let range = self.pair_span(&pair);
+        let viewer = self.push(
+            G3cssSymbolKind::PanoramicViewer,
+            "panoramic_viewer",
+            span,
+            range,
+            parent,
+        );
+
+        for breakpoint in pair.into_inner() {
+            let range = self.pair_span(&breakpoint);
+            let mut name = Some(viewer);
+
+            for inner_pair in breakpoint.into_inner() {
+                match inner_pair.as_rule() {
+                    Rule::prime => {
+                        let span = self.pair_span(&inner_pair);
+                        let breakpoint_name = inner_pair.as_str().trim();
+                        name = Some(self.push(
+                            G3cssSymbolKind::Breakpoint,
+                            breakpoint_name,
+                            span,
+                            range,
+                            Some(viewer),
+                        ));
+                    }
+                    Rule::important => (),
+                    _ => self.visit_block(inner_pair, name),
+                }
+            }
+        }
+    }
+
+    /// Collects a properties or pseudo block and its declarations.
+    fn visit_block(&mut self, pair: Pair<Rule>, parent: Option<usize>) {
+        let keyword = format!("{:?}", pair.as_rule());
+        let span = self.keyword_span(&pair, &keyword);
+        let range = self.pair_span(&pair);
+        let block = self.push(G3cssSymbolKind::Block, &keyword, span, range, parent);
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                // Nicknames hold their key and value as inner pairs.
+                Rule::nickname => {
+                    let range = self.pair_span(&inner_pair);
+                    let mut nickname = Some(block);
+
+                    for part in inner_pair.into_inner() {
+                        match part.as_rule() {
+                            Rule::primary => {
+                                let span = self.pair_span(&part);
+                                let name = part.as_str().trim();
+                                nickname = Some(self.push(
+                                    G3cssSymbolKind::Nickname,
+                                    name,
+                                    span,
+                                    range,
+                                    Some(block),
+                                ));
+                            }
+                            Rule::valuation => self.visit_value(part, nickname),
+                            _ => (),
+                        }
+                    }
+                }
+                // Property keys are silent literals, so they are located before the value.
+                _ => {
+                    let name = format!("{:?}", inner_pair.as_rule());
+                    let value_start = inner_pair.as_span().start();
+                    let key_start = self.src[range.start..value_start]
+                        .rfind(&name)
+                        .map_or(value_start, |offset| range.start + offset);
+
+                    let span = self.span(key_start, key_start + name.len());
+                    let property_range = self.span(key_start, inner_pair.as_span().end());
+                    let property = self.push(
+                        G3cssSymbolKind::Property,
+                        &name,
+                        span,
+                        property_range,
+                        Some(block),
+                    );
+
+                    self.visit_value(inner_pair, Some(property));
+                }
+            }
+        }
+    }
+}
+
+/// Builds the symbols of a parsed global or component.
+///
+/// Symbols are listed in source order, so every parent comes before its children.
+///
+/// # Arguments
+///
+/// - `src` - The source the pair was parsed from.
+/// - `pair` - The `global` or `component` pair of the source.
+///
+/// # Returns
+///
+/// The symbols found in the source.
+pub fn build_symbols(src: &str, pair: Pair<Rule>) -> Vec<G3cssSymbol> {
+    let mut collector = SymbolCollector {
+        src,
+        symbols: vec![],
+    };
+
+    collector.visit_scope(pair);
+
+    collector.symbols
+}
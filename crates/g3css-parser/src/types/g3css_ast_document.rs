@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use super::g3css_node::G3cssNode;
+
+/// Name of the schema of serialized ASTs.
+pub const G3CSS_AST_SCHEMA: &str = "g3css-ast";
+
+/// Version of the schema of serialized ASTs.
+///
+/// The AST is serialized with the names of its enums' variants, so renaming, removing or
+/// changing the content of a variant requires a new version. Adding a variant does not.
+pub const G3CSS_AST_VERSION: u32 = 1;
+
+/// Represents a serialized AST together with the schema it was written with.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct G3cssAstDocument {
+    /// Always `G3CSS_AST_SCHEMA`.
+    pub schema: String,
+    /// Version of the schema the AST was written with.
+    pub version: u32,
+    pub ast: G3cssNode,
+}
+
+/// Represents the schema and version of a serialized AST, read before the AST itself so
+/// unsupported versions are reported as such.
+#[derive(Deserialize)]
+pub struct G3cssAstHeader {
+    pub schema: String,
+    pub version: u32,
+}
@@ -1,10 +1,12 @@
+use serde::{Deserialize, Serialize};
+
 use super::{
     g3css_alias::G3cssAlias, g3css_class::G3cssClass, g3css_theme::G3cssTheme,
     g3css_variable::G3cssVariable,
 };
 
 /// Enum representing different types of children elements in the G3CSS framework
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum G3cssChildren {
     LightTheme(Vec<G3cssTheme>),
     DarkTheme(Vec<G3cssTheme>),
@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 use super::{g3css_elements::G3cssElements, g3css_panoramic::G3cssPanoramic};
 
 /// Enum representing different properties of class elements in the G3CSS framework
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum G3cssClass {
     ClassName(String),
     Inherits(String),
@@ -42,3 +44,100 @@ pub enum G3cssClass {
     Empty(Vec<G3cssElements>),
     PanoramicViewer(Vec<Vec<G3cssPanoramic>>),
 }
+
+impl G3cssClass {
+    /// Returns the G3CSS keyword and elements of a properties or pseudo block.
+    ///
+    /// # Returns
+    ///
+    /// `Some((keyword, elements))` for `properties` and the pseudo blocks, where `keyword` is
+    /// the block as written in the grammar (e.g. `first_of_type`), or `None` for the class
+    /// name, `inherits`, `!important` and `panoramic_viewer`.
+    pub fn block(&self) -> Option<(&'static str, &Vec<G3cssElements>)> {
+        match self {
+            G3cssClass::Properties(elements) => Some(("properties", elements)),
+            G3cssClass::Hover(elements) => Some(("hover", elements)),
+            G3cssClass::Active(elements) => Some(("active", elements)),
+            G3cssClass::Focus(elements) => Some(("focus", elements)),
+            G3cssClass::FirstChild(elements) => Some(("first_child", elements)),
+            G3cssClass::LastChild(elements) => Some(("last_child", elements)),
+            G3cssClass::FirstOfType(elements) => Some(("first_of_type", elements)),
+            G3cssClass::LastOfType(elements) => Some(("last_of_type", elements)),
+            G3cssClass::OnlyChild(elements) => Some(("only_child", elements)),
+            G3cssClass::OnlyOfType(elements) => Some(("only_of_type", elements)),
+            G3cssClass::TargetPseudoClass(elements) => Some(("target_pseudo_class", elements)),
+            G3cssClass::Visited(elements) => Some(("visited", elements)),
+            G3cssClass::Checked(elements) => Some(("checked", elements)),
+            G3cssClass::Disabled(elements) => Some(("disabled", elements)),
+            G3cssClass::Enabled(elements) => Some(("enabled", elements)),
+            G3cssClass::ReadOnly(elements) => Some(("read_only", elements)),
+            G3cssClass::ReadWrite(elements) => Some(("read_write", elements)),
+            G3cssClass::PlaceholderShown(elements) => Some(("placeholder_shown", elements)),
+            G3cssClass::Valid(elements) => Some(("valid", elements)),
+            G3cssClass::Invalid(elements) => Some(("invalid", elements)),
+            G3cssClass::Required(elements) => Some(("required", elements)),
+            G3cssClass::Optional(elements) => Some(("optional", elements)),
+            G3cssClass::Fullscreen(elements) => Some(("fullscreen", elements)),
+            G3cssClass::FocusWithin(elements) => Some(("focus_within", elements)),
+            G3cssClass::FirstLine(elements) => Some(("first_line", elements)),
+            G3cssClass::FirstLetter(elements) => Some(("first_letter", elements)),
+            G3cssClass::Before(elements) => Some(("before", elements)),
+            G3cssClass::After(elements) => Some(("after", elements)),
+            G3cssClass::OutOfRange(elements) => Some(("out_of_range", elements)),
+            G3cssClass::Root(elements) => Some(("root", elements)),
+            G3cssClass::FirstPage(elements) => Some(("first_page", elements)),
+            G3cssClass::LeftPage(elements) => Some(("left_page", elements)),
+            G3cssClass::RightPage(elements) => Some(("right_page", elements)),
+            G3cssClass::Empty(elements) => Some(("empty", elements)),
+            G3cssClass::ClassName(_)
+            | G3cssClass::Inherits(_)
+            | G3cssClass::Important(_)
+            | G3cssClass::PanoramicViewer(_) => None,
+        }
+    }
+
+    /// Returns the G3CSS keyword and mutable elements of a properties or pseudo block, like
+    /// `block`.
+    pub fn block_mut(&mut self) -> Option<(&'static str, &mut Vec<G3cssElements>)> {
+        match self {
+            G3cssClass::Properties(elements) => Some(("properties", elements)),
+            G3cssClass::Hover(elements) => Some(("hover", elements)),
+            G3cssClass::Active(elements) => Some(("active", elements)),
+            G3cssClass::Focus(elements) => Some(("focus", elements)),
+            G3cssClass::FirstChild(elements) => Some(("first_child", elements)),
+            G3cssClass::LastChild(elements) => Some(("last_child", elements)),
+            G3cssClass::FirstOfType(elements) => Some(("first_of_type", elements)),
+            G3cssClass::LastOfType(elements) => Some(("last_of_type", elements)),
+            G3cssClass::OnlyChild(elements) => Some(("only_child", elements)),
+            G3cssClass::OnlyOfType(elements) => Some(("only_of_type", elements)),
+            G3cssClass::TargetPseudoClass(elements) => Some(("target_pseudo_class", elements)),
+            G3cssClass::Visited(elements) => Some(("visited", elements)),
+            G3cssClass::Checked(elements) => Some(("checked", elements)),
+            G3cssClass::Disabled(elements) => Some(("disabled", elements)),
+            G3cssClass::Enabled(elements) => Some(("enabled", elements)),
+            G3cssClass::ReadOnly(elements) => Some(("read_only", elements)),
+            G3cssClass::ReadWrite(elements) => Some(("read_write", elements)),
+            G3cssClass::PlaceholderShown(elements) => Some(("placeholder_shown", elements)),
+            G3cssClass::Valid(elements) => Some(("valid", elements)),
+            G3cssClass::Invalid(elements) => Some(("invalid", elements)),
+            G3cssClass::Required(elements) => Some(("required", elements)),
+            G3cssClass::Optional(elements) => Some(("optional", elements)),
+            G3cssClass::Fullscreen(elements) => Some(("fullscreen", elements)),
+            G3cssClass::FocusWithin(elements) => Some(("focus_within", elements)),
+            G3cssClass::FirstLine(elements) => Some(("first_line", elements)),
+            G3cssClass::FirstLetter(elements) => Some(("first_letter", elements)),
+            G3cssClass::Before(elements) => Some(("before", elements)),
+            G3cssClass::After(elements) => Some(("after", elements)),
+            G3cssClass::OutOfRange(elements) => Some(("out_of_range", elements)),
+            G3cssClass::Root(elements) => Some(("root", elements)),
+            G3cssClass::FirstPage(elements) => Some(("first_page", elements)),
+            G3cssClass::LeftPage(elements) => Some(("left_page", elements)),
+            G3cssClass::RightPage(elements) => Some(("right_page", elements)),
+            G3cssClass::Empty(elements) => Some(("empty", elements)),
+            G3cssClass::ClassName(_)
+            | G3cssClass::Inherits(_)
+            | G3cssClass::Important(_)
+            | G3cssClass::PanoramicViewer(_) => None,
+        }
+    }
+}
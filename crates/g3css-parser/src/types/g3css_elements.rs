@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// Enum representing different types of elements in the G3CSS framework
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum G3cssElements {
     Nickname(Vec<String>),
     AspectRatio(String),
@@ -255,3 +257,288 @@ pub enum G3cssElements {
     ScrollbarWidth(String),
     ScrollbarGutter(String),
 }
+
+impl G3cssElements {
+    /// Returns the G3CSS property name and value held by this element.
+    ///
+    /// # Returns
+    ///
+    /// `Some((name, value))` for regular properties, where `name` is the property as written
+    /// in the grammar (e.g. `margin_top`), or `None` for nicknames, which must be resolved
+    /// through the aliases of their scope.
+    pub fn property(&self) -> Option<(&'static str, &str)> {
+        match self {
+            G3cssElements::Nickname(_) => None,
+            G3cssElements::AspectRatio(value) => Some(("aspect_ratio", value)),
+            G3cssElements::AccentColor(value) => Some(("accent_color", value)),
+            G3cssElements::BackdropFilter(value) => Some(("backdrop_filter", value)),
+            G3cssElements::Content(value) => Some(("content", value)),
+            G3cssElements::Gap(value) => Some(("gap", value)),
+            G3cssElements::RowGap(value) => Some(("row_gap", value)),
+            G3cssElements::Scale(value) => Some(("scale", value)),
+            G3cssElements::Order(value) => Some(("order", value)),
+            G3cssElements::PointerEvents(value) => Some(("pointer_events", value)),
+            G3cssElements::Margin(value) => Some(("margin", value)),
+            G3cssElements::MarginBottom(value) => Some(("margin_bottom", value)),
+            G3cssElements::MarginLeft(value) => Some(("margin_left", value)),
+            G3cssElements::MarginRight(value) => Some(("margin_right", value)),
+            G3cssElements::MarginTop(value) => Some(("margin_top", value)),
+            G3cssElements::Padding(value) => Some(("padding", value)),
+            G3cssElements::PaddingBottom(value) => Some(("padding_bottom", value)),
+            G3cssElements::PaddingLeft(value) => Some(("padding_left", value)),
+            G3cssElements::PaddingRight(value) => Some(("padding_right", value)),
+            G3cssElements::PaddingTop(value) => Some(("padding_top", value)),
+            G3cssElements::Height(value) => Some(("height", value)),
+            G3cssElements::Width(value) => Some(("width", value)),
+            G3cssElements::Filter(value) => Some(("filter", value)),
+            G3cssElements::MaxHeight(value) => Some(("max_height", value)),
+            G3cssElements::MaxWidth(value) => Some(("max_width", value)),
+            G3cssElements::MinHeight(value) => Some(("min_height", value)),
+            G3cssElements::MinWidth(value) => Some(("min_width", value)),
+            G3cssElements::Border(value) => Some(("border", value)),
+            G3cssElements::BorderBottom(value) => Some(("border_bottom", value)),
+            G3cssElements::BorderBottomColor(value) => Some(("border_bottom_color", value)),
+            G3cssElements::BorderBottomStyle(value) => Some(("border_bottom_style", value)),
+            G3cssElements::BorderBottomWidth(value) => Some(("border_bottom_width", value)),
+            G3cssElements::BorderColor(value) => Some(("border_color", value)),
+            G3cssElements::BorderLeft(value) => Some(("border_left", value)),
+            G3cssElements::BorderLeftColor(value) => Some(("border_left_color", value)),
+            G3cssElements::BorderLeftStyle(value) => Some(("border_left_style", value)),
+            G3cssElements::BorderLeftWidth(value) => Some(("border_left_width", value)),
+            G3cssElements::BorderRight(value) => Some(("border_right", value)),
+            G3cssElements::BorderRightColor(value) => Some(("border_right_color", value)),
+            G3cssElements::BorderRightStyles(value) => Some(("border_right_styles", value)),
+            G3cssElements::BorderRightWidth(value) => Some(("border_right_width", value)),
+            G3cssElements::BorderStyle(value) => Some(("border_style", value)),
+            G3cssElements::BorderTop(value) => Some(("border_top", value)),
+            G3cssElements::BorderTopColor(value) => Some(("border_top_color", value)),
+            G3cssElements::BorderTopStyle(value) => Some(("border_top_style", value)),
+            G3cssElements::BorderTopWidth(value) => Some(("border_top_width", value)),
+            G3cssElements::BorderWidth(value) => Some(("border_width", value)),
+            G3cssElements::Outline(value) => Some(("outline", value)),
+            G3cssElements::OutlineColor(value) => Some(("outline_color", value)),
+            G3cssElements::OutlineStyle(value) => Some(("outline_style", value)),
+            G3cssElements::OutlineWidth(value) => Some(("outline_width", value)),
+            G3cssElements::BorderBottomLeftRadius(value) => {
+                Some(("border_bottom_left_radius", value))
+            }
+            G3cssElements::BorderBottomRightRadius(value) => {
+                Some(("border_bottom_right_radius", value))
+            }
+            G3cssElements::BorderImage(value) => Some(("border_image", value)),
+            G3cssElements::BorderImageOutset(value) => Some(("border_image_outset", value)),
+            G3cssElements::BorderImageRepeat(value) => Some(("border_image_repeat", value)),
+            G3cssElements::BorderImageSlice(value) => Some(("border_image_slice", value)),
+            G3cssElements::BorderImageSource(value) => Some(("border_image_source", value)),
+            G3cssElements::BorderImageWidth(value) => Some(("border_image_width", value)),
+            G3cssElements::BorderRadius(value) => Some(("border_radius", value)),
+            G3cssElements::BorderTopLeftRadius(value) => Some(("border_top_left_radius", value)),
+            G3cssElements::BorderTopRightRadius(value) => Some(("border_top_right_radius", value)),
+            G3cssElements::BoxDecorationBreak(value) => Some(("box_decoration_break", value)),
+            G3cssElements::BoxShadow(value) => Some(("box_shadow", value)),
+            G3cssElements::Background(value) => Some(("background", value)),
+            G3cssElements::BackgroundAttachment(value) => Some(("background_attachment", value)),
+            G3cssElements::BackgroundColor(value) => Some(("background_color", value)),
+            G3cssElements::BackgroundImage(value) => Some(("background_image", value)),
+            G3cssElements::BackgroundPosition(value) => Some(("background_position", value)),
+            G3cssElements::BackgroundPositionX(value) => Some(("background_position_x", value)),
+            G3cssElements::BackgroundPositionY(value) => Some(("background_position_y", value)),
+            G3cssElements::BackgroundRepeat(value) => Some(("background_repeat", value)),
+            G3cssElements::BackgroundClip(value) => Some(("background_clip", value)),
+            G3cssElements::BackgroundOrigin(value) => Some(("background_origin", value)),
+            G3cssElements::BackgroundSize(value) => Some(("background_size", value)),
+            G3cssElements::BackgroundBlendMode(value) => Some(("background_blend_mode", value)),
+            G3cssElements::ColorProfile(value) => Some(("color_profile", value)),
+            G3cssElements::Opacity(value) => Some(("opacity", value)),
+            G3cssElements::RenderingIntent(value) => Some(("rendering_intent", value)),
+            G3cssElements::Font(value) => Some(("font", value)),
+            G3cssElements::FontFamily(value) => Some(("font_family", value)),
+            G3cssElements::FontSize(value) => Some(("font_size", value)),
+            G3cssElements::FontStyle(value) => Some(("font_style", value)),
+            G3cssElements::FontVariant(value) => Some(("font_variant", value)),
+            G3cssElements::FontWeight(value) => Some(("font_weight", value)),
+            G3cssElements::FontSizeAdjust(value) => Some(("font_size_adjust", value)),
+            G3cssElements::FontStretch(value) => Some(("font_stretch", value)),
+            G3cssElements::Positioning(value) => Some(("positioning", value)),
+            G3cssElements::Bottom(value) => Some(("bottom", value)),
+            G3cssElements::Clear(value) => Some(("clear", value)),
+            G3cssElements::ClipPath(value) => Some(("clip_path", value)),
+            G3cssElements::Cursor(value) => Some(("cursor", value)),
+            G3cssElements::Display(value) => Some(("display", value)),
+            G3cssElements::Float(value) => Some(("float", value)),
+            G3cssElements::Left(value) => Some(("left", value)),
+            G3cssElements::Overflow(value) => Some(("overflow", value)),
+            G3cssElements::Position(value) => Some(("position", value)),
+            G3cssElements::Right(value) => Some(("right", value)),
+            G3cssElements::Top(value) => Some(("top", value)),
+            G3cssElements::Visibility(value) => Some(("visibility", value)),
+            G3cssElements::ZIndex(value) => Some(("z_index", value)),
+            G3cssElements::Color(value) => Some(("color", value)),
+            G3cssElements::Direction(value) => Some(("direction", value)),
+            G3cssElements::FlexDirection(value) => Some(("flex_direction", value)),
+            G3cssElements::FlexWrap(value) => Some(("flex_wrap", value)),
+            G3cssElements::LetterSpacing(value) => Some(("letter_spacing", value)),
+            G3cssElements::LineHeight(value) => Some(("line_height", value)),
+            G3cssElements::LineBreak(value) => Some(("line_break", value)),
+            G3cssElements::TextAlign(value) => Some(("text_align", value)),
+            G3cssElements::TextDecoration(value) => Some(("text_decoration", value)),
+            G3cssElements::TextIndent(value) => Some(("text_indent", value)),
+            G3cssElements::TextTransform(value) => Some(("text_transform", value)),
+            G3cssElements::UnicodeBidi(value) => Some(("unicode_bidi", value)),
+            G3cssElements::VerticalAlign(value) => Some(("vertical_align", value)),
+            G3cssElements::WhiteSpace(value) => Some(("white_space", value)),
+            G3cssElements::WordSpacing(value) => Some(("word_spacing", value)),
+            G3cssElements::TextOutline(value) => Some(("text_outline", value)),
+            G3cssElements::TextOverflow(value) => Some(("text_overflow", value)),
+            G3cssElements::TextShadow(value) => Some(("text_shadow", value)),
+            G3cssElements::TextWrap(value) => Some(("text_wrap", value)),
+            G3cssElements::WordBreak(value) => Some(("word_break", value)),
+            G3cssElements::WordWrap(value) => Some(("word_wrap", value)),
+            G3cssElements::ListStyle(value) => Some(("list_style", value)),
+            G3cssElements::ListStyleImage(value) => Some(("list_style_image", value)),
+            G3cssElements::ListStylePosition(value) => Some(("list_style_position", value)),
+            G3cssElements::ListStyleType(value) => Some(("list_style_type", value)),
+            G3cssElements::BorderCollapse(value) => Some(("border_collapse", value)),
+            G3cssElements::BorderSpacing(value) => Some(("border_spacing", value)),
+            G3cssElements::CaptionSide(value) => Some(("caption_side", value)),
+            G3cssElements::EmptyCells(value) => Some(("empty_cells", value)),
+            G3cssElements::TableLayout(value) => Some(("table_layout", value)),
+            G3cssElements::MarqueeDirection(value) => Some(("marquee_direction", value)),
+            G3cssElements::MarqueePlayCount(value) => Some(("marquee_play_count", value)),
+            G3cssElements::MarqueeSpeed(value) => Some(("marquee_speed", value)),
+            G3cssElements::MarqueeStyle(value) => Some(("marquee_style", value)),
+            G3cssElements::OverflowX(value) => Some(("overflow_x", value)),
+            G3cssElements::OverflowY(value) => Some(("overflow_y", value)),
+            G3cssElements::OverflowStyle(value) => Some(("overflow_style", value)),
+            G3cssElements::Rotation(value) => Some(("rotation", value)),
+            G3cssElements::BoxAlign(value) => Some(("box_align", value)),
+            G3cssElements::BoxDirection(value) => Some(("box_direction", value)),
+            G3cssElements::BoxFlex(value) => Some(("box_flex", value)),
+            G3cssElements::BoxFlexGroup(value) => Some(("box_flex_group", value)),
+            G3cssElements::BoxLines(value) => Some(("box_lines", value)),
+            G3cssElements::BoxOrdinalGroup(value) => Some(("box_ordinal_group", value)),
+            G3cssElements::BoxOrient(value) => Some(("box_orient", value)),
+            G3cssElements::BoxPack(value) => Some(("box_pack", value)),
+            G3cssElements::AlignmentAdjust(value) => Some(("alignment_adjust", value)),
+            G3cssElements::AlignmentBaseline(value) => Some(("alignment_baseline", value)),
+            G3cssElements::BaselineShift(value) => Some(("baseline_shift", value)),
+            G3cssElements::DominantBaseline(value) => Some(("dominant_baseline", value)),
+            G3cssElements::DropInitialAfterAdjust(value) => {
+                Some(("drop_initial_after_adjust", value))
+            }
+            G3cssElements::DropInitialAfterAlign(value) => {
+                Some(("drop_initial_after_align", value))
+            }
+            G3cssElements::DropInitialBeforeAdjust(value) => {
+                Some(("drop_initial_before_adjust", value))
+            }
+            G3cssElements::DropInitialBeforeAlign(value) => {
+                Some(("drop_initial_before_align", value))
+            }
+            G3cssElements::DropInitialSize(value) => Some(("drop_initial_size", value)),
+            G3cssElements::DropInitialValue(value) => Some(("drop_initial_value", value)),
+            G3cssElements::InlineBoxAlign(value) => Some(("inline_box_align", value)),
+            G3cssElements::LineStacking(value) => Some(("line_stacking", value)),
+            G3cssElements::LineStackingRuby(value) => Some(("line_stacking_ruby", value)),
+            G3cssElements::LineStackingShift(value) => Some(("line_stacking_shift", value)),
+            G3cssElements::LineStackingStrategy(value) => Some(("line_stacking_strategy", value)),
+            G3cssElements::TextHeight(value) => Some(("text_height", value)),
+            G3cssElements::ColumnCount(value) => Some(("column_count", value)),
+            G3cssElements::ColumnFill(value) => Some(("column_fill", value)),
+            G3cssElements::ColumnGap(value) => Some(("column_gap", value)),
+            G3cssElements::ColumnRule(value) => Some(("column_rule", value)),
+            G3cssElements::ColumnRuleColor(value) => Some(("column_rule_color", value)),
+            G3cssElements::ColumnRuleStyle(value) => Some(("column_rule_style", value)),
+            G3cssElements::ColumnRuleWidth(value) => Some(("column_rule_width", value)),
+            G3cssElements::ColumnSpan(value) => Some(("column_span", value)),
+            G3cssElements::ColumnWidth(value) => Some(("column_width", value)),
+            G3cssElements::Columns(value) => Some(("columns", value)),
+            G3cssElements::Animation(value) => Some(("animation", value)),
+            G3cssElements::AnimationName(value) => Some(("animation_name", value)),
+            G3cssElements::AnimationDuration(value) => Some(("animation_duration", value)),
+            G3cssElements::AnimationTimingFunction(value) => {
+                Some(("animation_timing_function", value))
+            }
+            G3cssElements::AnimationDelay(value) => Some(("animation_delay", value)),
+            G3cssElements::AnimationFillMode(value) => Some(("animation_fill_mode", value)),
+            G3cssElements::AnimationIterationCount(value) => {
+                Some(("animation_iteration_count", value))
+            }
+            G3cssElements::AnimationDirection(value) => Some(("animation_direction", value)),
+            G3cssElements::AnimationPlayState(value) => Some(("animation_play_state", value)),
+            G3cssElements::Transform(value) => Some(("transform", value)),
+            G3cssElements::TransformOrigin(value) => Some(("transform_origin", value)),
+            G3cssElements::TransformStyle(value) => Some(("transform_style", value)),
+            G3cssElements::Perspective(value) => Some(("perspective", value)),
+            G3cssElements::PerspectiveOrigin(value) => Some(("perspective_origin", value)),
+            G3cssElements::BackfaceVisibility(value) => Some(("backface_visibility", value)),
+            G3cssElements::Transition(value) => Some(("transition", value)),
+            G3cssElements::TransitionProperty(value) => Some(("transition_property", value)),
+            G3cssElements::TransitionDuration(value) => Some(("transition_duration", value)),
+            G3cssElements::TransitionTimingFunction(value) => {
+                Some(("transition_timing_function", value))
+            }
+            G3cssElements::TransitionDelay(value) => Some(("transition_delay", value)),
+            G3cssElements::Orphans(value) => Some(("orphans", value)),
+            G3cssElements::PageBreakAfter(value) => Some(("page_break_after", value)),
+            G3cssElements::PageBreakBefore(value) => Some(("page_break_before", value)),
+            G3cssElements::PageBreakInside(value) => Some(("page_break_inside", value)),
+            G3cssElements::Widows(value) => Some(("widows", value)),
+            G3cssElements::Mark(value) => Some(("mark", value)),
+            G3cssElements::MarkAfter(value) => Some(("mark_after", value)),
+            G3cssElements::MarkBefore(value) => Some(("mark_before", value)),
+            G3cssElements::Phonemes(value) => Some(("phonemes", value)),
+            G3cssElements::Rest(value) => Some(("rest", value)),
+            G3cssElements::RestAfter(value) => Some(("rest_after", value)),
+            G3cssElements::RestBefore(value) => Some(("rest_before", value)),
+            G3cssElements::VoiceBalance(value) => Some(("voice_balance", value)),
+            G3cssElements::VoiceDuration(value) => Some(("voice_duration", value)),
+            G3cssElements::VoicePitch(value) => Some(("voice_pitch", value)),
+            G3cssElements::VoicePitchRange(value) => Some(("voice_pitch_range", value)),
+            G3cssElements::VoiceRate(value) => Some(("voice_rate", value)),
+            G3cssElements::VoiceStress(value) => Some(("voice_stress", value)),
+            G3cssElements::VoiceVolume(value) => Some(("voice_volume", value)),
+            G3cssElements::Appearance(value) => Some(("appearance", value)),
+            G3cssElements::BoxSizing(value) => Some(("box_sizing", value)),
+            G3cssElements::Icon(value) => Some(("icon", value)),
+            G3cssElements::NavDown(value) => Some(("nav_down", value)),
+            G3cssElements::NavIndex(value) => Some(("nav_index", value)),
+            G3cssElements::NavLeft(value) => Some(("nav_left", value)),
+            G3cssElements::NavRight(value) => Some(("nav_right", value)),
+            G3cssElements::NavUp(value) => Some(("nav_up", value)),
+            G3cssElements::OutlineOffset(value) => Some(("outline_offset", value)),
+            G3cssElements::Resize(value) => Some(("resize", value)),
+            G3cssElements::Quotes(value) => Some(("quotes", value)),
+            G3cssElements::Rotate(value) => Some(("rotate", value)),
+            G3cssElements::Translate(value) => Some(("translate", value)),
+            G3cssElements::UserSelect(value) => Some(("user_select", value)),
+            G3cssElements::WritingMode(value) => Some(("writing_mode", value)),
+            G3cssElements::ObjectPosition(value) => Some(("object_position", value)),
+            G3cssElements::ObjectFit(value) => Some(("object_fit", value)),
+            G3cssElements::JustifySelf(value) => Some(("justify_self", value)),
+            G3cssElements::JustifyContent(value) => Some(("justify_content", value)),
+            G3cssElements::JustifyItems(value) => Some(("justify_items", value)),
+            G3cssElements::AlignSelf(value) => Some(("align_self", value)),
+            G3cssElements::AlignContent(value) => Some(("align_content", value)),
+            G3cssElements::AlignItems(value) => Some(("align_items", value)),
+            G3cssElements::Grid(value) => Some(("grid", value)),
+            G3cssElements::GridArea(value) => Some(("grid_area", value)),
+            G3cssElements::GridAutoColumns(value) => Some(("grid_auto_columns", value)),
+            G3cssElements::GridAutoFlow(value) => Some(("grid_auto_flow", value)),
+            G3cssElements::GridAutoRows(value) => Some(("grid_auto_rows", value)),
+            G3cssElements::GridColumn(value) => Some(("grid_column", value)),
+            G3cssElements::GridColumnEnd(value) => Some(("grid_column_end", value)),
+            G3cssElements::GridColumnStart(value) => Some(("grid_column_start", value)),
+            G3cssElements::GridRow(value) => Some(("grid_row", value)),
+            G3cssElements::GridRowEnd(value) => Some(("grid_row_end", value)),
+            G3cssElements::GridRowStart(value) => Some(("grid_row_start", value)),
+            G3cssElements::GridTemplate(value) => Some(("grid_template", value)),
+            G3cssElements::GridTemplateAreas(value) => Some(("grid_template_areas", value)),
+            G3cssElements::GridTemplateColumns(value) => Some(("grid_template_columns", value)),
+            G3cssElements::GridTemplateRows(value) => Some(("grid_template_rows", value)),
+            G3cssElements::ScrollbarColor(value) => Some(("scrollbar_color", value)),
+            G3cssElements::ScrollbarWidth(value) => Some(("scrollbar_width", value)),
+            G3cssElements::ScrollbarGutter(value) => Some(("scrollbar_gutter", value)),
+        }
+    }
+}
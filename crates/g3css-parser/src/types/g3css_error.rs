@@ -4,8 +4,9 @@ use pest::error::Error;
 // Define a custom error enum for G3css-related errors
 #[derive(PartialEq, Debug, Clone)]
 pub enum G3cssError {
-    // Represents errors that occur during parsing
-    ParseError(Error<Rule>),
+    // Represents errors that occur during parsing, boxed as the pest error is far larger than
+    // the other variants and would otherwise grow every `Result` holding a G3cssError
+    ParseError(Box<Error<Rule>>),
     // Represents other types of errors with a custom error message
     OtherError(String),
 }
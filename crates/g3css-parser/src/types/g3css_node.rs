@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 use super::g3css_children::G3cssChildren;
 
 /// Enum representing nodes in the G3CSS abstract syntax tree (AST)
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum G3cssNode {
     Component(Vec<G3cssNode>),
     Global(Vec<G3cssNode>),
@@ -0,0 +1,10 @@
+/// Represents the location of a piece of G3CSS source.
+/// Offsets are byte offsets into the source, while line and column are 1-based and
+/// count characters, matching the positions reported by parse errors.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct G3cssSpan {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
@@ -0,0 +1,50 @@
+use super::g3css_span::G3cssSpan;
+
+/// Enum representing the kinds of named elements found in G3CSS source.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum G3cssSymbolKind {
+    /// Name of a global or component scope.
+    Scope,
+    /// Name of the global extended by a component.
+    Extends,
+    /// A `light theme` or `dark theme` block, named `light` or `dark`.
+    Theme,
+    /// Key of an alias (the nickname it declares).
+    Alias,
+    /// Property an alias points to.
+    AliasTarget,
+    /// Key of a variable, either plain or declared by a theme.
+    Variable,
+    /// Name of a class.
+    Class,
+    /// Name of the class inherited by a class.
+    Inherits,
+    /// A properties or pseudo block, named after its keyword (e.g. `hover`).
+    Block,
+    /// A `panoramic_viewer` block.
+    PanoramicViewer,
+    /// Name of a `panoramic_viewer` breakpoint.
+    Breakpoint,
+    /// Key of a property declaration (e.g. `display`).
+    Property,
+    /// Key of a nickname declaration.
+    Nickname,
+    /// A quoted value, without its quotes.
+    Value,
+    /// A `${name}` variable reference inside a value, named after the variable.
+    VariableReference,
+}
+
+/// Represents a named element of G3CSS source and where it was written.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct G3cssSymbol {
+    pub kind: G3cssSymbolKind,
+    /// Name of the element (e.g. the class name, the property key or the value text).
+    pub name: String,
+    /// Location of the name itself.
+    pub span: G3cssSpan,
+    /// Location of the whole element, including its body.
+    pub range: G3cssSpan,
+    /// Index of the enclosing symbol, always lower than the index of this symbol.
+    pub parent: Option<usize>,
+}
@@ -1,8 +1,10 @@
+use serde::{Deserialize, Serialize};
+
 use super::g3css_variable::G3cssVariable;
 
 /// Enum representing a G3CSS theme.
 /// Represents a theme with a vector of G3CSS variables.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum G3cssTheme {
     Variables(Vec<G3cssVariable>),
 }
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 /// Enum representing a G3CSS variable.
 /// Represents a variable with a vector of strings.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum G3cssVariable {
     Variable(Vec<String>),
 }
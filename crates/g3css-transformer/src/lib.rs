@@ -1,3 +1,106 @@
-pub fn g3css_transformer() {
-    println!("Hello, world!");
+pub mod types {
+    pub mod browser_target;
+    pub mod css_declaration;
+    pub mod css_mapping;
+    pub mod css_origin;
+    pub mod css_rule;
+    pub mod css_stylesheet;
+    pub mod resolved_class;
+    pub mod scope_context;
+    pub mod source_map_source;
+    pub mod transformer_options;
+}
+
+pub mod rustal {
+    pub mod atomic_handlers;
+    pub mod browser_query;
+    pub mod class_resolver;
+    pub mod css_emitter;
+    pub mod css_minifier;
+    pub mod css_writer;
+    pub mod optimizer_handlers;
+    pub mod prefix_handlers;
+    pub mod pseudo_handlers;
+    pub mod rule_handlers;
+    pub mod scope_handlers;
+    pub mod source_map_handlers;
+    pub mod static_handlers;
+    pub mod value_handlers;
+}
+
+use g3css_parser::types::{g3css_error::G3cssError, g3css_node::G3cssNode};
+use rustal::{
+    atomic_handlers::build_atomic_stylesheet,
+    browser_query::parse_browser_query,
+    class_resolver::resolve_class,
+    optimizer_handlers::optimize_rules,
+    prefix_handlers::prefix_rules,
+    rule_handlers::{build_theme_rules, order_rules},
+    scope_handlers::build_scope_context,
+    static_handlers::build_static_stylesheet,
+};
+use types::{
+    css_stylesheet::CssStylesheet,
+    transformer_options::{G3cssOutputMode, G3cssTransformerOptions},
+};
+
+/// Transforms a parsed global or component into a CSS stylesheet.
+///
+/// Classes are resolved first (inheritance, nicknames and variables), then turned into
+/// rules according to the selected output mode. Theme variables of a global are emitted as
+/// custom properties on `:root` by the global only, the components extending it referring
+/// to them. When enabled, the optimization pass runs next, and the vendor prefixes required
+/// by the browser targets are added last.
+///
+/// # Arguments
+///
+/// - `ast` - The global or component to transform.
+/// - `global` - The global extended by `ast`, required when `ast` extends one.
+/// - `options` - The options controlling the generated CSS.
+///
+/// # Returns
+///
+/// - `Result<CssStylesheet, G3cssError>` - Ok containing the generated stylesheet, or an Err
+///   describing the first declaration that could not be resolved or the invalid
+///   browser target query.
+pub fn g3css_transformer(
+    ast: &G3cssNode,
+    global: Option<&G3cssNode>,
+    options: &G3cssTransformerOptions,
+) -> Result<CssStylesheet, G3cssError> {
+    let targets = match &options.browser_targets {
+        Some(query) => parse_browser_query(query)?,
+        None => Vec::new(),
+    };
+
+    let context = build_scope_context(ast, global)?;
+
+    // Resolve every class declared by the scope itself.
+    let classes = context
+        .classes
+        .iter()
+        .map(|class| resolve_class(&context, class))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut stylesheet = match options.output_mode {
+        G3cssOutputMode::Static => build_static_stylesheet(options, &classes)?,
+        G3cssOutputMode::Atomic => build_atomic_stylesheet(options, &classes)?,
+    };
+
+    // Theme custom properties come before any class using them. Components read them from
+    // the stylesheet of their global, so only the global emits them.
+    let mut rules = match ast {
+        G3cssNode::Global(_) => build_theme_rules(options, &context),
+        _ => vec![],
+    };
+    rules.append(&mut stylesheet.rules);
+    stylesheet.rules = order_rules(options, rules);
+
+    if options.optimize {
+        stylesheet.rules = optimize_rules(stylesheet.rules);
+    }
+
+    stylesheet.rules = prefix_rules(&targets, stylesheet.rules);
+
+    Ok(stylesheet)
 }
@@ -0,0 +1,357 @@
+use std::collections::{BTreeSet, HashMap};
+
+use g3css_common::{
+    tables::css_shorthands::properties_overlap,
+    utils::hashing::{fnv1a_64, to_base36},
+};
+use g3css_parser::types::g3css_error::G3cssError;
+
+use crate::types::{
+    css_declaration::CssDeclaration,
+    css_rule::CssRule,
+    css_stylesheet::CssStylesheet,
+    resolved_class::ResolvedClass,
+    transformer_options::{G3cssHashStrategy, G3cssTransformerOptions},
+};
+
+use super::rule_handlers::{breakpoint_media, effective_declarations};
+
+/// Number of base 36 digits kept in atomic class names.
+const ATOMIC_NAME_LENGTH: usize = 6;
+
+/// Generates a stable class name for an atomic declaration.
+///
+/// The name is derived from a hash of the media query, pseudo selector and declaration, so
+/// the same declaration always gets the same class across builds. With the short strategy,
+/// the full hash is used only if the short name is already taken by a different declaration.
+///
+/// # Arguments
+///
+/// - `key` - The unique key of the atomic declaration.
+/// - `names` - The names already generated, mapped to the key they were generated for.
+/// - `hashing` - Whether names are shortened.
+///
+/// # Returns
+///
+/// The atomic class name.
+fn atomic_class_name(
+    key: &str,
+    names: &HashMap<String, String>,
+    hashing: G3cssHashStrategy,
+) -> String {
+    let hash = to_base36(fnv1a_64(key.as_bytes()));
+
+    if hashing == G3cssHashStrategy::Full {
+        return format!("g{}", hash);
+    }
+
+    let name = format!("g{}", &hash[..hash.len().min(ATOMIC_NAME_LENGTH)]);
+
+    match names.get(&name) {
+        Some(existing) if existing != key => format!("g{}", hash),
+        _ => name,
+    }
+}
+
+/// Reads the pseudo selector following the class name in the selector of an atomic rule.
+///
+/// # Arguments
+///
+/// - `rule` - The atomic rule.
+///
+/// # Returns
+///
+/// The pseudo selector, empty if the rule has none.
+fn atomic_pseudo(rule: &CssRule) -> &str {
+    rule.selector
+        .trim_start_matches('.')
+        .trim_start_matches(|character: char| character.is_ascii_alphanumeric())
+}
+
+/// Orders atomic rules so every class keeps the cascade its author wrote.
+///
+/// A rule must come after every rule it overrides, that is every rule setting a common
+/// longhand for the same breakpoint and pseudo selector and used earlier by the same class.
+/// The rules are sorted topologically along these constraints, taking the first created
+/// rule whenever several are free. If two classes order the same rules in opposite ways, no
+/// order satisfies both, and the first created remaining rule is placed to break the cycle.
+///
+/// # Arguments
+///
+/// - `rules` - The atomic rules, in the order they were created.
+/// - `usages` - The indexes of the rules used by every class, in declaration order.
+///
+/// # Returns
+///
+/// The ordered rules.
+fn order_atomic_rules(rules: Vec<CssRule>, usages: &[Vec<usize>]) -> Vec<CssRule> {
+    let mut successors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); rules.len()];
+    let mut predecessors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); rules.len()];
+
+    for usage in usages {
+        for (position, &earlier) in usage.iter().enumerate() {
+            for &later in &usage[position + 1..] {
+                let (first, second) = (&rules[earlier], &rules[later]);
+
+                let is_same_context =
+                    first.media == second.media && atomic_pseudo(first) == atomic_pseudo(second);
+
+                let is_overlapping = first.declarations.iter().any(|first| {
+                    second
+                        .declarations
+                        .iter()
+                        .any(|second| properties_overlap(&first.property, &second.property))
+                });
+
+                if earlier != later && is_same_context && is_overlapping {
+                    successors[earlier].insert(later);
+                    predecessors[later].insert(earlier);
+                }
+            }
+        }
+    }
+
+    let mut remaining: BTreeSet<usize> = (0..rules.len()).collect();
+    let mut order: Vec<usize> = Vec::with_capacity(rules.len());
+
+    while let Some(&first) = remaining.iter().next() {
+        let next = remaining
+            .iter()
+            .find(|index| predecessors[**index].is_empty())
+            .copied()
+            .unwrap_or(first);
+
+        remaining.remove(&next);
+        order.push(next);
+
+        for successor in std::mem::take(&mut successors[next]) {
+            predecessors[successor].remove(&next);
+        }
+    }
+
+    let mut rules: Vec<Option<CssRule>> = rules.into_iter().map(Some).collect();
+
+    order
+        .into_iter()
+        .filter_map(|index| rules[index].take())
+        .collect()
+}
+
+/// Splits every declaration of every resolved class into a deduplicated atomic class.
+///
+/// Each atomic class holds a single property/value pair for one pseudo selector and
+/// breakpoint. Classes sharing a declaration share the same atomic class. Rules are ordered
+/// so that, within every class, a declaration still overrides the ones written before it
+/// (see `order_atomic_rules`).
+///
+/// Deduplication is done per stylesheet: every file is transformed on its own, so an
+/// atomic rule used by several files is emitted in the stylesheet of each of them.
+///
+/// # Arguments
+///
+/// - `options` - The transformer options holding the breakpoint definitions.
+/// - `classes` - The resolved classes of the scope.
+///
+/// # Returns
+///
+/// A `CssStylesheet` with one rule per atomic class, mapping every G3CSS class to its atomic
+/// classes, or a `G3cssError` if a breakpoint is not defined.
+pub fn build_atomic_stylesheet(
+    options: &G3cssTransformerOptions,
+    classes: &[ResolvedClass],
+) -> Result<CssStylesheet, G3cssError> {
+    let mut stylesheet = CssStylesheet::default();
+    // Maps the key of every atomic declaration to its class name and rule index, and back.
+    let mut keys: HashMap<String, (String, usize)> = HashMap::new();
+    let mut names: HashMap<String, String> = HashMap::new();
+    // The indexes of the rules used by every class, in declaration order.
+    let mut usages: Vec<Vec<usize>> = vec![];
+
+    for class in classes {
+        let mut atomic_classes: Vec<String> = vec![];
+        let mut usage: Vec<usize> = vec![];
+
+        for block in &class.blocks {
+            let media = breakpoint_media(options, block.breakpoint.as_deref())?;
+
+            // Overridden declarations would conflict once split, so only effective ones are kept.
+            for declaration in effective_declarations(&block.declarations) {
+                let key = format!(
+                    "{}|{}|{}:{}{}",
+                    media.as_deref().unwrap_or(""),
+                    block.pseudo,
+                    declaration.property,
+                    declaration.value,
+                    if declaration.important {
+                        "!important"
+                    } else {
+                        ""
+                    }
+                );
+
+                let (name, index) = match keys.get(&key) {
+                    Some(entry) => entry.clone(),
+                    None => {
+                        let name = atomic_class_name(&key, &names, options.hashing);
+
+                        stylesheet.rules.push(CssRule {
+                            class_name: None,
+                            selector: format!(".{}{}", name, block.pseudo),
+                            origins: declaration.origin.iter().cloned().collect(),
+                            media: media.clone(),
+                            declarations: vec![CssDeclaration::clone(declaration)],
+                        });
+
+                        let index = stylesheet.rules.len() - 1;
+
+                        keys.insert(key.clone(), (name.clone(), index));
+                        names.insert(name.clone(), key);
+
+                        (name, index)
+                    }
+                };
+
+                usage.push(index);
+
+                if !atomic_classes.contains(&name) {
+                    atomic_classes.push(name);
+                }
+            }
+        }
+
+        usages.push(usage);
+        stylesheet
+            .class_map
+            .insert(class.name.clone(), atomic_classes);
+    }
+
+    stylesheet.rules = order_atomic_rules(stylesheet.rules, &usages);
+
+    Ok(stylesheet)
+}
+
+#[cfg(test)]
+mod tests {
+    use g3css_parser::g3css_parse_source;
+
+    use crate::{g3css_transformer, types::transformer_options::G3cssOutputMode};
+
+    use super::*;
+
+    /// Transforms a source in atomic mode, without the optimization pass.
+    fn atomic_stylesheet(source: &str) -> CssStylesheet {
+        let options = G3cssTransformerOptions {
+            output_mode: G3cssOutputMode::Atomic,
+            optimize: false,
+            ..Default::default()
+        };
+
+        g3css_transformer(&g3css_parse_source(source).unwrap(), None, &options).unwrap()
+    }
+
+    /// Lists the property set by every rule of a stylesheet, in order.
+    fn rule_properties(stylesheet: &CssStylesheet) -> Vec<&str> {
+        stylesheet
+            .rules
+            .iter()
+            .map(|rule| rule.declarations[0].property.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn a_later_shorthand_still_overrides_a_larger_one() {
+        let stylesheet = atomic_stylesheet(
+            r#"define global scope "APP" {
+                define class "A" {
+                    properties: {
+                        border_top: "1px solid red",
+                        border_width: "2px",
+                    },
+                },
+            }"#,
+        );
+
+        assert_eq!(rule_properties(&stylesheet), ["border-top", "border-width"]);
+    }
+
+    #[test]
+    fn a_longhand_used_first_by_another_class_moves_after_its_shorthand() {
+        let stylesheet = atomic_stylesheet(
+            r#"define global scope "APP" {
+                define class "A" {
+                    properties: {
+                        margin_top: "4px",
+                    },
+                },
+                define class "B" {
+                    properties: {
+                        margin: "0",
+                        margin_top: "4px",
+                    },
+                },
+            }"#,
+        );
+
+        assert_eq!(rule_properties(&stylesheet), ["margin", "margin-top"]);
+    }
+
+    #[test]
+    fn unrelated_rules_keep_their_first_use_order() {
+        let stylesheet = atomic_stylesheet(
+            r#"define global scope "APP" {
+                define class "A" {
+                    properties: {
+                        color: "red",
+                        padding_left: "1px",
+                    },
+                },
+                define class "B" {
+                    properties: {
+                        opacity: "0.5",
+                        padding: "2px",
+                    },
+                },
+            }"#,
+        );
+
+        assert_eq!(
+            rule_properties(&stylesheet),
+            ["color", "padding-left", "opacity", "padding"]
+        );
+    }
+
+    #[test]
+    fn shared_declarations_map_to_the_same_atomic_class() {
+        let stylesheet = atomic_stylesheet(
+            r#"define global scope "APP" {
+                define class "A" {
+                    properties: {
+                        color: "red",
+                        cursor: "pointer",
+                    },
+                },
+                define class "B" {
+                    properties: {
+                        color: "red",
+                    },
+                    hover: {
+                        color: "red",
+                    },
+                },
+            }"#,
+        );
+
+        let first = &stylesheet.class_map["A"];
+        let second = &stylesheet.class_map["B"];
+
+        assert_eq!(stylesheet.rules.len(), 3);
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 2);
+        assert_eq!(first[0], second[0]);
+        assert_ne!(second[0], second[1]);
+        assert!(stylesheet
+            .rules
+            .iter()
+            .any(|rule| rule.selector == format!(".{}:hover", second[1])));
+    }
+}
@@ -0,0 +1,294 @@
+use g3css_common::tables::browser_compat::{find_g3css_browser, G3cssBrowser, G3CSS_BROWSERS};
+use g3css_parser::types::g3css_error::G3cssError;
+
+use crate::types::browser_target::BrowserTarget;
+
+/// Queries understood by `parse_browser_query`, named by its errors.
+const SUPPORTED_QUERIES: &str = "supported queries are `defaults`, `last N versions`, \
+    `last N <browser> versions`, `<browser> >= <version>` (or `>`, `<=`, `<`), \
+    `<browser> <version>`, `<browser> <from>-<to>` and `not dead`";
+
+/// Builds the error returned for a query the parser does not understand.
+fn query_error(query: &str, reason: &str) -> G3cssError {
+    G3cssError::OtherError(format!("Invalid browser query \"{}\": {}", query, reason))
+}
+
+/// Parses a version such as `15.4` or `120` into its major and minor numbers.
+fn parse_version(query: &str, version: &str) -> Result<(u32, u32), G3cssError> {
+    let (major, minor) = version.split_once('.').unwrap_or((version, "0"));
+    // Patch numbers (e.g. `15.4.1`) do not change which prefixes are needed.
+    let minor = minor.split('.').next().unwrap_or("0");
+
+    match (major.parse(), minor.parse()) {
+        (Ok(major), Ok(minor)) => Ok((major, minor)),
+        _ => Err(query_error(
+            query,
+            &format!("\"{}\" is not a version number", version),
+        )),
+    }
+}
+
+/// Finds the newest version strictly older than a version, patch numbers being ignored.
+fn previous_version(query: &str, version: (u32, u32)) -> Result<(u32, u32), G3cssError> {
+    match version {
+        (0, 0) => Err(query_error(query, "no version is older than 0")),
+        (major, 0) => Ok((major - 1, u32::MAX)),
+        (major, minor) => Ok((major, minor - 1)),
+    }
+}
+
+/// Looks up the browser named by a query.
+fn parse_browser(query: &str, name: &str) -> Result<&'static G3cssBrowser, G3cssError> {
+    find_g3css_browser(name)
+        .ok_or_else(|| query_error(query, &format!("unknown browser \"{}\"", name)))
+}
+
+/// Parses the count of a `last N versions` query.
+fn parse_count(query: &str, count: &str) -> Result<usize, G3cssError> {
+    match count.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(query_error(
+            query,
+            &format!("\"{}\" is not a positive number", count),
+        )),
+    }
+}
+
+/// Selects the last `count` known releases of a browser.
+fn last_versions(browser: &'static G3cssBrowser, count: usize) -> BrowserTarget {
+    let versions = browser.versions;
+    let first = versions.len().saturating_sub(count);
+
+    BrowserTarget {
+        browser: browser.name,
+        min_version: versions[first],
+        max_version: None,
+    }
+}
+
+/// Parses a single query of a comma separated list.
+fn parse_single_query(query: &str) -> Result<Vec<BrowserTarget>, G3cssError> {
+    let words = query.split_whitespace().collect::<Vec<_>>();
+
+    match words.as_slice() {
+        ["defaults"] => Ok(G3CSS_BROWSERS
+            .iter()
+            .filter(|browser| !browser.dead)
+            .map(|browser| last_versions(browser, 2))
+            .collect()),
+        ["last", count, "versions" | "version"] => {
+            let count = parse_count(query, count)?;
+
+            Ok(G3CSS_BROWSERS
+                .iter()
+                .filter(|browser| !browser.dead)
+                .map(|browser| last_versions(browser, count))
+                .collect())
+        }
+        ["last", count, name, "versions" | "version"] => {
+            let count = parse_count(query, count)?;
+            Ok(vec![last_versions(parse_browser(query, name)?, count)])
+        }
+        // `not dead` is handled by `parse_browser_query`, other exclusions are not supported.
+        ["not", ..] => Err(query_error(
+            query,
+            &format!("unsupported query; {}", SUPPORTED_QUERIES),
+        )),
+        [">" | ">=" | "<" | "<=", ..] => Err(query_error(
+            query,
+            &format!(
+                "usage statistics are not available offline; {}",
+                SUPPORTED_QUERIES
+            ),
+        )),
+        [name, operator @ (">=" | ">" | "<=" | "<"), version] => {
+            let browser = parse_browser(query, name)?.name;
+            let version = parse_version(query, version)?;
+
+            let (min_version, max_version) = match *operator {
+                ">=" => (version, None),
+                ">" => ((version.0, version.1 + 1), None),
+                "<=" => ((0, 0), Some(version)),
+                // `<` excludes the version itself.
+                _ => ((0, 0), Some(previous_version(query, version)?)),
+            };
+
+            Ok(vec![BrowserTarget {
+                browser,
+                min_version,
+                max_version,
+            }])
+        }
+        [name, version] => {
+            let browser = parse_browser(query, name)?.name;
+
+            // Either a single version (`safari 14`) or an inclusive range (`safari 14-15.4`).
+            let (min_version, max_version) = match version.split_once('-') {
+                Some((min, max)) => (parse_version(query, min)?, parse_version(query, max)?),
+                None => {
+                    let version = parse_version(query, version)?;
+                    (version, version)
+                }
+            };
+
+            Ok(vec![BrowserTarget {
+                browser,
+                min_version,
+                max_version: Some(max_version),
+            }])
+        }
+        _ => Err(query_error(
+            query,
+            &format!("unsupported query; {}", SUPPORTED_QUERIES),
+        )),
+    }
+}
+
+/// Parses a browserslist-style target query into the browser versions it selects.
+///
+/// Supported queries are `defaults`, `last N versions`, `last N <browser> versions`,
+/// `<browser> <op> <version>` (with `>=`, `>`, `<=` or `<`), `<browser> <version>` and
+/// `<browser> <from>-<to>`, combined with commas or `or`. `not dead` removes the dead
+/// browsers selected by the queries before it. Queries relying on usage statistics (e.g.
+/// `> 0.5%`) are rejected since the compatibility table is embedded offline.
+///
+/// # Arguments
+///
+/// - `query` - The target query (e.g. `last 2 versions, safari >= 14`).
+///
+/// # Returns
+///
+/// - `Result<Vec<BrowserTarget>, G3cssError>` - Ok containing every selected browser range,
+///   or an Err describing the first query that could not be parsed.
+pub fn parse_browser_query(query: &str) -> Result<Vec<BrowserTarget>, G3cssError> {
+    let query = query.to_lowercase();
+    let mut targets: Vec<BrowserTarget> = Vec::new();
+
+    for part in query
+        .split(',')
+        .flat_map(|part| part.split(" or "))
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        if part.split_whitespace().eq(["not", "dead"]) {
+            targets.retain(|target| {
+                find_g3css_browser(target.browser).is_some_and(|browser| !browser.dead)
+            });
+        } else {
+            targets.append(&mut parse_single_query(part)?);
+        }
+    }
+
+    Ok(targets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the target of one browser.
+    fn target(
+        browser: &'static str,
+        min_version: (u32, u32),
+        max_version: Option<(u32, u32)>,
+    ) -> BrowserTarget {
+        BrowserTarget {
+            browser,
+            min_version,
+            max_version,
+        }
+    }
+
+    #[test]
+    fn single_browser_queries_select_their_range() {
+        let cases = [
+            ("safari >= 15", target("safari", (15, 0), None)),
+            ("safari > 15", target("safari", (15, 1), None)),
+            ("safari <= 15", target("safari", (0, 0), Some((15, 0)))),
+            (
+                "safari < 15",
+                target("safari", (0, 0), Some((14, u32::MAX))),
+            ),
+            ("safari < 15.4", target("safari", (0, 0), Some((15, 3)))),
+            ("ios 14", target("ios_saf", (14, 0), Some((14, 0)))),
+            ("Safari 14-15.4.1", target("safari", (14, 0), Some((15, 4)))),
+            ("last 2 ie versions", target("ie", (10, 0), None)),
+            ("last 1 ff version", target("firefox", (132, 0), None)),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(parse_browser_query(query).unwrap(), [expected], "{}", query);
+        }
+    }
+
+    #[test]
+    fn shared_queries_select_every_living_browser() {
+        let living = G3CSS_BROWSERS
+            .iter()
+            .filter(|browser| !browser.dead)
+            .count();
+
+        for query in ["defaults", "last 2 versions", "last 3 version"] {
+            let targets = parse_browser_query(query).unwrap();
+
+            assert_eq!(targets.len(), living, "{}", query);
+            assert!(
+                targets.iter().all(|target| target.browser != "ie"),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn queries_combine_with_commas_and_or() {
+        let targets = parse_browser_query("safari >= 15, chrome >= 120 or ie 11").unwrap();
+
+        assert_eq!(
+            targets,
+            [
+                target("safari", (15, 0), None),
+                target("chrome", (120, 0), None),
+                target("ie", (11, 0), Some((11, 0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn not_dead_removes_the_dead_browsers_selected_before_it() {
+        assert_eq!(
+            parse_browser_query("ie 11, safari >= 15, not dead").unwrap(),
+            [target("safari", (15, 0), None)]
+        );
+        assert_eq!(
+            parse_browser_query("not dead, ie 11").unwrap(),
+            [target("ie", (11, 0), Some((11, 0)))]
+        );
+    }
+
+    #[test]
+    fn invalid_queries_are_rejected() {
+        let cases = [
+            ("> 0.5%", "usage statistics"),
+            (">= 1% in US", "usage statistics"),
+            ("not ie 11", "unsupported query; supported queries are"),
+            (
+                "maintained node versions",
+                "unsupported query; supported queries are",
+            ),
+            ("netscape >= 4", "unknown browser \"netscape\""),
+            ("safari >= fifteen", "\"fifteen\" is not a version number"),
+            ("safari == 15", "unsupported query; supported queries are"),
+            ("safari < 0", "no version is older than 0"),
+            ("last 0 versions", "\"0\" is not a positive number"),
+        ];
+
+        for (query, reason) in cases {
+            let Err(G3cssError::OtherError(message)) = parse_browser_query(query) else {
+                panic!("{} was accepted", query);
+            };
+
+            assert!(message.contains(reason), "{}: {}", query, message);
+        }
+    }
+}
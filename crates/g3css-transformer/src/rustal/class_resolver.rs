@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use g3css_common::{tables::g3css_properties::css_property_name, utils::node_utils::class_name};
+use g3css_parser::types::{
+    g3css_class::G3cssClass, g3css_elements::G3cssElements, g3css_error::G3cssError,
+    g3css_panoramic::G3cssPanoramic,
+};
+
+use crate::types::{
+    css_declaration::CssDeclaration,
+    css_origin::CssOrigin,
+    resolved_class::{ResolvedBlock, ResolvedClass},
+    scope_context::ScopeContext,
+};
+
+use super::{pseudo_handlers::pseudo_block, value_handlers::substitute_variables};
+
+/// Converts the elements of a block into CSS declarations.
+///
+/// # Arguments
+///
+/// - `context` - The context holding the aliases and variables of the scope.
+/// - `elements` - The elements of a properties or pseudo block.
+/// - `important` - Whether the declarations are marked with `!important`.
+/// - `origin` - The origin of the block, whose index is set to the position of each element,
+///   counted from `first_index`.
+/// - `first_index` - The position of the first element of the block.
+///
+/// # Returns
+///
+/// The CSS declarations, or a `G3cssError` if a nickname or variable cannot be resolved.
+pub fn resolve_elements(
+    context: &ScopeContext,
+    elements: &[G3cssElements],
+    important: bool,
+    origin: &CssOrigin,
+    first_index: usize,
+) -> Result<Vec<CssDeclaration>, G3cssError> {
+    let mut declarations = vec![];
+
+    for (position, element) in elements.iter().enumerate() {
+        // Regular properties carry their name, nicknames are looked up in the aliases.
+        let (property, value) = match (element.property(), element) {
+            (Some((property, value)), _) => (property, value),
+            (None, G3cssElements::Nickname(nickname)) => match nickname.as_slice() {
+                [key, value] => match context.aliases.get(key) {
+                    Some(property) => (property.as_str(), value.as_str()),
+                    None => {
+                        return Err(G3cssError::OtherError(format!(
+                            "Unknown alias \"{}\" in scope \"{}\"",
+                            key, context.name
+                        )));
+                    }
+                },
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        let css_property = css_property_name(property).ok_or_else(|| {
+            G3cssError::OtherError(format!(
+                "Alias in scope \"{}\" points to unknown property \"{}\"",
+                context.name, property
+            ))
+        })?;
+
+        declarations.push(CssDeclaration {
+            property: css_property.to_string(),
+            value: substitute_variables(context, value)?,
+            important,
+            origin: Some(match origin.clone() {
+                CssOrigin::Class {
+                    class_name,
+                    breakpoint,
+                    block,
+                    ..
+                } => CssOrigin::Class {
+                    class_name,
+                    breakpoint,
+                    block,
+                    index: Some(first_index + position),
+                },
+                theme => theme,
+            }),
+        });
+    }
+
+    Ok(declarations)
+}
+
+/// Appends declarations to the block matching the pseudo selector and breakpoint,
+/// creating the block if it does not exist yet.
+///
+/// # Arguments
+///
+/// - `blocks` - The blocks of the class being resolved.
+/// - `keyword` - The G3CSS keyword of the block.
+/// - `pseudo` - The selector suffix of the block.
+/// - `breakpoint` - The breakpoint wrapping the block, if any.
+/// - `declarations` - The declarations to append.
+fn merge_block(
+    blocks: &mut Vec<ResolvedBlock>,
+    keyword: &'static str,
+    pseudo: &'static str,
+    breakpoint: Option<&str>,
+    declarations: Vec<CssDeclaration>,
+) {
+    match blocks
+        .iter_mut()
+        .find(|block| block.pseudo == pseudo && block.breakpoint.as_deref() == breakpoint)
+    {
+        Some(block) => block.declarations.extend(declarations),
+        None => blocks.push(ResolvedBlock {
+            keyword,
+            pseudo,
+            breakpoint: breakpoint.map(str::to_string),
+            declarations,
+        }),
+    }
+}
+
+/// Checks whether a list of class nodes contains `!important: true`.
+fn is_important(nodes: &[G3cssClass]) -> bool {
+    nodes
+        .iter()
+        .any(|node| matches!(node, G3cssClass::Important(value) if value == "true"))
+}
+
+/// Resolves a class, following its `inherits` chain.
+///
+/// # Arguments
+///
+/// - `context` - The context of the scope holding the class.
+/// - `class` - The nodes of the class.
+/// - `stack` - The names of the classes currently being resolved, used to detect cycles.
+///
+/// # Returns
+///
+/// The `ResolvedClass`, with the parent's declarations placed before the class's own, or a
+/// `G3cssError` if a parent, nickname or variable cannot be resolved.
+fn resolve_class_with_stack(
+    context: &ScopeContext,
+    class: &[G3cssClass],
+    stack: &mut Vec<String>,
+) -> Result<ResolvedClass, G3cssError> {
+    let name = class_name(class).to_string();
+
+    // A class appearing twice in the stack inherits from itself.
+    if stack.contains(&name) {
+        return Err(G3cssError::OtherError(format!(
+            "Class \"{}\" inherits from itself through \"{}\"",
+            name,
+            stack.join("\" -> \"")
+        )));
+    }
+
+    stack.push(name.clone());
+
+    let mut blocks = vec![];
+
+    // Start from the parent's blocks so the class's own declarations override them.
+    if let Some(parent) = class.iter().find_map(|node| match node {
+        G3cssClass::Inherits(parent) => Some(parent),
+        _ => None,
+    }) {
+        let parent_class = context.inheritable.get(parent).ok_or_else(|| {
+            G3cssError::OtherError(format!(
+                "Class \"{}\" inherits unknown class \"{}\"",
+                name, parent
+            ))
+        })?;
+
+        blocks = resolve_class_with_stack(context, parent_class, stack)?.blocks;
+    }
+
+    let important = is_important(class);
+    // Counts the elements already written in every block, by breakpoint and keyword, so each
+    // declaration knows its position in the source.
+    let mut counters: HashMap<(Option<String>, &'static str), usize> = HashMap::new();
+
+    let mut resolve_block = |blocks: &mut Vec<ResolvedBlock>,
+                             node: &G3cssClass,
+                             breakpoint: Option<&str>,
+                             important: bool|
+     -> Result<(), G3cssError> {
+        if let Some((keyword, pseudo, elements)) = pseudo_block(node) {
+            let counter = counters
+                .entry((breakpoint.map(str::to_string), keyword))
+                .or_default();
+
+            let origin = CssOrigin::Class {
+                class_name: name.clone(),
+                breakpoint: breakpoint.map(str::to_string),
+                block: keyword,
+                index: None,
+            };
+
+            let declarations = resolve_elements(context, elements, important, &origin, *counter)?;
+            *counter += elements.len();
+
+            merge_block(blocks, keyword, pseudo, breakpoint, declarations);
+        }
+
+        Ok(())
+    };
+
+    for node in class {
+        // Properties and pseudo blocks are merged outside of any breakpoint.
+        resolve_block(&mut blocks, node, None, important)?;
+
+        // Every breakpoint holds its own set of properties and pseudo blocks.
+        if let G3cssClass::PanoramicViewer(breakpoints) = node {
+            for breakpoint in breakpoints {
+                let mut breakpoint_name = None;
+                let mut children: &[G3cssClass] = &[];
+
+                for panoramic in breakpoint {
+                    match panoramic {
+                        G3cssPanoramic::Breakpoint(value) => breakpoint_name = Some(value.as_str()),
+                        G3cssPanoramic::Children(value) => children = value,
+                    }
+                }
+
+                let breakpoint_important = important || is_important(children);
+
+                for child in children {
+                    resolve_block(&mut blocks, child, breakpoint_name, breakpoint_important)?;
+                }
+            }
+        }
+    }
+
+    stack.pop();
+
+    Ok(ResolvedClass { name, blocks })
+}
+
+/// Resolves a class of the scope into its final blocks of declarations.
+///
+/// # Arguments
+///
+/// - `context` - The context of the scope holding the class.
+/// - `class` - The nodes of the class.
+///
+/// # Returns
+///
+/// The `ResolvedClass`, or a `G3cssError` if the class cannot be resolved.
+pub fn resolve_class(
+    context: &ScopeContext,
+    class: &[G3cssClass],
+) -> Result<ResolvedClass, G3cssError> {
+    resolve_class_with_stack(context, class, &mut vec![])
+}
@@ -0,0 +1,140 @@
+use crate::types::{
+    css_mapping::CssMapping, css_rule::CssRule, css_stylesheet::CssStylesheet,
+    transformer_options::G3cssCssStyle,
+};
+
+use super::{css_minifier::write_minified_css, css_writer::CssWriter};
+
+/// Indentation used for each nesting level of the emitted CSS.
+const INDENT: &str = "    ";
+
+/// Writes a single rule, indented by the given level.
+///
+/// # Arguments
+///
+/// - `writer` - The writer receiving the CSS.
+/// - `rule` - The rule to write.
+/// - `level` - The nesting level of the rule.
+fn write_rule(writer: &mut CssWriter, rule: &CssRule, level: usize) {
+    let indent = INDENT.repeat(level);
+
+    writer.write(&indent);
+
+    // Every grouped selector is mapped back to the class it was generated from.
+    for (index, selector) in rule.selector.split(", ").enumerate() {
+        if index > 0 {
+            writer.write(", ");
+        }
+
+        writer.mark(rule.origins.get(index));
+        writer.write(selector);
+    }
+
+    writer.write(" {\n");
+
+    for declaration in &rule.declarations {
+        writer.write(&format!("{}{}", indent, INDENT));
+        writer.mark(declaration.origin.as_ref());
+        writer.write(&format!(
+            "{}: {}{};\n",
+            declaration.property,
+            declaration.value,
+            if declaration.important {
+                " !important"
+            } else {
+                ""
+            }
+        ));
+    }
+
+    writer.write(&format!("{}}}\n", indent));
+}
+
+/// Writes a stylesheet as indented CSS.
+///
+/// Consecutive rules sharing a media query are grouped into a single `@media` block.
+///
+/// # Arguments
+///
+/// - `writer` - The writer receiving the CSS.
+/// - `stylesheet` - The stylesheet to write.
+pub fn write_pretty_css(writer: &mut CssWriter, stylesheet: &CssStylesheet) {
+    let mut index = 0;
+
+    while index < stylesheet.rules.len() {
+        // Rules are separated by a blank line.
+        if index > 0 {
+            writer.write("\n");
+        }
+
+        let rule = &stylesheet.rules[index];
+
+        match &rule.media {
+            None => {
+                write_rule(writer, rule, 0);
+                index += 1;
+            }
+            Some(media) => {
+                writer.write(&format!("@media {} {{\n", media));
+
+                // Write every following rule of the same media query inside this block.
+                let mut first = true;
+
+                while let Some(inner) = stylesheet.rules.get(index) {
+                    if inner.media.as_ref() != Some(media) {
+                        break;
+                    }
+
+                    if !first {
+                        writer.write("\n");
+                    }
+
+                    write_rule(writer, inner, 1);
+                    first = false;
+                    index += 1;
+                }
+
+                writer.write("}\n");
+            }
+        }
+    }
+}
+
+/// Emits a stylesheet in the given style, along with the mappings of every selector and
+/// declaration back to the G3CSS construct it was generated from.
+///
+/// # Arguments
+///
+/// - `stylesheet` - The stylesheet to emit.
+/// - `style` - Whether to emit readable or minified CSS.
+///
+/// # Returns
+///
+/// The CSS source of the stylesheet and its mappings, in generated order.
+pub fn emit_css_with_mappings(
+    stylesheet: &CssStylesheet,
+    style: G3cssCssStyle,
+) -> (String, Vec<CssMapping>) {
+    let mut writer = CssWriter::new();
+
+    match style {
+        G3cssCssStyle::Pretty => write_pretty_css(&mut writer, stylesheet),
+        G3cssCssStyle::Minified => write_minified_css(&mut writer, stylesheet),
+    }
+
+    writer.finish()
+}
+
+/// Emits a stylesheet in the given style.
+///
+/// # Arguments
+///
+/// - `stylesheet` - The stylesheet to emit.
+/// - `style` - Whether to emit readable or minified CSS.
+///
+/// # Returns
+///
+/// The CSS source of the stylesheet.
+pub fn emit_css(stylesheet: &CssStylesheet, style: G3cssCssStyle) -> String {
+    emit_css_with_mappings(stylesheet, style).0
+}
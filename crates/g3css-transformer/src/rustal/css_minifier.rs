@@ -0,0 +1,392 @@
+use crate::types::{css_rule::CssRule, css_stylesheet::CssStylesheet};
+
+use super::css_writer::CssWriter;
+
+/// Length units that can be dropped from a zero value.
+///
+/// Percentages, times and angles are left alone, since `0%`, `0s` or `0deg` are not always
+/// interchangeable with a bare `0`.
+const LENGTH_UNITS: &[&str] = &[
+    "px", "em", "rem", "ex", "ch", "vw", "vh", "vmin", "vmax", "cm", "mm", "in", "pt", "pc", "q",
+];
+
+/// Properties taking a colour besides the `*-color` ones, in which `#` tokens are colours.
+///
+/// Elsewhere a `#` token may be an identifier (e.g. `nav-down: #menu`), which must be kept.
+const COLOR_PROPERTIES: &[&str] = &[
+    "background",
+    "background-image",
+    "backdrop-filter",
+    "border",
+    "border-bottom",
+    "border-image",
+    "border-image-source",
+    "border-left",
+    "border-right",
+    "border-top",
+    "box-shadow",
+    "column-rule",
+    "filter",
+    "outline",
+    "text-decoration",
+    "text-outline",
+    "text-shadow",
+];
+
+/// Checks whether the `#` tokens of a property's values are colours.
+fn is_color_property(property: &str) -> bool {
+    property.ends_with("color") || COLOR_PROPERTIES.contains(&property)
+}
+
+/// Shortens a hexadecimal colour whose digits come in identical pairs.
+///
+/// # Arguments
+///
+/// - `token` - A token of a value, starting with `#`.
+///
+/// # Returns
+///
+/// The lowercase short form (e.g. `#fff` for `#FFFFFF`), or the lowercase token if it
+/// cannot be shortened. Tokens that are not colours are returned unchanged.
+fn shorten_hex_color(token: &str) -> String {
+    let digits = &token[1..];
+
+    if !(digits.len() == 6 || digits.len() == 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return token.to_string();
+    }
+
+    let digits = digits.to_ascii_lowercase();
+    let bytes = digits.as_bytes();
+
+    if bytes.chunks(2).all(|pair| pair[0] == pair[1]) {
+        let short: String = bytes.chunks(2).map(|pair| pair[0] as char).collect();
+        format!("#{}", short)
+    } else {
+        format!("#{}", digits)
+    }
+}
+
+/// Drops the unit of a zero length (e.g. `0px` becomes `0`).
+///
+/// # Arguments
+///
+/// - `token` - A token of a value.
+///
+/// # Returns
+///
+/// `0` if the token is a zero length, or the token unchanged.
+fn drop_zero_unit(token: &str) -> String {
+    let number_length = token
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(token.len());
+    let (number, unit) = token.split_at(number_length);
+
+    let is_zero = !number.is_empty()
+        && number.chars().any(|c| c.is_ascii_digit())
+        && number.chars().all(|c| c == '0' || c == '.');
+
+    if is_zero && LENGTH_UNITS.contains(&unit.to_ascii_lowercase().as_str()) {
+        "0".to_string()
+    } else {
+        token.to_string()
+    }
+}
+
+/// CSS math functions, inside which a unitless zero is not a valid length.
+const MATH_FUNCTIONS: &[&str] = &["calc", "min", "max", "clamp"];
+
+/// Minifies a single unquoted token of a value.
+///
+/// # Arguments
+///
+/// - `token` - A token of a value.
+/// - `functions` - The names of the functions the token is nested in, innermost last.
+/// - `is_color` - Whether the `#` tokens of the value are colours.
+fn minify_token(token: &str, functions: &[String], is_color: bool) -> String {
+    // References such as `url(#ID)` must be kept verbatim.
+    if functions.iter().any(|function| function == "url") {
+        token.to_string()
+    } else if token.starts_with('#') && is_color {
+        shorten_hex_color(token)
+    } else if functions
+        .iter()
+        .any(|function| MATH_FUNCTIONS.contains(&function.as_str()))
+    {
+        token.to_string()
+    } else {
+        drop_zero_unit(token)
+    }
+}
+
+/// Minifies the unquoted part of a value.
+///
+/// Whitespace is collapsed, spaces around commas and parentheses are removed, and each token
+/// is shortened.
+fn minify_unquoted(segment: &str, is_color: bool, output: &mut String) {
+    let mut token = String::new();
+    let mut pending_space = false;
+    // Tracks the lowercase name of the function every open parenthesis belongs to.
+    let mut functions: Vec<String> = vec![];
+
+    for character in segment.chars() {
+        match character {
+            c if c.is_whitespace() => {
+                if !token.is_empty() {
+                    output.push_str(&minify_token(&token, &functions, is_color));
+                    token.clear();
+                }
+
+                pending_space = true;
+            }
+            ',' | '(' | ')' | '/' => {
+                // The token before an opening parenthesis is the function name.
+                if character == '(' {
+                    functions.push(token.to_ascii_lowercase());
+                    output.push_str(&token);
+                } else if !token.is_empty() {
+                    output.push_str(&minify_token(&token, &functions, is_color));
+                }
+
+                if character == ')' {
+                    functions.pop();
+                }
+
+                token.clear();
+                output.push(character);
+                pending_space = false;
+            }
+            _ => {
+                // Only keep a space between two tokens, never after a separator.
+                if pending_space
+                    && token.is_empty()
+                    && !output.is_empty()
+                    && !output.ends_with([',', '(', '/'])
+                {
+                    output.push(' ');
+                }
+
+                pending_space = false;
+                token.push(character);
+            }
+        }
+    }
+
+    if !token.is_empty() {
+        output.push_str(&minify_token(&token, &functions, is_color));
+    }
+
+    if pending_space && !output.is_empty() && !output.ends_with([',', '(', ')', '/']) {
+        output.push(' ');
+    }
+}
+
+/// Minifies a declaration value, leaving quoted strings untouched.
+///
+/// `content` values are kept as written, since their unquoted parts (counters, keywords,
+/// `attr()`) are meaningful text. Hexadecimal tokens are only shortened in the values of
+/// properties taking colours.
+///
+/// # Arguments
+///
+/// - `property` - The CSS property of the declaration.
+/// - `value` - The value of the declaration.
+///
+/// # Returns
+///
+/// The minified value.
+pub fn minify_value(property: &str, value: &str) -> String {
+    if property == "content" {
+        return value.to_string();
+    }
+
+    let is_color = is_color_property(property);
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+
+    // Alternate between unquoted segments and quoted strings.
+    while let Some(start) = rest.find(['"', '\'']) {
+        let quote = rest[start..].chars().next().unwrap_or('"');
+        let end = rest[start + 1..]
+            .find(quote)
+            .map_or(rest.len(), |end| start + end + 2);
+
+        minify_unquoted(&rest[..start], is_color, &mut output);
+        output.push_str(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    minify_unquoted(rest, is_color, &mut output);
+
+    output.trim_end().to_string()
+}
+
+/// Minifies a media query condition (e.g. `(max-width: 640px)` becomes `(max-width:640px)`).
+fn minify_media(media: &str) -> String {
+    media
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(": ", ":")
+}
+
+/// Writes a rule without any whitespace or final semicolon.
+fn write_minified_rule(writer: &mut CssWriter, rule: &CssRule) {
+    // Every grouped selector is mapped back to the class it was generated from.
+    for (index, selector) in rule.selector.split(',').enumerate() {
+        if index > 0 {
+            writer.write(",");
+        }
+
+        writer.mark(rule.origins.get(index));
+        writer.write(selector.trim());
+    }
+
+    writer.write("{");
+
+    for (index, declaration) in rule.declarations.iter().enumerate() {
+        if index > 0 {
+            writer.write(";");
+        }
+
+        writer.mark(declaration.origin.as_ref());
+        writer.write(&format!(
+            "{}:{}{}",
+            declaration.property,
+            minify_value(&declaration.property, &declaration.value),
+            if declaration.important {
+                "!important"
+            } else {
+                ""
+            }
+        ));
+    }
+
+    writer.write("}");
+}
+
+/// Writes a stylesheet as minified CSS.
+///
+/// Whitespace is stripped, colours and zero lengths are shortened, and the final semicolon
+/// of every rule is removed. Consecutive rules sharing a media query are grouped into a
+/// single `@media` block.
+///
+/// # Arguments
+///
+/// - `writer` - The writer receiving the CSS.
+/// - `stylesheet` - The stylesheet to write.
+pub fn write_minified_css(writer: &mut CssWriter, stylesheet: &CssStylesheet) {
+    let mut index = 0;
+
+    while index < stylesheet.rules.len() {
+        let rule = &stylesheet.rules[index];
+
+        match &rule.media {
+            None => {
+                write_minified_rule(writer, rule);
+                index += 1;
+            }
+            Some(media) => {
+                writer.write(&format!("@media {}{{", minify_media(media)));
+
+                while let Some(inner) = stylesheet.rules.get(index) {
+                    if inner.media.as_ref() != Some(media) {
+                        break;
+                    }
+
+                    write_minified_rule(writer, inner);
+                    index += 1;
+                }
+
+                writer.write("}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_lengths_lose_their_unit() {
+        let cases = [
+            ("margin", "0px", "0"),
+            ("margin", "0.0em 10px 0REM", "0 10px 0"),
+            ("padding", "00px", "0"),
+            ("transition-delay", "0s", "0s"),
+            ("width", "0%", "0%"),
+            ("transform", "rotate(0deg)", "rotate(0deg)"),
+            ("margin", "10px", "10px"),
+        ];
+
+        for (property, value, minified) in cases {
+            assert_eq!(minify_value(property, value), minified, "{}", value);
+        }
+    }
+
+    #[test]
+    fn hex_colors_are_shortened_in_color_positions() {
+        let cases = [
+            ("color", "#FFFFFF", "#fff"),
+            ("background-color", "#aabbccdd", "#abcd"),
+            ("border", "1px solid #FF0000", "1px solid #f00"),
+            (
+                "box-shadow",
+                "0 0 4px #112233 , inset 0 0 #ABCDEF",
+                "0 0 4px #123,inset 0 0 #abcdef",
+            ),
+            ("color", "#abc", "#abc"),
+            ("color", "#aabbc", "#aabbc"),
+        ];
+
+        for (property, value, minified) in cases {
+            assert_eq!(minify_value(property, value), minified, "{}", value);
+        }
+    }
+
+    #[test]
+    fn hash_tokens_of_other_properties_are_kept() {
+        assert_eq!(minify_value("nav-down", "#AABBCC"), "#AABBCC");
+        assert_eq!(minify_value("grid-area", "#112233"), "#112233");
+    }
+
+    #[test]
+    fn urls_and_math_functions_pass_through() {
+        let cases = [
+            ("background", "url(#FFFFFF) #FFFFFF", "url(#FFFFFF) #fff"),
+            ("background-image", "url( img/0px.png )", "url(img/0px.png)"),
+            ("width", "calc(100% - 0px)", "calc(100% - 0px)"),
+            (
+                "margin",
+                "clamp(0px, 2vw, 0rem) 0px",
+                "clamp(0px,2vw,0rem) 0",
+            ),
+        ];
+
+        for (property, value, minified) in cases {
+            assert_eq!(minify_value(property, value), minified, "{}", value);
+        }
+    }
+
+    #[test]
+    fn quoted_strings_are_kept() {
+        assert_eq!(
+            minify_value("font-family", "'0px  Sans' , serif"),
+            "'0px  Sans',serif"
+        );
+    }
+
+    #[test]
+    fn content_is_kept_as_written() {
+        let cases = [
+            "counter(item)  \".\"",
+            "open-quote",
+            "attr(data-label) ' : 0px'",
+            "\"#FFFFFF\" #FFFFFF",
+        ];
+
+        for value in cases {
+            assert_eq!(minify_value("content", value), value);
+        }
+    }
+}
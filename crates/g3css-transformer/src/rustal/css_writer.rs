@@ -0,0 +1,49 @@
+use crate::types::{css_mapping::CssMapping, css_origin::CssOrigin};
+
+/// Accumulates generated CSS while tracking the position of the text written so far.
+///
+/// Columns are counted in UTF-16 code units, as expected by Source Map v3 consumers.
+#[derive(Debug, Default)]
+pub struct CssWriter {
+    output: String,
+    line: usize,
+    column: usize,
+    mappings: Vec<CssMapping>,
+}
+
+impl CssWriter {
+    /// Creates an empty writer positioned at the start of the first line.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends text to the generated CSS.
+    pub fn write(&mut self, text: &str) {
+        for character in text.chars() {
+            if character == '\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                self.column += character.len_utf16();
+            }
+        }
+
+        self.output.push_str(text);
+    }
+
+    /// Records that the next text written was generated from the given origin.
+    pub fn mark(&mut self, origin: Option<&CssOrigin>) {
+        if let Some(origin) = origin {
+            self.mappings.push(CssMapping {
+                line: self.line,
+                column: self.column,
+                origin: origin.clone(),
+            });
+        }
+    }
+
+    /// Returns the generated CSS and the mappings recorded while writing it.
+    pub fn finish(self) -> (String, Vec<CssMapping>) {
+        (self.output, self.mappings)
+    }
+}
@@ -0,0 +1,361 @@
+use g3css_common::tables::css_shorthands::{properties_overlap, CSS_WIDE_KEYWORDS};
+
+use crate::types::{css_declaration::CssDeclaration, css_rule::CssRule};
+
+use super::rule_handlers::effective_declarations;
+
+/// Shorthands that can be rebuilt from their top, right, bottom and left longhands.
+const BOX_SHORTHANDS: &[(&str, [&str; 4])] = &[
+    (
+        "margin",
+        ["margin-top", "margin-right", "margin-bottom", "margin-left"],
+    ),
+    (
+        "padding",
+        [
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+    ),
+    (
+        "border-width",
+        [
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+    ),
+    (
+        "border-style",
+        [
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+    ),
+    (
+        "border-color",
+        [
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+    ),
+];
+
+/// Builds the shortest shorthand value from the top, right, bottom and left values.
+///
+/// # Arguments
+///
+/// - `values` - The values of the longhands, in top, right, bottom, left order.
+///
+/// # Returns
+///
+/// The shorthand value, omitting every side that CSS can infer from the others.
+fn shorthand_value([top, right, bottom, left]: [&str; 4]) -> String {
+    if right != left {
+        format!("{} {} {} {}", top, right, bottom, left)
+    } else if top != bottom {
+        format!("{} {} {}", top, right, bottom)
+    } else if top != right {
+        format!("{} {}", top, right)
+    } else {
+        top.to_string()
+    }
+}
+
+/// Replaces complete sets of box longhands with their shorthand.
+///
+/// A set is only collapsed when all four longhands are present with the same importance,
+/// which the shorthand keeps, each value is a single component other than a CSS-wide keyword
+/// (which a shorthand cannot mix with other values), and no other declaration of the rule
+/// sets any of the longhands, since the relative order between it and the longhands would
+/// otherwise matter.
+///
+/// # Arguments
+///
+/// - `declarations` - The effective declarations of a rule.
+///
+/// # Returns
+///
+/// The declarations with every safe set of longhands collapsed, placed where the last
+/// longhand of the set was declared.
+pub fn collapse_shorthands(mut declarations: Vec<CssDeclaration>) -> Vec<CssDeclaration> {
+    for (shorthand, longhands) in BOX_SHORTHANDS {
+        if declarations.iter().any(|declaration| {
+            !longhands.contains(&declaration.property.as_str())
+                && properties_overlap(&declaration.property, shorthand)
+        }) {
+            continue;
+        }
+
+        // Locate every longhand of the set.
+        let positions = longhands.map(|longhand| {
+            declarations
+                .iter()
+                .position(|declaration| declaration.property == longhand)
+        });
+
+        let [Some(top), Some(right), Some(bottom), Some(left)] = positions else {
+            continue;
+        };
+
+        let sides = [top, right, bottom, left];
+        let important = declarations[top].important;
+
+        let is_safe = sides.iter().all(|&index| {
+            let declaration = &declarations[index];
+
+            declaration.important == important
+                && !declaration.value.is_empty()
+                && !declaration.value.contains(char::is_whitespace)
+                && !declaration.value.contains([',', '!'])
+                && !CSS_WIDE_KEYWORDS
+                    .iter()
+                    .any(|keyword| declaration.value.eq_ignore_ascii_case(keyword))
+        });
+
+        if !is_safe {
+            continue;
+        }
+
+        // Put the shorthand where the last longhand was and drop the other longhands.
+        let last = sides.iter().copied().max().unwrap_or(top);
+
+        declarations[last] = CssDeclaration {
+            property: shorthand.to_string(),
+            value: shorthand_value(sides.map(|index| declarations[index].value.as_str())),
+            important,
+            origin: declarations[last].origin.clone(),
+        };
+
+        let mut index = 0;
+
+        declarations.retain(|_| {
+            let keep = index == last || !sides.contains(&index);
+            index += 1;
+            keep
+        });
+    }
+
+    declarations
+}
+
+/// Checks whether two rules have the same body, regardless of where it was declared.
+fn same_body(first: &[CssDeclaration], second: &[CssDeclaration]) -> bool {
+    first.len() == second.len()
+        && first.iter().zip(second).all(|(declaration, other)| {
+            declaration.property == other.property
+                && declaration.value == other.value
+                && declaration.important == other.important
+        })
+}
+
+/// Checks whether two rules set at least one common property, a shorthand setting the same
+/// properties as its longhands.
+fn shares_property(first: &[CssDeclaration], second: &[CssDeclaration]) -> bool {
+    first.iter().any(|declaration| {
+        second
+            .iter()
+            .any(|other| properties_overlap(&other.property, &declaration.property))
+    })
+}
+
+/// Merges rules with identical media queries and declarations into grouped selectors.
+///
+/// A later rule is only merged into an earlier one when no rule between them sets any of the
+/// same properties, directly or through a shorthand, so moving it earlier cannot change the
+/// cascade.
+///
+/// # Arguments
+///
+/// - `rules` - The ordered rules of a stylesheet.
+///
+/// # Returns
+///
+/// The rules with identical bodies merged into the first rule holding them.
+pub fn merge_identical_rules(rules: Vec<CssRule>) -> Vec<CssRule> {
+    let mut merged: Vec<CssRule> = vec![];
+
+    for rule in rules {
+        // Look for the closest earlier rule with the same body.
+        let target = merged.iter().rposition(|candidate| {
+            candidate.media == rule.media && same_body(&candidate.declarations, &rule.declarations)
+        });
+
+        let Some(target) = target else {
+            merged.push(rule);
+            continue;
+        };
+
+        let is_safe = merged[target + 1..]
+            .iter()
+            .all(|between| !shares_property(&between.declarations, &rule.declarations));
+
+        if !is_safe {
+            merged.push(rule);
+            continue;
+        }
+
+        let candidate = &mut merged[target];
+
+        candidate.selector = format!("{}, {}", candidate.selector, rule.selector);
+        candidate.origins.extend(rule.origins);
+
+        // A grouped rule only keeps its class name if every selector comes from that class.
+        if candidate.class_name != rule.class_name {
+            candidate.class_name = None;
+        }
+    }
+
+    merged
+}
+
+/// Optimizes the rules of a stylesheet.
+///
+/// Overridden declarations are removed first, then box longhands are collapsed into
+/// shorthands, and finally rules with identical bodies are merged.
+///
+/// # Arguments
+///
+/// - `rules` - The ordered rules of a stylesheet.
+///
+/// # Returns
+///
+/// The optimized rules.
+pub fn optimize_rules(rules: Vec<CssRule>) -> Vec<CssRule> {
+    let rules = rules
+        .into_iter()
+        .map(|rule| {
+            let declarations = effective_declarations(&rule.declarations)
+                .into_iter()
+                .cloned()
+                .collect();
+
+            CssRule {
+                declarations: collapse_shorthands(declarations),
+                ..rule
+            }
+        })
+        .collect();
+
+    merge_identical_rules(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds declarations from `(property, value, important)` triples.
+    fn declarations(entries: &[(&str, &str, bool)]) -> Vec<CssDeclaration> {
+        entries
+            .iter()
+            .map(|(property, value, important)| CssDeclaration {
+                property: property.to_string(),
+                value: value.to_string(),
+                important: *important,
+                origin: None,
+            })
+            .collect()
+    }
+
+    /// Builds a rule of a class, outside any media query.
+    fn rule(class_name: &str, entries: &[(&str, &str, bool)]) -> CssRule {
+        CssRule {
+            class_name: Some(class_name.to_string()),
+            selector: format!(".{}", class_name),
+            origins: vec![],
+            media: None,
+            declarations: declarations(entries),
+        }
+    }
+
+    #[test]
+    fn complete_sets_of_longhands_collapse_into_their_shorthand() {
+        let collapsed = collapse_shorthands(declarations(&[
+            ("margin-top", "1px", false),
+            ("color", "red", false),
+            ("margin-right", "2px", false),
+            ("margin-bottom", "1px", false),
+            ("margin-left", "2px", false),
+        ]));
+
+        assert_eq!(
+            collapsed,
+            declarations(&[("color", "red", false), ("margin", "1px 2px", false)])
+        );
+    }
+
+    #[test]
+    fn important_sets_collapse_and_keep_their_importance() {
+        let collapsed = collapse_shorthands(declarations(&[
+            ("padding-top", "0", true),
+            ("padding-right", "0", true),
+            ("padding-bottom", "0", true),
+            ("padding-left", "0", true),
+        ]));
+
+        assert_eq!(collapsed, declarations(&[("padding", "0", true)]));
+    }
+
+    #[test]
+    fn sets_mixing_importance_are_kept() {
+        let longhands = declarations(&[
+            ("padding-top", "0", true),
+            ("padding-right", "0", false),
+            ("padding-bottom", "0", true),
+            ("padding-left", "0", true),
+        ]);
+
+        assert_eq!(collapse_shorthands(longhands.clone()), longhands);
+    }
+
+    #[test]
+    fn multi_token_values_and_css_wide_keywords_are_kept() {
+        for value in ["calc(1px + 2px)", "inherit"] {
+            let longhands = declarations(&[
+                ("margin-top", value, false),
+                ("margin-right", "0", false),
+                ("margin-bottom", "0", false),
+                ("margin-left", "0", false),
+            ]);
+
+            assert_eq!(
+                collapse_shorthands(longhands.clone()),
+                longhands,
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn identical_rules_are_merged_into_the_first() {
+        let merged = merge_identical_rules(vec![
+            rule("a", &[("color", "red", false)]),
+            rule("b", &[("margin", "0", false)]),
+            rule("c", &[("color", "red", false)]),
+        ]);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].selector, ".a, .c");
+        assert_eq!(merged[0].class_name, None);
+        assert_eq!(merged[1].selector, ".b");
+    }
+
+    #[test]
+    fn identical_rules_are_not_merged_across_an_overlapping_rule() {
+        let rules = vec![
+            rule("a", &[("margin-top", "0", false)]),
+            rule("b", &[("margin", "4px", false)]),
+            rule("c", &[("margin-top", "0", false)]),
+        ];
+
+        assert_eq!(merge_identical_rules(rules.clone()), rules);
+    }
+}
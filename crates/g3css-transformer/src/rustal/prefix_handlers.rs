@@ -0,0 +1,106 @@
+use g3css_common::tables::browser_compat::{G3cssPrefixRule, G3CSS_PREFIX_RULES};
+
+use crate::types::{
+    browser_target::BrowserTarget, css_declaration::CssDeclaration, css_rule::CssRule,
+};
+
+/// Checks whether any targeted browser version still requires a prefix.
+fn needs_prefix(targets: &[BrowserTarget], rule: &G3cssPrefixRule) -> bool {
+    targets
+        .iter()
+        .any(|target| target.browser == rule.browser && target.min_version < rule.unprefixed_since)
+}
+
+/// Builds the prefixed declarations required before a declaration.
+///
+/// # Arguments
+///
+/// - `targets` - The browser versions being targeted.
+/// - `declaration` - The unprefixed declaration.
+///
+/// # Returns
+///
+/// The prefixed declarations, each prefix appearing once.
+fn prefixed_declarations(
+    targets: &[BrowserTarget],
+    declaration: &CssDeclaration,
+) -> Vec<CssDeclaration> {
+    let value = declaration.value.trim().to_lowercase();
+    let mut prefixed: Vec<CssDeclaration> = Vec::new();
+
+    for rule in G3CSS_PREFIX_RULES.iter().filter(|rule| {
+        rule.property == declaration.property
+            && rule.value.is_none_or(|rule_value| rule_value == value)
+            && needs_prefix(targets, rule)
+    }) {
+        // Prefixes go either on the property or, for value rules, on the value.
+        let (property, value) = match rule.value {
+            Some(_) => (
+                declaration.property.clone(),
+                format!("{}{}", rule.prefix, value),
+            ),
+            None => (
+                format!("{}{}", rule.prefix, declaration.property),
+                declaration.value.clone(),
+            ),
+        };
+
+        if !prefixed
+            .iter()
+            .any(|other| other.property == property && other.value == value)
+        {
+            prefixed.push(CssDeclaration {
+                property,
+                value,
+                important: declaration.important,
+                origin: declaration.origin.clone(),
+            });
+        }
+    }
+
+    prefixed
+}
+
+/// Adds the vendor prefixed declarations the targeted browsers need to every rule.
+///
+/// Prefixed declarations are inserted right before the declaration they were derived from,
+/// so browsers supporting the standard syntax keep using it. Declarations the rule already
+/// contains are not duplicated.
+///
+/// # Arguments
+///
+/// - `targets` - The browser versions being targeted.
+/// - `rules` - The rules to prefix.
+///
+/// # Returns
+///
+/// The rules with their prefixed declarations.
+pub fn prefix_rules(targets: &[BrowserTarget], rules: Vec<CssRule>) -> Vec<CssRule> {
+    if targets.is_empty() {
+        return rules;
+    }
+
+    rules
+        .into_iter()
+        .map(|mut rule| {
+            let mut declarations = Vec::with_capacity(rule.declarations.len());
+
+            for declaration in &rule.declarations {
+                for prefixed in prefixed_declarations(targets, declaration) {
+                    let declared = rule.declarations.iter().any(|other| {
+                        other.property == prefixed.property && other.value == prefixed.value
+                    });
+
+                    if !declared {
+                        declarations.push(prefixed);
+                    }
+                }
+
+                declarations.push(declaration.clone());
+            }
+
+            rule.declarations = declarations;
+            rule
+        })
+        .collect()
+}
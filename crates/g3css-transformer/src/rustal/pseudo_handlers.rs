@@ -0,0 +1,21 @@
+use g3css_common::tables::g3css_keywords::find_g3css_block_keyword;
+use g3css_parser::types::{g3css_class::G3cssClass, g3css_elements::G3cssElements};
+
+/// Retrieves the keyword, selector suffix and declarations of a properties or pseudo block.
+///
+/// # Arguments
+///
+/// - `node` - A node of a G3CSS class.
+///
+/// # Returns
+///
+/// The G3CSS keyword of the block, its selector suffix (empty for `properties`, e.g.
+/// `:hover` or `::before` otherwise) and its elements, or `None` if the node is not a block.
+pub fn pseudo_block(
+    node: &G3cssClass,
+) -> Option<(&'static str, &'static str, &Vec<G3cssElements>)> {
+    let (keyword, elements) = node.block()?;
+    let selector = find_g3css_block_keyword(keyword)?.selector;
+
+    Some((keyword, selector, elements))
+}
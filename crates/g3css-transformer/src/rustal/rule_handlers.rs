@@ -0,0 +1,173 @@
+use g3css_common::tables::css_shorthands::css_longhands;
+use g3css_parser::types::g3css_error::G3cssError;
+
+use crate::types::{
+    css_declaration::CssDeclaration,
+    css_origin::CssOrigin,
+    css_rule::CssRule,
+    scope_context::ScopeContext,
+    transformer_options::{G3cssThemeStrategy, G3cssTransformerOptions},
+};
+
+/// Retrieves the media query condition of a breakpoint.
+///
+/// # Arguments
+///
+/// - `options` - The transformer options holding the breakpoint definitions.
+/// - `breakpoint` - The name of the breakpoint, or `None` for blocks outside breakpoints.
+///
+/// # Returns
+///
+/// The media query condition, `None` for blocks outside breakpoints, or a `G3cssError` if
+/// the breakpoint is not defined.
+pub fn breakpoint_media(
+    options: &G3cssTransformerOptions,
+    breakpoint: Option<&str>,
+) -> Result<Option<String>, G3cssError> {
+    let Some(breakpoint) = breakpoint else {
+        return Ok(None);
+    };
+
+    options
+        .breakpoints
+        .iter()
+        .find(|(name, _)| name == breakpoint)
+        .map(|(_, media)| Some(media.clone()))
+        .ok_or_else(|| G3cssError::OtherError(format!("Unknown breakpoint \"{}\"", breakpoint)))
+}
+
+/// Builds the `:root` rules declaring the custom properties of the light and dark themes.
+///
+/// # Arguments
+///
+/// - `options` - The transformer options holding the theme strategy.
+/// - `context` - The context holding the theme variables of the scope.
+///
+/// # Returns
+///
+/// The theme rules, with the dark theme wrapped in a `prefers-color-scheme` media query or
+/// scoped to the `dark` class or `data-theme` attribute of the root element.
+pub fn build_theme_rules(
+    options: &G3cssTransformerOptions,
+    context: &ScopeContext,
+) -> Vec<CssRule> {
+    let custom_properties = |variables: &[(String, String)], theme: &'static str| {
+        variables
+            .iter()
+            .enumerate()
+            .map(|(index, (name, value))| CssDeclaration {
+                property: format!("--{}", name),
+                value: value.clone(),
+                important: false,
+                origin: Some(CssOrigin::Theme {
+                    theme,
+                    index: Some(index),
+                }),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut rules = vec![];
+
+    if !context.light_theme.is_empty() {
+        rules.push(CssRule {
+            class_name: None,
+            selector: ":root".to_string(),
+            origins: vec![CssOrigin::Theme {
+                theme: "light",
+                index: None,
+            }],
+            media: None,
+            declarations: custom_properties(&context.light_theme, "light"),
+        });
+    }
+
+    if !context.dark_theme.is_empty() {
+        let (selector, media) = match options.theme {
+            G3cssThemeStrategy::Media => (":root", Some("(prefers-color-scheme: dark)")),
+            G3cssThemeStrategy::Class => (":root.dark", None),
+            G3cssThemeStrategy::Attribute => (":root[data-theme=\"dark\"]", None),
+        };
+
+        rules.push(CssRule {
+            class_name: None,
+            selector: selector.to_string(),
+            origins: vec![CssOrigin::Theme {
+                theme: "dark",
+                index: None,
+            }],
+            media: media.map(str::to_string),
+            declarations: custom_properties(&context.dark_theme, "dark"),
+        });
+    }
+
+    rules
+}
+
+/// Orders rules so that media rules follow the rules they override.
+///
+/// Rules outside media queries come first, followed by the rules of every breakpoint in the
+/// order the breakpoints are defined. Any other media rule, such as the dark theme, keeps its
+/// place at the end. The relative order of rules inside each group is preserved.
+///
+/// # Arguments
+///
+/// - `options` - The transformer options holding the breakpoint definitions.
+/// - `rules` - The rules to order.
+///
+/// # Returns
+///
+/// The ordered rules.
+pub fn order_rules(options: &G3cssTransformerOptions, mut rules: Vec<CssRule>) -> Vec<CssRule> {
+    rules.sort_by_key(|rule| match &rule.media {
+        None => 0,
+        Some(media) => options
+            .breakpoints
+            .iter()
+            .position(|(_, condition)| condition == media)
+            .map_or(usize::MAX, |position| position + 1),
+    });
+
+    rules
+}
+
+/// Removes the declarations overridden by a later declaration of the same property, or by a
+/// later shorthand setting every longhand they set (e.g. `margin-top` followed by `margin`).
+///
+/// An `!important` declaration is only overridden by another `!important` one.
+///
+/// # Arguments
+///
+/// - `declarations` - The declarations of a single block, in cascade order.
+///
+/// # Returns
+///
+/// The declarations that take effect, in the order the browser would apply them.
+pub fn effective_declarations(declarations: &[CssDeclaration]) -> Vec<&CssDeclaration> {
+    let mut effective: Vec<&CssDeclaration> = vec![];
+
+    for declaration in declarations {
+        // A normal declaration cannot override an important one.
+        let is_overridden = effective.iter().any(|current| {
+            current.property == declaration.property && current.important && !declaration.important
+        });
+
+        if is_overridden {
+            continue;
+        }
+
+        // The later declaration wins over every one it entirely sets, and moves to the end of
+        // the cascade.
+        let longhands = css_longhands(&declaration.property);
+
+        effective.retain(|current| {
+            (current.important && !declaration.important)
+                || !css_longhands(&current.property)
+                    .iter()
+                    .all(|longhand| longhands.contains(longhand))
+        });
+        effective.push(declaration);
+    }
+
+    effective
+}
@@ -0,0 +1,135 @@
+use g3css_common::utils::node_utils::{class_name, scope_classes, scope_parts};
+use g3css_parser::types::{
+    g3css_alias::G3cssAlias, g3css_children::G3cssChildren, g3css_error::G3cssError,
+    g3css_node::G3cssNode, g3css_theme::G3cssTheme, g3css_variable::G3cssVariable,
+};
+
+use crate::types::scope_context::ScopeContext;
+
+/// Converts alias or variable string vectors into key/value pairs.
+///
+/// # Arguments
+///
+/// - `values` - The `[key, value]` vectors built by the parser.
+///
+/// # Returns
+///
+/// The key/value pairs of every well-formed vector.
+fn key_value_pairs<'a>(values: impl Iterator<Item = &'a Vec<String>>) -> Vec<(String, String)> {
+    values
+        .filter_map(|value| match value.as_slice() {
+            [key, value] => Some((key.clone(), value.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects the variables of a theme.
+///
+/// # Arguments
+///
+/// - `themes` - The theme nodes of a `light theme` or `dark theme` block.
+///
+/// # Returns
+///
+/// The variables declared by the theme, in source order.
+fn theme_variables(themes: &[G3cssTheme]) -> Vec<(String, String)> {
+    key_value_pairs(
+        themes
+            .iter()
+            .flat_map(|G3cssTheme::Variables(variables)| variables.iter())
+            .map(|G3cssVariable::Variable(variable)| variable),
+    )
+}
+
+/// Adds the aliases, variables, themes and classes of a scope's children to the context.
+///
+/// # Arguments
+///
+/// - `context` - The context being built.
+/// - `children` - The children of the scope.
+/// - `own` - Whether the classes belong to the scope being transformed.
+fn collect_scope<'a>(context: &mut ScopeContext<'a>, children: &'a [G3cssChildren], own: bool) {
+    for child in children {
+        match child {
+            // Nicknames map to G3CSS property names.
+            G3cssChildren::Aliases(aliases) => {
+                context.aliases.extend(key_value_pairs(
+                    aliases.iter().map(|G3cssAlias::Alias(alias)| alias),
+                ));
+            }
+            // Plain variables are substituted by value.
+            G3cssChildren::Variables(variables) => {
+                context.variables.extend(key_value_pairs(
+                    variables
+                        .iter()
+                        .map(|G3cssVariable::Variable(variable)| variable),
+                ));
+            }
+            // Theme variables become CSS custom properties.
+            G3cssChildren::LightTheme(themes) => {
+                context.light_theme.extend(theme_variables(themes))
+            }
+            G3cssChildren::DarkTheme(themes) => context.dark_theme.extend(theme_variables(themes)),
+            _ => (),
+        }
+    }
+
+    // Register the classes so they can be inherited, and emitted if they are our own.
+    for class in scope_classes(children) {
+        context
+            .inheritable
+            .insert(class_name(class).to_string(), class);
+
+        if own {
+            context.classes.push(class);
+        }
+    }
+}
+
+/// Builds the transformation context of a scope.
+///
+/// Aliases, variables and classes of the extended global are registered first, so the
+/// scope's own declarations take precedence over them.
+///
+/// # Arguments
+///
+/// - `ast` - The global or component being transformed.
+/// - `global` - The global extended by `ast`, if any.
+///
+/// # Returns
+///
+/// The `ScopeContext` of `ast`, or a `G3cssError` if `ast` is not a scope, or if it extends
+/// a global other than `global`.
+pub fn build_scope_context<'a>(
+    ast: &'a G3cssNode,
+    global: Option<&'a G3cssNode>,
+) -> Result<ScopeContext<'a>, G3cssError> {
+    let (name, extends, children) = scope_parts(ast).ok_or_else(|| {
+        G3cssError::OtherError("Only global and component scopes can be transformed".to_string())
+    })?;
+
+    let mut context = ScopeContext {
+        name: name.to_string(),
+        ..Default::default()
+    };
+
+    // Register the extended global before the scope itself.
+    if let Some(extends) = extends {
+        match global.and_then(scope_parts) {
+            Some((global_name, _, global_children)) if global_name == extends => {
+                collect_scope(&mut context, global_children, false);
+            }
+            _ => {
+                return Err(G3cssError::OtherError(format!(
+                    "Component \"{}\" extends unknown global \"{}\"",
+                    name, extends
+                )));
+            }
+        }
+    }
+
+    collect_scope(&mut context, children, true);
+
+    Ok(context)
+}
@@ -0,0 +1,412 @@
+use g3css_parser::types::{
+    g3css_span::G3cssSpan,
+    g3css_symbol::{G3cssSymbol, G3cssSymbolKind},
+};
+
+use crate::types::{
+    css_mapping::CssMapping, css_origin::CssOrigin, source_map_source::SourceMapSource,
+};
+
+/// Characters used by the base 64 VLQ encoding of Source Map v3.
+const BASE64_DIGITS: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Appends a value to the mappings string using base 64 VLQ encoding.
+fn encode_vlq(output: &mut String, value: i64) {
+    // The sign is stored in the least significant bit.
+    let mut vlq = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+
+    loop {
+        let mut digit = vlq & 0b11111;
+        vlq >>= 5;
+
+        if vlq > 0 {
+            digit |= 0b100000;
+        }
+
+        output.push(BASE64_DIGITS[digit as usize] as char);
+
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+/// Escapes a string so it can be written as a JSON string literal.
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+
+    escaped.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// Retrieves the indexes of the symbols of a kind whose parent is one of the given symbols.
+fn children_of(
+    symbols: &[G3cssSymbol],
+    parents: &[usize],
+    kinds: &[G3cssSymbolKind],
+) -> Vec<usize> {
+    symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, symbol)| {
+            kinds.contains(&symbol.kind)
+                && symbol
+                    .parent
+                    .is_some_and(|parent| parents.contains(&parent))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Finds the span of the source construct an origin points to.
+///
+/// The most precise construct found is returned: the declaration, then its block, then its
+/// breakpoint, and finally the class or theme itself.
+///
+/// # Arguments
+///
+/// - `symbols` - The symbols of a source file.
+/// - `origin` - The origin to look for.
+///
+/// # Returns
+///
+/// The span of the construct, or `None` if the file does not declare the class or theme.
+pub fn find_origin_span(symbols: &[G3cssSymbol], origin: &CssOrigin) -> Option<G3cssSpan> {
+    match origin {
+        CssOrigin::Class {
+            class_name,
+            breakpoint,
+            block,
+            index,
+        } => {
+            let class = symbols.iter().position(|symbol| {
+                symbol.kind == G3cssSymbolKind::Class && symbol.name == *class_name
+            })?;
+
+            // Blocks are either direct children of the class, or of one of its breakpoints.
+            let containers = match breakpoint {
+                None => vec![class],
+                Some(breakpoint) => {
+                    let viewers =
+                        children_of(symbols, &[class], &[G3cssSymbolKind::PanoramicViewer]);
+
+                    children_of(symbols, &viewers, &[G3cssSymbolKind::Breakpoint])
+                        .into_iter()
+                        .filter(|&index| symbols[index].name == *breakpoint)
+                        .collect()
+                }
+            };
+
+            let blocks: Vec<usize> = children_of(symbols, &containers, &[G3cssSymbolKind::Block])
+                .into_iter()
+                .filter(|&index| symbols[index].name == *block)
+                .collect();
+
+            let declaration = index.and_then(|index| {
+                children_of(
+                    symbols,
+                    &blocks,
+                    &[G3cssSymbolKind::Property, G3cssSymbolKind::Nickname],
+                )
+                .get(index)
+                .copied()
+            });
+
+            let target = declaration
+                .or(blocks.first().copied())
+                .or(containers.first().copied())
+                .unwrap_or(class);
+
+            Some(symbols[target].span)
+        }
+        CssOrigin::Theme { theme, index } => {
+            let theme = symbols.iter().position(|symbol| {
+                symbol.kind == G3cssSymbolKind::Theme && symbol.name == *theme
+            })?;
+
+            let variable = index.and_then(|index| {
+                children_of(symbols, &[theme], &[G3cssSymbolKind::Variable])
+                    .get(index)
+                    .copied()
+            });
+
+            Some(symbols[variable.unwrap_or(theme)].span)
+        }
+    }
+}
+
+/// Converts a span into the 0-based line and UTF-16 column expected by source maps.
+fn source_position(content: &str, span: &G3cssSpan) -> (i64, i64) {
+    let line_start = content[..span.start]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let column = content[line_start..span.start].encode_utf16().count();
+
+    (span.line as i64 - 1, column as i64)
+}
+
+/// Builds a Source Map v3 document mapping generated CSS back to its G3CSS sources.
+///
+/// Every mapping is looked up in the sources in order, so a component should be listed
+/// before the global it extends: declarations inherited from a global class then map back
+/// to the global file.
+///
+/// # Arguments
+///
+/// - `file` - Name of the generated CSS file.
+/// - `sources` - The G3CSS files the CSS was generated from.
+/// - `mappings` - The mappings returned by `emit_css_with_mappings`.
+///
+/// # Returns
+///
+/// The JSON source of the source map.
+pub fn build_source_map(
+    file: &str,
+    sources: &[SourceMapSource],
+    mappings: &[CssMapping],
+) -> String {
+    let mut encoded = String::new();
+    // Source map fields are relative to the previous segment.
+    let mut generated_line = 0;
+    let mut previous_column = 0;
+    let mut previous_source = 0;
+    let mut previous_line = 0;
+    let mut previous_source_column = 0;
+    let mut first_in_line = true;
+
+    for mapping in mappings {
+        let Some((source, span)) = sources.iter().enumerate().find_map(|(index, source)| {
+            find_origin_span(source.symbols, &mapping.origin).map(|span| (index, span))
+        }) else {
+            continue;
+        };
+
+        // Lines are separated by semicolons, and the column restarts on every line.
+        while generated_line < mapping.line {
+            encoded.push(';');
+            generated_line += 1;
+            previous_column = 0;
+            first_in_line = true;
+        }
+
+        if !first_in_line {
+            encoded.push(',');
+        }
+
+        let (line, column) = source_position(sources[source].content, &span);
+
+        encode_vlq(&mut encoded, mapping.column as i64 - previous_column);
+        encode_vlq(&mut encoded, source as i64 - previous_source);
+        encode_vlq(&mut encoded, line - previous_line);
+        encode_vlq(&mut encoded, column - previous_source_column);
+
+        previous_column = mapping.column as i64;
+        previous_source = source as i64;
+        previous_line = line;
+        previous_source_column = column;
+        first_in_line = false;
+    }
+
+    let paths: Vec<String> = sources
+        .iter()
+        .map(|source| escape_json(source.path))
+        .collect();
+    let contents: Vec<String> = sources
+        .iter()
+        .map(|source| escape_json(source.content))
+        .collect();
+
+    format!(
+        "{{\"version\":3,\"file\":{},\"sources\":[{}],\"sourcesContent\":[{}],\"names\":[],\"mappings\":{}}}",
+        escape_json(file),
+        paths.join(","),
+        contents.join(","),
+        escape_json(&encoded)
+    )
+}
+
+/// Builds the comment linking generated CSS to its source map.
+///
+/// # Arguments
+///
+/// - `map_file` - Path of the `.css.map` file, relative to the CSS file.
+///
+/// # Returns
+///
+/// The `sourceMappingURL` comment to append to the CSS.
+pub fn source_mapping_url(map_file: &str) -> String {
+    format!("/*# sourceMappingURL={} */", map_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use g3css_parser::g3css_parse_with_symbols;
+
+    use crate::{
+        g3css_transformer,
+        rustal::css_emitter::emit_css_with_mappings,
+        types::transformer_options::{G3cssCssStyle, G3cssTransformerOptions},
+    };
+
+    use super::*;
+
+    const SOURCE: &str = r#"define global scope "APP" {
+    define class "CARD" {
+        properties: {
+            padding: "4px",
+            color: "red",
+        },
+        hover: {
+            opacity: "0.5",
+        },
+    },
+}"#;
+
+    /// Decodes a base 64 VLQ mappings string into the absolute `[generated column, source,
+    /// source line, source column]` segments of every generated line.
+    fn decode_mappings(mappings: &str) -> Vec<Vec<[i64; 4]>> {
+        let mut fields = [0i64; 4];
+        let mut lines = vec![];
+
+        for line in mappings.split(';') {
+            let mut segments = vec![];
+            // The generated column restarts on every line, the other fields do not.
+            fields[0] = 0;
+
+            for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+                let (mut field, mut value, mut shift) = (0, 0i64, 0);
+
+                for digit in segment.bytes() {
+                    let digit = BASE64_DIGITS.iter().position(|&c| c == digit).unwrap() as i64;
+                    value |= (digit & 0b11111) << shift;
+                    shift += 5;
+
+                    if digit & 0b100000 == 0 {
+                        let delta = if value & 1 == 1 {
+                            -(value >> 1)
+                        } else {
+                            value >> 1
+                        };
+                        fields[field] += delta;
+                        (field, value, shift) = (field + 1, 0, 0);
+                    }
+                }
+
+                segments.push(fields);
+            }
+
+            lines.push(segments);
+        }
+
+        lines
+    }
+
+    /// Emits the source map of `SOURCE`, along with the generated CSS.
+    fn source_map(style: G3cssCssStyle) -> (String, String) {
+        let (ast, symbols) = g3css_parse_with_symbols(SOURCE).unwrap();
+        let options = G3cssTransformerOptions {
+            css_style: style,
+            ..Default::default()
+        };
+        let stylesheet = g3css_transformer(&ast, None, &options).unwrap();
+        let (css, mappings) = emit_css_with_mappings(&stylesheet, style);
+        let sources = [SourceMapSource {
+            path: "app.g3css",
+            content: SOURCE,
+            symbols: &symbols,
+        }];
+
+        (css, build_source_map("app.css", &sources, &mappings))
+    }
+
+    /// Reads the mappings string of a source map.
+    fn mappings_of(map: &str) -> &str {
+        let start = map.find("\"mappings\":\"").unwrap() + "\"mappings\":\"".len();
+        &map[start..start + map[start..].find('"').unwrap()]
+    }
+
+    #[test]
+    fn every_segment_points_at_the_construct_that_generated_it() {
+        for style in [G3cssCssStyle::Pretty, G3cssCssStyle::Minified] {
+            let (css, map) = source_map(style);
+            let css_lines: Vec<&str> = css.lines().collect();
+            let source_lines: Vec<&str> = SOURCE.lines().collect();
+            let mut pairs = vec![];
+
+            for (line, segments) in decode_mappings(mappings_of(&map)).iter().enumerate() {
+                for [column, source, source_line, source_column] in segments {
+                    assert_eq!(*source, 0);
+
+                    let generated = &css_lines[line][*column as usize..];
+                    let written = &source_lines[*source_line as usize][*source_column as usize..];
+                    pairs.push((generated.to_string(), written.to_string()));
+                }
+            }
+
+            // Every CSS declaration starts where its G3CSS declaration does.
+            for (property, value) in [("padding", "4px"), ("color", "red"), ("opacity", "0.5")] {
+                assert!(
+                    pairs.iter().any(|(generated, written)| {
+                        generated.starts_with(property)
+                            && written.starts_with(&format!("{}: \"{}\"", property, value))
+                    }),
+                    "{:?}: {} is not mapped",
+                    style,
+                    property
+                );
+            }
+
+            // Selectors point at their block.
+            assert!(pairs.iter().any(|(generated, written)| {
+                generated.starts_with(".CARD:hover") && written.starts_with("hover: {")
+            }));
+        }
+    }
+
+    #[test]
+    fn the_map_embeds_its_sources() {
+        let (_, map) = source_map(G3cssCssStyle::Pretty);
+
+        assert!(map.starts_with("{\"version\":3,\"file\":\"app.css\""));
+        assert!(map.contains("\"sources\":[\"app.g3css\"]"));
+        assert!(map.contains(&escape_json(SOURCE)));
+    }
+
+    #[test]
+    fn vlq_values_are_encoded_with_their_sign() {
+        let cases = [
+            (0, "A"),
+            (1, "C"),
+            (-1, "D"),
+            (15, "e"),
+            (16, "gB"),
+            (-1000, "x+B"),
+        ];
+
+        for (value, encoded) in cases {
+            let mut output = String::new();
+            encode_vlq(&mut output, value);
+
+            assert_eq!(output, encoded, "{}", value);
+        }
+    }
+}
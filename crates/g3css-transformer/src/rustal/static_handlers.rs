@@ -0,0 +1,49 @@
+use g3css_parser::types::g3css_error::G3cssError;
+
+use crate::types::{
+    css_origin::CssOrigin, css_rule::CssRule, css_stylesheet::CssStylesheet,
+    resolved_class::ResolvedClass, transformer_options::G3cssTransformerOptions,
+};
+
+use super::rule_handlers::breakpoint_media;
+
+/// Builds one CSS rule per block of every resolved class.
+///
+/// # Arguments
+///
+/// - `options` - The transformer options holding the breakpoint definitions.
+/// - `classes` - The resolved classes of the scope.
+///
+/// # Returns
+///
+/// A `CssStylesheet` mapping every class to itself, or a `G3cssError` if a breakpoint is
+/// not defined.
+pub fn build_static_stylesheet(
+    options: &G3cssTransformerOptions,
+    classes: &[ResolvedClass],
+) -> Result<CssStylesheet, G3cssError> {
+    let mut stylesheet = CssStylesheet::default();
+
+    for class in classes {
+        for block in &class.blocks {
+            stylesheet.rules.push(CssRule {
+                class_name: Some(class.name.clone()),
+                selector: format!(".{}{}", class.name, block.pseudo),
+                origins: vec![CssOrigin::Class {
+                    class_name: class.name.clone(),
+                    breakpoint: block.breakpoint.clone(),
+                    block: block.keyword,
+                    index: None,
+                }],
+                media: breakpoint_media(options, block.breakpoint.as_deref())?,
+                declarations: block.declarations.clone(),
+            });
+        }
+
+        stylesheet
+            .class_map
+            .insert(class.name.clone(), vec![class.name.clone()]);
+    }
+
+    Ok(stylesheet)
+}
@@ -0,0 +1,56 @@
+use g3css_parser::types::g3css_error::G3cssError;
+
+use crate::types::scope_context::ScopeContext;
+
+/// Substitutes every `${name}` variable reference inside a value.
+///
+/// Theme variables are replaced by a `var(--name)` reference to their custom property, so
+/// the value follows the active theme, while plain variables are replaced by their value.
+///
+/// # Arguments
+///
+/// - `context` - The context holding the variables of the scope.
+/// - `value` - The raw value written in the G3CSS file.
+///
+/// # Returns
+///
+/// The value with every reference substituted, or a `G3cssError` naming the first unknown
+/// variable.
+pub fn substitute_variables(context: &ScopeContext, value: &str) -> Result<String, G3cssError> {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+
+    // Copy the value up to each `${`, then replace the reference that follows it.
+    while let Some(start) = rest.find("${") {
+        let Some(length) = rest[start..].find('}') else {
+            break;
+        };
+
+        let name = rest[start + 2..start + length].trim();
+        output.push_str(&rest[..start]);
+
+        let is_theme_variable = context
+            .light_theme
+            .iter()
+            .chain(context.dark_theme.iter())
+            .any(|(key, _)| key == name);
+
+        if is_theme_variable {
+            output.push_str(&format!("var(--{})", name));
+        } else if let Some(variable) = context.variables.get(name) {
+            output.push_str(variable);
+        } else {
+            return Err(G3cssError::OtherError(format!(
+                "Unknown variable \"{}\" in scope \"{}\"",
+                name, context.name
+            )));
+        }
+
+        rest = &rest[start + length + 1..];
+    }
+
+    // Keep whatever follows the last reference untouched.
+    output.push_str(rest);
+
+    Ok(output)
+}
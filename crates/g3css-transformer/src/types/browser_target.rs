@@ -0,0 +1,10 @@
+/// Represents a range of versions of one browser selected by a target query.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BrowserTarget {
+    /// Canonical name of the browser in the compatibility table (e.g. `safari`).
+    pub browser: &'static str,
+    /// Oldest version of the browser being targeted.
+    pub min_version: (u32, u32),
+    /// Newest version of the browser being targeted, or `None` when unbounded.
+    pub max_version: Option<(u32, u32)>,
+}
@@ -0,0 +1,14 @@
+use super::css_origin::CssOrigin;
+
+/// Represents a single CSS declaration (e.g. `display: flex !important`).
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct CssDeclaration {
+    /// CSS property name (e.g. `display`).
+    pub property: String,
+    /// Value of the declaration with nicknames and variables already resolved.
+    pub value: String,
+    /// Whether the declaration is emitted with `!important`.
+    pub important: bool,
+    /// Construct of the G3CSS source the declaration was written in, if known.
+    pub origin: Option<CssOrigin>,
+}
@@ -0,0 +1,12 @@
+use super::css_origin::CssOrigin;
+
+/// Represents a position of the generated CSS and the construct it was generated from.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CssMapping {
+    /// 0-based line of the generated CSS.
+    pub line: usize,
+    /// 0-based column of the generated CSS.
+    pub column: usize,
+    /// Construct of the G3CSS source the position maps back to.
+    pub origin: CssOrigin,
+}
@@ -0,0 +1,23 @@
+/// Enum representing the G3CSS construct a generated selector or declaration comes from.
+/// Class - a block of a class, optionally inside a `panoramic_viewer` breakpoint
+/// Theme - the `light` or `dark` theme of a global
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum CssOrigin {
+    Class {
+        /// Name of the class that declares the block.
+        class_name: String,
+        /// Name of the breakpoint holding the block, if any.
+        breakpoint: Option<String>,
+        /// Keyword of the block (e.g. `properties` or `hover`).
+        block: &'static str,
+        /// Position of the declaration among the declarations written in every block with
+        /// this keyword, or `None` when pointing at the block itself.
+        index: Option<usize>,
+    },
+    Theme {
+        /// Either `light` or `dark`.
+        theme: &'static str,
+        /// Position of the variable in the theme, or `None` when pointing at the theme itself.
+        index: Option<usize>,
+    },
+}
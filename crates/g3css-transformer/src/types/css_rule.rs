@@ -0,0 +1,17 @@
+use super::{css_declaration::CssDeclaration, css_origin::CssOrigin};
+
+/// Represents a CSS rule generated by the transformer.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CssRule {
+    /// Name of the G3CSS class this rule was generated from, or `None` for theme rules and
+    /// atomic rules shared by several classes.
+    pub class_name: Option<String>,
+    /// Selector of the rule (e.g. `.BUTTON:hover`), with grouped selectors separated by `, `.
+    pub selector: String,
+    /// Construct each grouped selector was generated from, in selector order.
+    pub origins: Vec<CssOrigin>,
+    /// Media query condition wrapping the rule (e.g. `(max-width: 640px)`), if any.
+    pub media: Option<String>,
+    /// Declarations of the rule, in source order.
+    pub declarations: Vec<CssDeclaration>,
+}
@@ -0,0 +1,12 @@
+use std::collections::BTreeMap;
+
+use super::css_rule::CssRule;
+
+/// Represents the stylesheet generated from a G3CSS scope.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct CssStylesheet {
+    /// Generated rules, with media rules placed after the rules they override.
+    pub rules: Vec<CssRule>,
+    /// Maps every G3CSS class name to the CSS class names that must be applied for it.
+    pub class_map: BTreeMap<String, Vec<String>>,
+}
@@ -0,0 +1,23 @@
+use super::css_declaration::CssDeclaration;
+
+/// Represents the declarations of a class for one pseudo selector and breakpoint.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ResolvedBlock {
+    /// Keyword of the block (e.g. `properties` or `hover`).
+    pub keyword: &'static str,
+    /// Selector suffix of the block (e.g. `:hover`), empty for `properties`.
+    pub pseudo: &'static str,
+    /// Name of the `panoramic_viewer` breakpoint wrapping the block, if any.
+    pub breakpoint: Option<String>,
+    /// Declarations of the block, inherited ones first.
+    pub declarations: Vec<CssDeclaration>,
+}
+
+/// Represents a G3CSS class with inheritance, nicknames and variables resolved.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ResolvedClass {
+    /// Name of the G3CSS class.
+    pub name: String,
+    /// Blocks of the class, in the order they were first declared.
+    pub blocks: Vec<ResolvedBlock>,
+}
@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+
+use g3css_parser::types::g3css_class::G3cssClass;
+
+/// Holds everything the transformer needs to know about a scope and its extended global.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct ScopeContext<'a> {
+    /// Name of the scope being transformed.
+    pub name: String,
+    /// Maps every nickname to the G3CSS property it stands for.
+    pub aliases: HashMap<String, String>,
+    /// Maps every variable name to its value.
+    pub variables: HashMap<String, String>,
+    /// Variables declared by the light theme, in source order.
+    pub light_theme: Vec<(String, String)>,
+    /// Variables declared by the dark theme, in source order.
+    pub dark_theme: Vec<(String, String)>,
+    /// Classes declared by the scope itself, in source order.
+    pub classes: Vec<&'a Vec<G3cssClass>>,
+    /// Classes that can be inherited from, including the ones of the extended global.
+    pub inheritable: HashMap<String, &'a Vec<G3cssClass>>,
+}
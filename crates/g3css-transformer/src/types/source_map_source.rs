@@ -0,0 +1,12 @@
+use g3css_parser::types::g3css_symbol::G3cssSymbol;
+
+/// Represents a G3CSS file that generated CSS can be mapped back to.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SourceMapSource<'a> {
+    /// Path of the file as it should appear in the source map.
+    pub path: &'a str,
+    /// Content of the file.
+    pub content: &'a str,
+    /// Symbols of the file, as returned by `g3css_parse_with_symbols`.
+    pub symbols: &'a [G3cssSymbol],
+}
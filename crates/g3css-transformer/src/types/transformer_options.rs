@@ -0,0 +1,80 @@
+use g3css_common::types::g3css_config::G3cssConfig;
+pub use g3css_common::types::g3css_config::{
+    G3cssHashStrategy, G3cssOutputMode, G3cssThemeStrategy,
+};
+
+/// Enum representing how the generated CSS is written out.
+/// Pretty - indented, readable output for development builds
+/// Minified - whitespace-free, shortened output for production builds
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum G3cssCssStyle {
+    #[default]
+    Pretty,
+    Minified,
+}
+
+/// Options controlling how the transformer generates CSS.
+#[derive(PartialEq, Debug, Clone)]
+pub struct G3cssTransformerOptions {
+    /// Selects between static and utility-first (atomic) output.
+    pub output_mode: G3cssOutputMode,
+    /// Media query condition of every `panoramic_viewer` breakpoint, in cascade order.
+    pub breakpoints: Vec<(String, String)>,
+    /// Whether the optimization pass runs over the generated rules.
+    pub optimize: bool,
+    /// Selects between readable and minified CSS when the stylesheet is emitted.
+    pub css_style: G3cssCssStyle,
+    /// Browserslist-style query selecting the browsers whose vendor prefixes are added
+    /// (e.g. `last 2 versions, safari >= 14`), or `None` to emit no prefixes.
+    pub browser_targets: Option<String>,
+    /// How atomic class names are derived from their hash.
+    pub hashing: G3cssHashStrategy,
+    /// How the dark theme is selected.
+    pub theme: G3cssThemeStrategy,
+}
+
+impl Default for G3cssTransformerOptions {
+    fn default() -> Self {
+        Self {
+            output_mode: G3cssOutputMode::default(),
+            breakpoints: vec![
+                ("mobile".to_string(), "(max-width: 640px)".to_string()),
+                (
+                    "tablet".to_string(),
+                    "(min-width: 641px) and (max-width: 1024px)".to_string(),
+                ),
+                (
+                    "laptop".to_string(),
+                    "(min-width: 1025px) and (max-width: 1440px)".to_string(),
+                ),
+                ("desktop".to_string(), "(min-width: 1441px)".to_string()),
+            ],
+            optimize: true,
+            css_style: G3cssCssStyle::default(),
+            browser_targets: None,
+            hashing: G3cssHashStrategy::default(),
+            theme: G3cssThemeStrategy::default(),
+        }
+    }
+}
+
+impl From<&G3cssConfig> for G3cssTransformerOptions {
+    fn from(config: &G3cssConfig) -> Self {
+        Self {
+            output_mode: config.output.mode,
+            breakpoints: config
+                .breakpoints
+                .iter()
+                .map(|breakpoint| (breakpoint.name.clone(), breakpoint.media.clone()))
+                .collect(),
+            optimize: config.output.optimize,
+            css_style: match config.output.minify {
+                true => G3cssCssStyle::Minified,
+                false => G3cssCssStyle::Pretty,
+            },
+            browser_targets: config.browser_targets.clone(),
+            hashing: config.hashing,
+            theme: config.theme,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Output generated for a single `.g3css` file, stored as a whole in the build cache.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct BuiltStylesheet {
+    /// The generated CSS, ending with a link to its source map.
+    pub css: String,
+    /// The Source Map v3 document mapping the CSS back to the G3CSS sources.
+    pub source_map: String,
+    /// Every G3CSS class mapped to the CSS classes to apply in its place.
+    pub class_map: BTreeMap<String, Vec<String>>,
+}
@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{self, Component, Path, PathBuf},
+};
+
+use g3css_parser::{g3css_parse_with_symbols, types::g3css_error::G3cssError};
+use g3css_transformer::{
+    g3css_transformer,
+    rustal::{
+        css_emitter::emit_css_with_mappings,
+        source_map_handlers::{build_source_map, source_mapping_url},
+    },
+    types::{
+        css_stylesheet::CssStylesheet, source_map_source::SourceMapSource,
+        transformer_options::G3cssTransformerOptions,
+    },
+};
+
+use crate::cache::{
+    build_cache::{cache_key, BuildCache},
+    cached_scope::CachedScope,
+};
+
+use super::built_stylesheet::BuiltStylesheet;
+
+/// A `.g3css` file read for a build.
+struct SourceFile {
+    /// Path of the file.
+    path: PathBuf,
+    /// Content of the file.
+    content: String,
+    /// Content key of the file in the cache.
+    key: String,
+    /// Summary of the file, or the error met while parsing it.
+    scope: Result<CachedScope, G3cssError>,
+}
+
+/// Builds the path of a file relative to a directory, going up with `..` where needed.
+///
+/// # Arguments
+///
+/// - `directory` - The directory the path is relative to.
+/// - `path` - The path of the file.
+///
+/// # Returns
+///
+/// The relative path, with `/` separators as expected by source maps.
+fn relative_path(directory: &Path, path: &Path) -> String {
+    let (Ok(directory), Ok(path)) = (path::absolute(directory), path::absolute(path)) else {
+        return path.to_string_lossy().replace('\\', "/");
+    };
+
+    let directory: Vec<Component> = directory.components().collect();
+    let path: Vec<Component> = path.components().collect();
+    let common = directory
+        .iter()
+        .zip(&path)
+        .take_while(|(first, second)| first == second)
+        .count();
+
+    let mut parts = vec!["..".to_string(); directory.len() - common];
+    parts.extend(
+        path[common..]
+            .iter()
+            .map(|component| component.as_os_str().to_string_lossy().to_string()),
+    );
+
+    parts.join("/")
+}
+
+/// Emits a stylesheet along with its source map, and links the CSS to the map.
+///
+/// # Arguments
+///
+/// - `stylesheet` - The stylesheet to emit.
+/// - `options` - The options controlling the generated CSS.
+/// - `output` - The path of the CSS file, whose source map is written next to it as
+///   `<name>.css.map`.
+/// - `sources` - The path and content of the files the stylesheet was generated from, the
+///   file itself before the global it extends.
+///
+/// # Returns
+///
+/// - `Result<BuiltStylesheet, G3cssError>` - Ok containing the CSS, source map and class
+///   map, or an Err if a source could not be parsed.
+pub fn build_stylesheet(
+    stylesheet: CssStylesheet,
+    options: &G3cssTransformerOptions,
+    output: &Path,
+    sources: &[(&Path, &str)],
+) -> Result<BuiltStylesheet, G3cssError> {
+    let (mut css, mappings) = emit_css_with_mappings(&stylesheet, options.css_style);
+
+    let directory = output.parent().unwrap_or(Path::new(""));
+    let file_name = |path: &Path| {
+        path.file_name()
+            .map_or(String::new(), |name| name.to_string_lossy().to_string())
+    };
+
+    let mut parsed = vec![];
+
+    for (path, content) in sources {
+        let (_, symbols) = g3css_parse_with_symbols(content)?;
+        parsed.push((relative_path(directory, path), *content, symbols));
+    }
+
+    let sources: Vec<SourceMapSource> = parsed
+        .iter()
+        .map(|(path, content, symbols)| SourceMapSource {
+            path,
+            content,
+            symbols,
+        })
+        .collect();
+
+    let map_path = source_map_path(output);
+    let source_map = build_source_map(&file_name(output), &sources, &mappings);
+
+    if !css.ends_with('\n') {
+        css.push('\n');
+    }
+    css.push_str(&source_mapping_url(&file_name(&map_path)));
+    css.push('\n');
+
+    Ok(BuiltStylesheet {
+        css,
+        source_map,
+        class_map: stylesheet.class_map,
+    })
+}
+
+/// Builds the path of the source map of a CSS file.
+pub fn source_map_path(output: &Path) -> PathBuf {
+    output.with_extension("css.map")
+}
+
+/// Generates the CSS, source map and class map of a file, reusing the cache whenever none of
+/// its inputs changed.
+///
+/// # Arguments
+///
+/// - `cache` - The build cache.
+/// - `file` - The file to build.
+/// - `global` - The global extended by the file, if any.
+/// - `output` - The path of the CSS generated for the file.
+/// - `options` - The options controlling the generated CSS.
+/// - `options_key` - The options, formatted so they can take part in the cache key.
+///
+/// # Returns
+///
+/// - `Result<BuiltStylesheet, G3cssError>` - Ok containing the stylesheet, or an Err if the
+///   file or its global could not be parsed or transformed.
+fn build_file(
+    cache: &mut BuildCache,
+    file: &SourceFile,
+    global: Option<&SourceFile>,
+    output: &Path,
+    options: &G3cssTransformerOptions,
+    options_key: &str,
+) -> Result<BuiltStylesheet, G3cssError> {
+    // The CSS depends on the file, the global it extends (with its aliases, variables and
+    // classes) and the options, so a change to any of them leads to another key. The source
+    // map also names the files relative to the output.
+    let global_key = global.map_or("", |global| global.key.as_str());
+    let global_path = global.map_or(Path::new(""), |global| global.path.as_path());
+    let css_key = cache_key(&[
+        &file.key,
+        global_key,
+        options_key,
+        &output.to_string_lossy(),
+        &file.path.to_string_lossy(),
+        &global_path.to_string_lossy(),
+    ]);
+
+    if let Some(built) = cache.stylesheet(&css_key) {
+        return Ok(built);
+    }
+
+    let ast = cache.ast(&file.key, &file.content)?;
+    let global_ast = match global {
+        Some(global) => Some(cache.ast(&global.key, &global.content)?),
+        None => None,
+    };
+
+    let stylesheet = g3css_transformer(&ast, global_ast.as_deref(), options)?;
+    let mut sources = vec![(file.path.as_path(), file.content.as_str())];
+    sources.extend(global.map(|global| (global.path.as_path(), global.content.as_str())));
+
+    let built = build_stylesheet(stylesheet, options, output, &sources)?;
+    cache.store_stylesheet(&css_key, &built);
+
+    Ok(built)
+}
+
+/// Builds the source files of a project, skipping every file whose inputs did not change
+/// since it was last built.
+///
+/// # Arguments
+///
+/// - `paths` - The source files of the project.
+/// - `output_path` - Builds the path of the CSS generated for a source file.
+/// - `options` - The options controlling the generated CSS.
+/// - `cache` - The build cache, pruned of stale entries once the build is done.
+///
+/// # Returns
+///
+/// The path of every file with its built stylesheet, or the error met while building it.
+pub fn build_project(
+    paths: &[PathBuf],
+    output_path: impl Fn(&Path) -> PathBuf,
+    options: &G3cssTransformerOptions,
+    cache: &mut BuildCache,
+) -> Vec<(PathBuf, Result<BuiltStylesheet, G3cssError>)> {
+    let mut files = vec![];
+
+    for path in paths {
+        let file = fs::read_to_string(path)
+            .map_err(|error| G3cssError::OtherError(error.to_string()))
+            .map(|content| {
+                let key = cache_key(&[&content]);
+                let scope = cache.scope(&key, &content);
+
+                SourceFile {
+                    path: path.clone(),
+                    content,
+                    key,
+                    scope,
+                }
+            });
+
+        files.push((path.clone(), file));
+    }
+
+    // Globals are looked up by name when a component extends one.
+    let globals = files
+        .iter()
+        .filter_map(|(_, file)| {
+            let file = file.as_ref().ok()?;
+            let scope = file.scope.as_ref().ok()?;
+            scope.is_global.then(|| (scope.name.clone(), file))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let options_key = format!("{:?}", options);
+    let mut results = vec![];
+
+    for (path, file) in &files {
+        let result = match file {
+            Ok(file) => match &file.scope {
+                Ok(scope) => {
+                    let global = scope
+                        .extends
+                        .as_ref()
+                        .and_then(|extends| globals.get(extends).copied());
+
+                    build_file(
+                        cache,
+                        file,
+                        global,
+                        &output_path(path),
+                        options,
+                        &options_key,
+                    )
+                }
+                Err(error) => Err(error.clone()),
+            },
+            Err(error) => Err(error.clone()),
+        };
+
+        results.push((path.clone(), result));
+    }
+
+    cache.prune();
+
+    results
+}
@@ -0,0 +1,340 @@
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use g3css_common::utils::{hashing::fnv1a_64, node_utils::scope_parts};
+use g3css_parser::{
+    g3css_parse_source,
+    types::{g3css_error::G3cssError, g3css_node::G3cssNode},
+};
+
+use crate::build::built_stylesheet::BuiltStylesheet;
+
+use super::cached_scope::CachedScope;
+
+/// Version of the compiler followed by a hash of its sources, part of every cache key so any
+/// change to the compiler invalidates the cache, even one keeping the package version.
+pub const COMPILER_VERSION: &str =
+    concat!(env!("CARGO_PKG_VERSION"), "+", env!("G3CSS_BUILD_HASH"));
+
+/// Persistent on-disk cache of parsed ASTs and generated CSS.
+///
+/// Entries are addressed by hashes of their inputs, so an entry never needs to be updated:
+/// a changed input simply produces a different key. The cache directory holds three
+/// folders: `scopes` and `asts` keyed by the content of a file, and `css` keyed by
+/// everything the built stylesheet depends on.
+#[derive(Debug)]
+pub struct BuildCache {
+    /// Root directory of the cache.
+    directory: PathBuf,
+    /// Every entry read or written since the cache was opened.
+    used: HashSet<PathBuf>,
+}
+
+/// Hashes the given parts into a cache key, together with the compiler version.
+///
+/// # Arguments
+///
+/// - `parts` - The inputs identifying the entry.
+///
+/// # Returns
+///
+/// The key as 16 hexadecimal digits.
+pub fn cache_key(parts: &[&str]) -> String {
+    let mut input = COMPILER_VERSION.to_string();
+
+    // Separators keep `["ab", "c"]` and `["a", "bc"]` apart.
+    for part in parts {
+        input.push('\0');
+        input.push_str(part);
+    }
+
+    format!("{:016x}", fnv1a_64(input.as_bytes()))
+}
+
+/// Builds the summary of a parsed file.
+fn summarize(ast: &G3cssNode) -> Option<CachedScope> {
+    let (name, extends, _) = scope_parts(ast)?;
+
+    Some(CachedScope {
+        name: name.to_string(),
+        extends: extends.map(str::to_string),
+        is_global: matches!(ast, G3cssNode::Global(_)),
+    })
+}
+
+impl BuildCache {
+    /// Opens the cache stored in `directory`, creating it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `directory` - The directory holding the cache.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BuildCache, G3cssError>` - Ok containing the cache, or an Err if the cache
+    ///   directories could not be created.
+    pub fn open(directory: &Path) -> Result<Self, G3cssError> {
+        for folder in ["scopes", "asts", "css"] {
+            fs::create_dir_all(directory.join(folder)).map_err(|error| {
+                G3cssError::OtherError(format!(
+                    "Failed to create cache directory {}: {}",
+                    directory.display(),
+                    error
+                ))
+            })?;
+        }
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            used: HashSet::new(),
+        })
+    }
+
+    /// Builds the path of an entry and records it as used.
+    fn entry(&mut self, folder: &str, key: &str, extension: &str) -> PathBuf {
+        let path = self
+            .directory
+            .join(folder)
+            .join(format!("{}.{}", key, extension));
+
+        self.used.insert(path.clone());
+        path
+    }
+
+    /// Reads and deserializes a JSON entry, treating unreadable entries as missing.
+    fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Option<T> {
+        let content = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Writes an entry. The cache is best-effort, so a failed write only costs a rebuild on
+    /// the next run.
+    fn write(path: &Path, content: &str) {
+        let _ = fs::write(path, content);
+    }
+
+    /// Parses a file and stores both its AST and its summary.
+    fn parse(&mut self, key: &str, content: &str) -> Result<Rc<G3cssNode>, G3cssError> {
+        let ast = g3css_parse_source(content)?;
+
+        if let Ok(json) = serde_json::to_string(ast.as_ref()) {
+            let path = self.entry("asts", key, "json");
+            Self::write(&path, &json);
+        }
+
+        if let Some(scope) = summarize(&ast) {
+            if let Ok(json) = serde_json::to_string(&scope) {
+                let path = self.entry("scopes", key, "json");
+                Self::write(&path, &json);
+            }
+        }
+
+        Ok(ast)
+    }
+
+    /// Gets the summary of a file, parsing it only if the cache has no entry for it.
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - The content key of the file (see `cache_key`).
+    /// - `content` - The content of the file.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<CachedScope, G3cssError>` - Ok containing the summary, or an Err if the file
+    ///   could not be parsed or declares neither a global nor a component.
+    pub fn scope(&mut self, key: &str, content: &str) -> Result<CachedScope, G3cssError> {
+        let path = self.entry("scopes", key, "json");
+
+        if let Some(scope) = Self::read_json(&path) {
+            return Ok(scope);
+        }
+
+        let ast = self.parse(key, content)?;
+
+        match summarize(&ast) {
+            Some(scope) => Ok(scope),
+            None => Err(G3cssError::OtherError(
+                "Expected a global or component scope".to_string(),
+            )),
+        }
+    }
+
+    /// Gets the AST of a file, parsing it only if the cache has no entry for it.
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - The content key of the file (see `cache_key`).
+    /// - `content` - The content of the file.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Rc<G3cssNode>, G3cssError>` - Ok containing the AST, or an Err if the file
+    ///   could not be parsed.
+    pub fn ast(&mut self, key: &str, content: &str) -> Result<Rc<G3cssNode>, G3cssError> {
+        let path = self.entry("asts", key, "json");
+
+        match Self::read_json::<G3cssNode>(&path) {
+            Some(ast) => Ok(Rc::new(ast)),
+            None => self.parse(key, content),
+        }
+    }
+
+    /// Gets a previously built stylesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - The key of every input of the stylesheet (see `cache_key`).
+    ///
+    /// # Returns
+    ///
+    /// The stylesheet, or `None` if it was never built for these inputs.
+    pub fn stylesheet(&mut self, key: &str) -> Option<BuiltStylesheet> {
+        let path = self.entry("css", key, "json");
+        Self::read_json(&path)
+    }
+
+    /// Stores a built stylesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - The key of every input of the stylesheet (see `cache_key`).
+    /// - `stylesheet` - The built stylesheet.
+    pub fn store_stylesheet(&mut self, key: &str, stylesheet: &BuiltStylesheet) {
+        if let Ok(json) = serde_json::to_string(stylesheet) {
+            let path = self.entry("css", key, "json");
+            Self::write(&path, &json);
+        }
+    }
+
+    /// Deletes every entry that was neither read nor written since the cache was opened,
+    /// which keeps the cache from growing with every edit. Only call it after building the
+    /// whole project, since the entries of files left out of the build are deleted too.
+    pub fn prune(&self) {
+        for folder in ["scopes", "asts", "css"] {
+            let Ok(entries) = fs::read_dir(self.directory.join(folder)) else {
+                continue;
+            };
+
+            for path in entries.flatten().map(|entry| entry.path()) {
+                if !self.used.contains(&path) {
+                    let _ = fs::remove_file(path);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    const GLOBAL: &str = r#"define global scope "APP" {
+    define class "CARD" {
+        properties: {
+            padding: "4px",
+        },
+    },
+}"#;
+
+    const COMPONENT: &str = r#"define component scope "BUTTON" extends global "APP" {
+    define class "PRIMARY" {
+        properties: {
+            cursor: "pointer",
+        },
+    },
+}"#;
+
+    /// Creates an empty cache directory for a test, removing what a previous run left.
+    fn test_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("g3css-cache-{}-{}", std::process::id(), name));
+
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    /// Lists the entries of a folder of the cache.
+    fn entries(directory: &Path, folder: &str) -> Vec<PathBuf> {
+        let mut entries: Vec<PathBuf> = fs::read_dir(directory.join(folder))
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+
+        entries.sort();
+        entries
+    }
+
+    #[test]
+    fn a_changed_file_misses_and_its_old_entries_are_pruned() {
+        let directory = test_directory("content");
+        let old_key = cache_key(&[GLOBAL]);
+        let new_key = cache_key(&[COMPONENT]);
+
+        assert_ne!(old_key, new_key);
+
+        // First build: the file is parsed and stored.
+        let mut cache = BuildCache::open(&directory).unwrap();
+        assert!(cache.scope(&old_key, GLOBAL).unwrap().is_global);
+        assert_eq!(entries(&directory, "asts").len(), 1);
+
+        // Next build of the same content: the entries are read back without parsing, which
+        // content that does not parse shows.
+        let mut cache = BuildCache::open(&directory).unwrap();
+        assert_eq!(cache.scope(&old_key, "not g3css").unwrap().name, "APP");
+        assert_eq!(
+            *cache.ast(&old_key, "not g3css").unwrap(),
+            *g3css_parse_source(GLOBAL).unwrap()
+        );
+
+        // The file changed: its new key misses, so the new content is parsed.
+        let mut cache = BuildCache::open(&directory).unwrap();
+        let scope = cache.scope(&new_key, COMPONENT).unwrap();
+
+        assert_eq!(scope.name, "BUTTON");
+        assert_eq!(scope.extends.as_deref(), Some("APP"));
+        assert!(cache
+            .scope(&cache_key(&["not g3css"]), "not g3css")
+            .is_err());
+
+        // Only the entries of the current content survive the prune.
+        cache.prune();
+
+        for folder in ["scopes", "asts"] {
+            assert_eq!(
+                entries(&directory, folder),
+                [directory.join(folder).join(format!("{}.json", new_key))]
+            );
+        }
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn stored_stylesheets_are_read_back_by_key() {
+        let directory = test_directory("stylesheet");
+        let key = cache_key(&[GLOBAL, "static"]);
+        let stylesheet = BuiltStylesheet {
+            css: ".CARD {\n  padding: 4px;\n}\n".to_string(),
+            source_map: "{}".to_string(),
+            class_map: BTreeMap::from([("CARD".to_string(), vec!["CARD".to_string()])]),
+        };
+
+        let mut cache = BuildCache::open(&directory).unwrap();
+        assert_eq!(cache.stylesheet(&key), None);
+        cache.store_stylesheet(&key, &stylesheet);
+
+        let mut cache = BuildCache::open(&directory).unwrap();
+        assert_eq!(cache.stylesheet(&key), Some(stylesheet));
+        assert_eq!(cache.stylesheet(&cache_key(&[GLOBAL, "atomic"])), None);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Summary of a parsed `.g3css` file stored next to its AST, so the cache can tell which
+/// global a file extends without loading the whole AST.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct CachedScope {
+    /// Name of the global or component declared by the file.
+    pub name: String,
+    /// Name of the global extended by the file, if any.
+    pub extends: Option<String>,
+    /// Whether the file declares a global rather than a component.
+    pub is_global: bool,
+}
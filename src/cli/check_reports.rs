@@ -0,0 +1,196 @@
+use std::path::PathBuf;
+
+use g3css_ast::types::g3css_diagnostic::{G3cssDiagnostic, G3cssSeverity};
+use serde_json::json;
+
+/// Version of the JSON report, bumped whenever a field changes meaning or disappears.
+const JSON_REPORT_VERSION: u32 = 1;
+
+/// Represents a file checked by `check` with its diagnostics.
+pub struct CheckedFile {
+    pub path: PathBuf,
+    pub diagnostics: Vec<G3cssDiagnostic>,
+}
+
+/// Names a severity the way the reports write it.
+fn severity_name(severity: G3cssSeverity) -> &'static str {
+    match severity {
+        G3cssSeverity::Error => "error",
+        G3cssSeverity::Warning => "warning",
+        G3cssSeverity::Information => "info",
+        G3cssSeverity::Hint => "hint",
+    }
+}
+
+/// Escapes text for an XML attribute or element.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Escapes the message of a GitHub workflow command.
+fn escape_github_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a property of a GitHub workflow command.
+fn escape_github_property(text: &str) -> String {
+    escape_github_data(text)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Builds the JSON report of a check.
+///
+/// # Arguments
+///
+/// - `files` - The checked files with their diagnostics.
+///
+/// # Returns
+///
+/// A JSON object holding the report version, the number of checked files and every
+/// diagnostic with its file, position, severity, code and message.
+pub fn json_report(files: &[CheckedFile]) -> String {
+    let diagnostics = files
+        .iter()
+        .flat_map(|file| {
+            file.diagnostics.iter().map(|diagnostic| {
+                json!({
+                    "file": file.path.display().to_string(),
+                    "line": diagnostic.span.line,
+                    "column": diagnostic.span.column,
+                    "severity": severity_name(diagnostic.severity),
+                    "code": diagnostic.code,
+                    "message": diagnostic.message,
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let report = json!({
+        "version": JSON_REPORT_VERSION,
+        "files": files.len(),
+        "diagnostics": diagnostics,
+    });
+
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+/// Builds the GitHub Actions workflow commands annotating the diagnostics of a check.
+///
+/// # Arguments
+///
+/// - `files` - The checked files with their diagnostics.
+///
+/// # Returns
+///
+/// One `::error`, `::warning` or `::notice` command per diagnostic, one per line.
+pub fn github_report(files: &[CheckedFile]) -> String {
+    let mut report = String::new();
+
+    for file in files {
+        for diagnostic in &file.diagnostics {
+            let command = match diagnostic.severity {
+                G3cssSeverity::Error => "error",
+                G3cssSeverity::Warning => "warning",
+                G3cssSeverity::Information | G3cssSeverity::Hint => "notice",
+            };
+
+            report.push_str(&format!(
+                "::{} file={},line={},col={},title={}::{}\n",
+                command,
+                escape_github_property(&file.path.display().to_string()),
+                diagnostic.span.line,
+                diagnostic.span.column,
+                escape_github_property(diagnostic.code),
+                escape_github_data(&diagnostic.message)
+            ));
+        }
+    }
+
+    report
+}
+
+/// Builds the JUnit XML report of a check, with one test case per file.
+///
+/// # Arguments
+///
+/// - `files` - The checked files with their diagnostics.
+/// - `deny_warnings` - Whether warnings fail their test case like errors.
+///
+/// # Returns
+///
+/// The XML document. Failing diagnostics become `failure` elements, the others are listed
+/// in the `system-out` of their test case.
+pub fn junit_report(files: &[CheckedFile], deny_warnings: bool) -> String {
+    let fails = |diagnostic: &G3cssDiagnostic| match diagnostic.severity {
+        G3cssSeverity::Error => true,
+        G3cssSeverity::Warning => deny_warnings,
+        _ => false,
+    };
+
+    let failures = files
+        .iter()
+        .filter(|file| file.diagnostics.iter().any(fails))
+        .count();
+
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let header = format!(
+        "name=\"galadriel3css check\" tests=\"{}\" failures=\"{}\"",
+        files.len(),
+        failures
+    );
+
+    report.push_str(&format!("<testsuites {}>\n", header));
+    report.push_str(&format!("  <testsuite {}>\n", header));
+
+    for file in files {
+        let path = escape_xml(&file.path.display().to_string());
+        report.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"g3css\">\n",
+            path
+        ));
+
+        let mut output = vec![];
+
+        for diagnostic in &file.diagnostics {
+            let line = format!(
+                "{}:{}:{}: {}[{}]: {}",
+                path,
+                diagnostic.span.line,
+                diagnostic.span.column,
+                severity_name(diagnostic.severity),
+                diagnostic.code,
+                escape_xml(&diagnostic.message)
+            );
+
+            if fails(diagnostic) {
+                report.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    diagnostic.code,
+                    escape_xml(&diagnostic.message),
+                    line
+                ));
+            } else {
+                output.push(line);
+            }
+        }
+
+        if !output.is_empty() {
+            report.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                output.join("\n")
+            ));
+        }
+
+        report.push_str("    </testcase>\n");
+    }
+
+    report.push_str("  </testsuite>\n</testsuites>\n");
+    report
+}
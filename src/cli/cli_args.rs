@@ -0,0 +1,174 @@
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+
+/// Enum representing when the output is coloured.
+/// Auto - colours the output when it goes to a terminal and `NO_COLOR` is not set
+/// Always - always colours the output
+/// Never - never colours the output
+#[derive(PartialEq, Debug, Clone, Copy, Default, ValueEnum)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Splits a `--set` argument into its key and value.
+fn parse_override(argument: &str) -> Result<(String, String), String> {
+    argument
+        .split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, found \"{}\"", argument))
+}
+
+/// Enum representing how `check` reports its diagnostics.
+/// Human - readable lines on the standard error
+/// Json - a versioned JSON document on the standard output
+/// Github - GitHub Actions workflow commands annotating the files
+/// Junit - a JUnit XML document with one test case per file
+#[derive(PartialEq, Debug, Clone, Copy, Default, ValueEnum)]
+pub enum CheckFormat {
+    #[default]
+    Human,
+    Json,
+    Github,
+    Junit,
+}
+
+/// Enum representing how `graph` prints the dependency graph.
+/// Dot - the Graphviz DOT format
+/// Json - a JSON document listing the nodes and edges
+#[derive(PartialEq, Debug, Clone, Copy, Default, ValueEnum)]
+pub enum GraphFormat {
+    #[default]
+    Dot,
+    Json,
+}
+
+/// Command-line interface of the galadriel3css binary.
+#[derive(Debug, Parser)]
+#[command(
+    name = "galadriel3css",
+    version,
+    about = "Compiler and tooling for G3CSS"
+)]
+pub struct G3cssCli {
+    #[command(subcommand)]
+    pub command: G3cssCommand,
+    #[command(flatten)]
+    pub global: GlobalArgs,
+}
+
+/// Flags shared by every subcommand.
+#[derive(Debug, Args)]
+pub struct GlobalArgs {
+    /// Path of the configuration file (default: `galadriel.toml` or `g3css.json` in the
+    /// project root).
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    /// Overrides a configuration key, such as `output.minify=true`.
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE", value_parser = parse_override)]
+    pub overrides: Vec<(String, String)>,
+    /// Directory receiving the generated CSS (default: `output.dir` of the configuration).
+    #[arg(short, long, global = true, value_name = "DIR")]
+    pub out_dir: Option<PathBuf>,
+    /// Prints more details, such as every written file.
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+    /// Only prints errors.
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+    /// When to colour the output.
+    #[arg(long, global = true, value_enum, default_value_t, value_name = "WHEN")]
+    pub color: ColorChoice,
+}
+
+/// Enum representing the subcommands of the binary.
+/// Build - compiles a project to its output directory
+/// Watch - compiles a project, then recompiles the files affected by every change
+/// Check - reports the diagnostics of a project without writing anything
+/// Fmt - formats `.g3css` files in place
+/// Lsp - runs the language server over stdio
+/// Init - creates a starter project
+/// Ast - prints the AST of a file
+/// Docs - generates the style guide of a project
+/// Graph - prints the dependency graph of a project
+#[derive(Debug, Subcommand)]
+pub enum G3cssCommand {
+    /// Compile every `.g3css` file of a project to CSS.
+    Build {
+        /// Root directory of the project.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+    },
+    /// Compile a project, then recompile it whenever its files change.
+    Watch {
+        /// Root directory of the project.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+    },
+    /// Report the diagnostics of a project without writing anything.
+    #[command(after_help = "Exit codes:\n  \
+        0  no error\n  \
+        1  the configuration could not be loaded\n  \
+        3  a file does not parse or cannot be read\n  \
+        4  a file has semantic errors\n  \
+        5  a file has warnings and --deny-warnings is set")]
+    Check {
+        /// Root directory of the project.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+        /// How the diagnostics are reported.
+        #[arg(long, value_enum, default_value_t)]
+        format: CheckFormat,
+        /// Fails on warnings as well as errors.
+        #[arg(long)]
+        deny_warnings: bool,
+    },
+    /// Format `.g3css` files in place, directories being searched recursively.
+    Fmt {
+        /// Only report the files that are not formatted, exiting with an error if there is any.
+        #[arg(long)]
+        check: bool,
+        /// Files and directories to format.
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+    },
+    /// Run the language server over stdio.
+    Lsp,
+    /// Create a starter project with a configuration, a global and a component.
+    Init {
+        /// Directory of the new project.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+    },
+    /// Print the AST of a `.g3css` file or of a JSON AST document.
+    Ast {
+        /// The file to parse.
+        file: PathBuf,
+        /// Prints the AST as a versioned JSON document instead of its debug representation.
+        #[arg(long, conflicts_with = "g3css")]
+        json: bool,
+        /// Prints the AST back as G3CSS source instead of its debug representation.
+        #[arg(long)]
+        g3css: bool,
+    },
+    /// Generate a static HTML style guide of the global scopes of a project, written to the
+    /// `style-guide` directory of the output directory.
+    Docs {
+        /// Root directory of the project.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+    },
+    /// Print the dependency graph between the scopes, classes, aliases and variables of a
+    /// project.
+    Graph {
+        /// Root directory of the project.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+        /// How the graph is printed.
+        #[arg(long, value_enum, default_value_t)]
+        format: GraphFormat,
+    },
+}
@@ -0,0 +1,866 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use g3css_ast::{
+    g3css_format,
+    rustal::{
+        graph_export::{graph_to_dot, graph_to_json},
+        graph_handlers::build_dependency_graph,
+        semantic_checks::{document_diagnostics, error_diagnostic, find_global},
+    },
+    types::{
+        g3css_diagnostic::{G3cssDiagnostic, G3cssSeverity},
+        g3css_document::G3cssDocument,
+    },
+};
+use g3css_common::{
+    rustal::config_loader::{env_overrides, load_config},
+    types::g3css_config::G3cssConfig,
+    utils::file_utils::{scan_directory, source_files},
+};
+use g3css_lsp::g3css_lsp;
+use g3css_observer::{
+    g3css_observer,
+    types::{observer_event::G3cssObserverEvent, observer_options::G3cssObserverOptions},
+};
+use g3css_parser::{
+    g3css_parser,
+    rustal::{
+        ast_printer::print_ast,
+        ast_serialization::{ast_from_json, ast_to_json},
+    },
+    types::g3css_error::G3cssError,
+};
+use g3css_transformer::{g3css_transformer, types::transformer_options::G3cssTransformerOptions};
+
+use crate::{
+    build::{
+        built_stylesheet::BuiltStylesheet,
+        project_builder::{build_project, build_stylesheet, source_map_path},
+    },
+    cache::build_cache::BuildCache,
+    docs::style_guide::build_style_guide,
+};
+
+use super::{
+    check_reports::{github_report, json_report, junit_report, CheckedFile},
+    cli_args::{CheckFormat, G3cssCli, G3cssCommand, GlobalArgs, GraphFormat},
+    terminal_output::Terminal,
+};
+
+/// Exit code of `check` when a file does not parse or cannot be read.
+const EXIT_PARSE_ERROR: u8 = 3;
+
+/// Exit code of `check` when a file has semantic errors.
+const EXIT_SEMANTIC_ERROR: u8 = 4;
+
+/// Exit code of `check` when a file has warnings and warnings are denied.
+const EXIT_DENIED_WARNINGS: u8 = 5;
+
+/// Files written by `init`, relative to the project root.
+const STARTER_FILES: [(&str, &str); 3] = [
+    (
+        "galadriel.toml",
+        r##"# Every key is optional, the values below being the defaults.
+
+# How atomic class names are derived from their hash: "short" or "full".
+hashing = "short"
+# How the dark theme is selected: "media", "class" or "attribute".
+theme = "media"
+# Browsers whose vendor prefixes are added, such as "last 2 versions".
+# browser_targets = "last 2 versions"
+plugins = []
+
+[source]
+roots = ["styles"]
+include = ["**/*.g3css"]
+exclude = []
+
+[output]
+dir = "dist"
+cache_dir = ".g3css-cache"
+# "static" or "atomic".
+mode = "static"
+minify = false
+optimize = true
+
+[[breakpoints]]
+name = "mobile"
+media = "(max-width: 640px)"
+
+[[breakpoints]]
+name = "tablet"
+media = "(min-width: 641px) and (max-width: 1024px)"
+
+[[breakpoints]]
+name = "laptop"
+media = "(min-width: 1025px) and (max-width: 1440px)"
+
+[[breakpoints]]
+name = "desktop"
+media = "(min-width: 1441px)"
+"##,
+    ),
+    (
+        "styles/app.g3css",
+        r##"define global scope "APP" {
+    define aliases {
+        bg: background_color,
+        fg: color,
+    },
+
+    define variables {
+        spacing: "8px",
+    },
+
+    define light theme {
+        define variables {
+            surface: "#ffffff",
+            text: "#1f2933",
+        },
+    },
+
+    define dark theme {
+        define variables {
+            surface: "#1f2933",
+            text: "#f5f7fa",
+        },
+    },
+
+    define class "CARD" {
+        properties: {
+            bg: "${surface}",
+            fg: "${text}",
+            padding: "${spacing}",
+            border_radius: "4px",
+        },
+    },
+}
+"##,
+    ),
+    (
+        "styles/button.g3css",
+        r##"define component scope "BUTTON" extends global "APP" {
+    define class "PRIMARY" inherits "CARD" {
+        properties: {
+            cursor: "pointer",
+        },
+        hover: {
+            opacity: "0.8",
+        },
+    },
+}
+"##,
+    ),
+];
+
+/// Maps a success flag to the exit code of the binary.
+fn exit_code(success: bool) -> ExitCode {
+    match success {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+/// Represents a project root together with its configuration.
+struct Project {
+    root: PathBuf,
+    config: G3cssConfig,
+    /// Directory receiving the generated CSS.
+    out_dir: PathBuf,
+}
+
+impl Project {
+    /// Loads the configuration of the project in `root`, applying the environment overrides
+    /// and then the command-line ones. Errors are reported and yield `None`.
+    fn load(root: &Path, args: &GlobalArgs, terminal: &Terminal) -> Option<Self> {
+        let mut overrides = env_overrides();
+        overrides.extend(args.overrides.iter().cloned());
+
+        let (config, path) = match load_config(root, args.config.as_deref(), &overrides) {
+            Ok(loaded) => loaded,
+            Err(message) => {
+                terminal.error(&message);
+                return None;
+            }
+        };
+
+        match path {
+            Some(path) => terminal.detail(&format!("Using configuration {}", path.display())),
+            None => terminal.detail("Using the default configuration"),
+        }
+
+        for plugin in &config.plugins {
+            terminal.warning(&format!(
+                "plugin \"{}\" ignored, plugins are not supported yet",
+                plugin
+            ));
+        }
+
+        // `--out-dir` is relative to the working directory, `output.dir` to the project.
+        let out_dir = args
+            .out_dir
+            .clone()
+            .unwrap_or_else(|| root.join(&config.output.dir));
+
+        Some(Self {
+            root: root.to_path_buf(),
+            config,
+            out_dir,
+        })
+    }
+
+    /// Builds the path of the CSS generated for `path`, mirroring its place below the root.
+    fn output_path(&self, path: &Path) -> PathBuf {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        self.out_dir.join(relative).with_extension("css")
+    }
+
+    /// Builds the path of the class map generated for `path`, next to its CSS.
+    fn class_map_path(&self, path: &Path) -> PathBuf {
+        self.output_path(path).with_extension("classes.json")
+    }
+
+    /// Writes the CSS, the source map and the class map generated for `path`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<PathBuf, G3cssError>` - Ok containing the path of the CSS, or an Err if a
+    ///   file could not be written.
+    fn write_outputs(&self, path: &Path, built: &BuiltStylesheet) -> Result<PathBuf, G3cssError> {
+        let output = self.output_path(path);
+        let class_map = serde_json::to_string_pretty(&built.class_map)
+            .map_err(|error| G3cssError::OtherError(error.to_string()))?;
+
+        write_file(&output, &built.css)?;
+        write_file(&source_map_path(&output), &built.source_map)?;
+        write_file(&self.class_map_path(path), &class_map)?;
+
+        Ok(output)
+    }
+}
+
+/// Writes a file, creating its parent directories.
+fn write_file(path: &Path, content: &str) -> Result<(), G3cssError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| G3cssError::OtherError(error.to_string()))?;
+    }
+
+    fs::write(path, content).map_err(|error| G3cssError::OtherError(error.to_string()))
+}
+
+/// Compiles every source of a project to its output directory, reusing its build cache.
+fn build_command(project: &Project, terminal: &Terminal) -> ExitCode {
+    let cache_dir = project.root.join(&project.config.output.cache_dir);
+
+    let mut cache = match BuildCache::open(&cache_dir) {
+        Ok(cache) => cache,
+        Err(error) => {
+            terminal.file_error(&cache_dir, &error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let options = G3cssTransformerOptions::from(&project.config);
+    let sources = source_files(&project.root, &project.config.source);
+    let (mut built, mut failed) = (0, 0);
+
+    let output_path = |path: &Path| project.output_path(path);
+
+    for (path, result) in build_project(&sources, output_path, &options, &mut cache) {
+        match result.and_then(|built| project.write_outputs(&path, &built)) {
+            Ok(output) => {
+                built += 1;
+                terminal.detail(&format!("{} -> {}", path.display(), output.display()));
+            }
+            Err(error) => {
+                failed += 1;
+                terminal.file_error(&path, &error);
+            }
+        }
+    }
+
+    terminal.status(
+        "Finished",
+        &format!(
+            "{} file(s) compiled to {}",
+            built,
+            project.out_dir.display()
+        ),
+    );
+
+    if failed > 0 {
+        terminal.error(&format!("{} file(s) failed to compile", failed));
+    }
+
+    exit_code(failed == 0)
+}
+
+/// Compiles a project, then recompiles the files affected by every change until interrupted.
+fn watch_command(project: &Project, terminal: &Terminal) -> ExitCode {
+    let options = G3cssObserverOptions {
+        sources: project.config.source.clone(),
+        transformer: G3cssTransformerOptions::from(&project.config),
+        ..G3cssObserverOptions::default()
+    };
+    let transformer = &options.transformer;
+    let root = &project.root;
+
+    terminal.status("Watching", &root.display().to_string());
+
+    let result = g3css_observer(&root.to_string_lossy(), &options, |event| match event {
+        G3cssObserverEvent::Built {
+            path,
+            global,
+            stylesheet,
+        } => {
+            // The sources are read back to map the CSS to them.
+            let result = [Some(&path), global.as_ref()]
+                .into_iter()
+                .flatten()
+                .map(|source| {
+                    fs::read_to_string(source)
+                        .map(|content| (source.as_path(), content))
+                        .map_err(|error| G3cssError::OtherError(error.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|sources| {
+                    let sources: Vec<(&Path, &str)> = sources
+                        .iter()
+                        .map(|(source, content)| (*source, content.as_str()))
+                        .collect();
+
+                    build_stylesheet(
+                        stylesheet,
+                        transformer,
+                        &project.output_path(&path),
+                        &sources,
+                    )
+                })
+                .and_then(|built| project.write_outputs(&path, &built));
+
+            match result {
+                Ok(_) => terminal.status("Compiled", &path.display().to_string()),
+                Err(error) => terminal.file_error(&path, &error),
+            }
+        }
+        G3cssObserverEvent::Removed { path } => {
+            let output = project.output_path(&path);
+
+            if fs::remove_file(&output).is_ok() {
+                terminal.status("Removed", &output.display().to_string());
+            }
+
+            let _ = fs::remove_file(source_map_path(&output));
+            let _ = fs::remove_file(project.class_map_path(&path));
+        }
+        G3cssObserverEvent::Failed { path, error } => terminal.file_error(&path, &error),
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            terminal.file_error(root, &error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Transforms a parsed document the way `build` does.
+///
+/// # Arguments
+///
+/// - `document` - The document to transform.
+/// - `workspace` - Every document of the project, used to find the extended global.
+/// - `options` - The options of the build.
+///
+/// # Returns
+///
+/// An error diagnostic at the start of the document if it cannot be transformed.
+fn build_diagnostic(
+    document: &G3cssDocument,
+    workspace: &[&G3cssDocument],
+    options: &G3cssTransformerOptions,
+) -> Option<G3cssDiagnostic> {
+    let ast = document.ast.as_ref()?;
+    let global = document
+        .extends()
+        .and_then(|extends| find_global(workspace, extends))
+        .and_then(|global| global.ast.as_deref());
+
+    let error = g3css_transformer(ast, global, options).err()?;
+
+    Some(G3cssDiagnostic {
+        code: "build-error",
+        ..error_diagnostic(&error)
+    })
+}
+
+/// Collects the diagnostics of every source of a project, sorted by path.
+fn check_project(project: &Project) -> Vec<CheckedFile> {
+    let mut documents = vec![];
+    let mut files = vec![];
+
+    for path in source_files(&project.root, &project.config.source) {
+        match fs::read_to_string(&path) {
+            Ok(source) => documents.push((path, G3cssDocument::parse(source))),
+            Err(error) => files.push(CheckedFile {
+                path,
+                diagnostics: vec![error_diagnostic(&G3cssError::OtherError(error.to_string()))],
+            }),
+        }
+    }
+
+    let workspace = documents
+        .iter()
+        .map(|(_, document)| document)
+        .collect::<Vec<_>>();
+
+    let options = G3cssTransformerOptions::from(&project.config);
+    let breakpoints = options
+        .breakpoints
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+
+    for (path, document) in &documents {
+        let mut diagnostics = document_diagnostics(document, &workspace, &breakpoints);
+
+        // Whatever else would make the build fail is reported too, so a passing check means
+        // a passing build.
+        if !diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == G3cssSeverity::Error)
+        {
+            diagnostics.extend(build_diagnostic(document, &workspace, &options));
+        }
+
+        files.push(CheckedFile {
+            path: path.clone(),
+            diagnostics,
+        });
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+/// Counts the diagnostics of checked files having `severity`.
+fn count_severity(files: &[CheckedFile], severity: G3cssSeverity) -> usize {
+    files
+        .iter()
+        .flat_map(|file| &file.diagnostics)
+        .filter(|diagnostic| diagnostic.severity == severity)
+        .count()
+}
+
+/// Picks the exit code of `check` from the diagnostics of the checked files.
+///
+/// # Arguments
+///
+/// - `files` - The checked files with their diagnostics.
+/// - `deny_warnings` - Whether warnings make the check fail.
+///
+/// # Returns
+///
+/// `EXIT_PARSE_ERROR` when a file does not parse or cannot be read, else `EXIT_SEMANTIC_ERROR`
+/// when a file has errors, else `EXIT_DENIED_WARNINGS` when a file has warnings and
+/// `deny_warnings` is set, else 0.
+fn check_status(files: &[CheckedFile], deny_warnings: bool) -> u8 {
+    // Unreadable files are reported with the generic `error` code.
+    let parse_errors = files
+        .iter()
+        .flat_map(|file| &file.diagnostics)
+        .any(|diagnostic| matches!(diagnostic.code, "parse-error" | "error"));
+
+    if parse_errors {
+        EXIT_PARSE_ERROR
+    } else if count_severity(files, G3cssSeverity::Error) > 0 {
+        EXIT_SEMANTIC_ERROR
+    } else if count_severity(files, G3cssSeverity::Warning) > 0 && deny_warnings {
+        EXIT_DENIED_WARNINGS
+    } else {
+        0
+    }
+}
+
+/// Reports the diagnostics of every source of a project in `format`.
+///
+/// Exits with the code picked by `check_status`.
+fn check_command(
+    project: &Project,
+    format: CheckFormat,
+    deny_warnings: bool,
+    terminal: &Terminal,
+) -> ExitCode {
+    let files = check_project(project);
+
+    match format {
+        CheckFormat::Human => {
+            for file in &files {
+                for diagnostic in &file.diagnostics {
+                    terminal.diagnostic(&file.path, diagnostic);
+                }
+            }
+        }
+        CheckFormat::Json => println!("{}", json_report(&files)),
+        CheckFormat::Github => print!("{}", github_report(&files)),
+        CheckFormat::Junit => print!("{}", junit_report(&files, deny_warnings)),
+    }
+
+    terminal.status(
+        "Checked",
+        &format!(
+            "{} file(s): {} error(s), {} warning(s)",
+            files.len(),
+            count_severity(&files, G3cssSeverity::Error),
+            count_severity(&files, G3cssSeverity::Warning)
+        ),
+    );
+
+    ExitCode::from(check_status(&files, deny_warnings))
+}
+
+/// Formats the `.g3css` files named by `paths`, directories being searched recursively.
+///
+/// Files are rewritten in place, or only reported when `check` is set. Files that do not
+/// parse are reported and left untouched.
+fn fmt_command(paths: &[PathBuf], check: bool, terminal: &Terminal) -> ExitCode {
+    let mut success = true;
+
+    let files = paths.iter().flat_map(|path| match path.is_dir() {
+        true => scan_directory(path),
+        false => vec![path.clone()],
+    });
+
+    for file in files {
+        let result = fs::read_to_string(&file)
+            .map_err(|error| G3cssError::OtherError(error.to_string()))
+            .and_then(|source| Ok((g3css_format(&source)?, source)));
+
+        match result {
+            Ok((formatted, source)) if formatted == source => {
+                terminal.detail(&format!("{} is formatted", file.display()));
+            }
+            Ok(_) if check => {
+                terminal.warning(&format!("{} is not formatted", file.display()));
+                success = false;
+            }
+            Ok((formatted, _)) => match write_file(&file, &formatted) {
+                Ok(()) => terminal.status("Formatted", &file.display().to_string()),
+                Err(error) => {
+                    terminal.file_error(&file, &error);
+                    success = false;
+                }
+            },
+            Err(error) => {
+                terminal.file_error(&file, &error);
+                success = false;
+            }
+        }
+    }
+
+    exit_code(success)
+}
+
+/// Writes the starter files of a project, refusing to overwrite existing files.
+fn init_command(root: &Path, terminal: &Terminal) -> ExitCode {
+    let existing = STARTER_FILES
+        .iter()
+        .map(|(path, _)| root.join(path))
+        .filter(|path| path.exists())
+        .collect::<Vec<_>>();
+
+    if !existing.is_empty() {
+        for path in existing {
+            terminal.error(&format!("{} already exists", path.display()));
+        }
+
+        return ExitCode::FAILURE;
+    }
+
+    for (path, content) in STARTER_FILES {
+        let path = root.join(path);
+
+        if let Err(error) = write_file(&path, content) {
+            terminal.file_error(&path, &error);
+            return ExitCode::FAILURE;
+        }
+
+        terminal.status("Created", &path.display().to_string());
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Prints the AST of a file, as JSON, as G3CSS source or as its debug representation.
+/// `.json` files are read as AST documents, so ASTs written by other tools can be checked
+/// or turned back into source.
+fn ast_command(file: &Path, json: bool, g3css: bool, terminal: &Terminal) -> ExitCode {
+    let ast = match file
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        true => fs::read_to_string(file)
+            .map_err(|error| G3cssError::OtherError(error.to_string()))
+            .and_then(|json| ast_from_json(&json)),
+        false => g3css_parser(&file.to_string_lossy()),
+    };
+
+    let output = ast.and_then(|ast| match (json, g3css) {
+        (true, _) => ast_to_json(&ast, true).map(|json| json + "\n"),
+        (_, true) => print_ast(&ast),
+        _ => Ok(format!("{:#?}\n", ast)),
+    });
+
+    match output {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            terminal.file_error(file, &error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the dependency graph of every source of a project that parses in `format`.
+fn graph_command(project: &Project, format: GraphFormat, terminal: &Terminal) -> ExitCode {
+    let mut asts = vec![];
+    let mut success = true;
+
+    for path in source_files(&project.root, &project.config.source) {
+        match g3css_parser(&path.to_string_lossy()) {
+            Ok(ast) => asts.push(ast),
+            Err(error) => {
+                terminal.file_error(&path, &error);
+                success = false;
+            }
+        }
+    }
+
+    let graph = build_dependency_graph(&asts.iter().map(|ast| ast.as_ref()).collect::<Vec<_>>());
+
+    match format {
+        GraphFormat::Dot => print!("{}", graph_to_dot(&graph)),
+        GraphFormat::Json => println!("{}", graph_to_json(&graph)),
+    }
+
+    exit_code(success)
+}
+
+/// Writes the style guide of the globals of a project to the `style-guide` directory of its
+/// output directory.
+fn docs_command(project: &Project, terminal: &Terminal) -> ExitCode {
+    let options = G3cssTransformerOptions::from(&project.config);
+    let sources = source_files(&project.root, &project.config.source);
+    let site_dir = project.out_dir.join("style-guide");
+
+    let guide = build_style_guide(&sources, &options);
+    let mut success = guide.failures.is_empty();
+
+    for (path, error) in &guide.failures {
+        terminal.file_error(path, error);
+    }
+
+    for (path, content) in &guide.files {
+        let path = site_dir.join(path);
+
+        match write_file(&path, content) {
+            Ok(()) => terminal.detail(&format!("Wrote {}", path.display())),
+            Err(error) => {
+                terminal.file_error(&path, &error);
+                success = false;
+            }
+        }
+    }
+
+    terminal.status(
+        "Finished",
+        &format!(
+            "style guide written to {}",
+            site_dir.join("index.html").display()
+        ),
+    );
+
+    exit_code(success)
+}
+
+/// Runs the subcommand of a parsed command line.
+///
+/// # Arguments
+///
+/// - `cli` - The parsed command line.
+///
+/// # Returns
+///
+/// - `ExitCode` - SUCCESS if the command succeeded, FAILURE otherwise.
+pub fn run_cli(cli: G3cssCli) -> ExitCode {
+    let terminal = Terminal::new(&cli.global);
+
+    let project = |root: &Path| Project::load(root, &cli.global, &terminal);
+
+    match &cli.command {
+        G3cssCommand::Build { root } => project(root).map_or(ExitCode::FAILURE, |project| {
+            build_command(&project, &terminal)
+        }),
+        G3cssCommand::Watch { root } => project(root).map_or(ExitCode::FAILURE, |project| {
+            watch_command(&project, &terminal)
+        }),
+        G3cssCommand::Check {
+            root,
+            format,
+            deny_warnings,
+        } => project(root).map_or(ExitCode::FAILURE, |project| {
+            check_command(&project, *format, *deny_warnings, &terminal)
+        }),
+        G3cssCommand::Fmt { check, paths } => fmt_command(paths, *check, &terminal),
+        G3cssCommand::Lsp => match g3css_lsp() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                terminal.error(&format!("{:?}", error));
+                ExitCode::FAILURE
+            }
+        },
+        G3cssCommand::Init { root } => init_command(root, &terminal),
+        G3cssCommand::Ast { file, json, g3css } => ast_command(file, *json, *g3css, &terminal),
+        G3cssCommand::Docs { root } => project(root).map_or(ExitCode::FAILURE, |project| {
+            docs_command(&project, &terminal)
+        }),
+        G3cssCommand::Graph { root, format } => project(root)
+            .map_or(ExitCode::FAILURE, |project| {
+                graph_command(&project, *format, &terminal)
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::cli::cli_args::ColorChoice;
+
+    /// Writes a project whose configuration and sources are `files`, removing what a previous
+    /// run left.
+    fn write_project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("g3css-check-{}-{}", std::process::id(), name));
+
+        let _ = fs::remove_dir_all(&root);
+
+        for (path, content) in files {
+            write_file(&root.join(path), content).unwrap();
+        }
+
+        root
+    }
+
+    /// Loads a project the way `check` does, without printing anything.
+    fn load_project(root: &Path) -> Project {
+        let args = GlobalArgs {
+            config: None,
+            overrides: vec![],
+            out_dir: None,
+            verbose: 0,
+            quiet: true,
+            color: ColorChoice::Never,
+        };
+
+        Project::load(root, &args, &Terminal::new(&args)).unwrap()
+    }
+
+    /// Runs `check` over a project, returning its exit code and JSON report.
+    fn check_json(root: &Path) -> (u8, Value) {
+        let files = check_project(&load_project(root));
+        let report = serde_json::from_str(&json_report(&files)).unwrap();
+
+        (check_status(&files, false), report)
+    }
+
+    /// Lists the `(file, severity, code)` of the diagnostics of a JSON report.
+    fn reported(report: &Value, root: &Path) -> Vec<(String, String, String)> {
+        report["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|diagnostic| {
+                let file = Path::new(diagnostic["file"].as_str().unwrap());
+                (
+                    file.strip_prefix(root).unwrap().display().to_string(),
+                    diagnostic["severity"].as_str().unwrap().to_string(),
+                    diagnostic["code"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn json_check_reports_every_file_and_exits_with_the_worst_failure() {
+        let [config, global, component] = STARTER_FILES;
+        let root = write_project("json", &[config, global, component]);
+
+        let (status, report) = check_json(&root);
+
+        assert_eq!(status, 0);
+        assert_eq!(report["version"], 1);
+        assert_eq!(report["files"], 2);
+        assert!(reported(&report, &root)
+            .iter()
+            .all(|(_, severity, _)| severity == "hint"));
+
+        // A semantic error fails the check with its own exit code.
+        write_file(
+            &root.join("styles/card.g3css"),
+            "define component scope \"CARD\" extends global \"APP\" {\n    define class \"BOX\" {\n        properties: {\n            margin: \"${gutter}\",\n        },\n    },\n}\n",
+        )
+        .unwrap();
+
+        let (status, report) = check_json(&root);
+        let error = (
+            "styles/card.g3css".to_string(),
+            "error".to_string(),
+            "unknown-variable".to_string(),
+        );
+
+        assert_eq!(status, EXIT_SEMANTIC_ERROR);
+        assert_eq!(report["files"], 3);
+        assert!(reported(&report, &root).contains(&error));
+
+        // A file that does not parse wins over the semantic error.
+        write_file(&root.join("styles/broken.g3css"), "define global scope {").unwrap();
+
+        let (status, report) = check_json(&root);
+        let diagnostics = reported(&report, &root);
+
+        assert_eq!(status, EXIT_PARSE_ERROR);
+        assert_eq!(report["files"], 4);
+        assert!(diagnostics.contains(&error));
+        assert!(diagnostics.contains(&(
+            "styles/broken.g3css".to_string(),
+            "error".to_string(),
+            "parse-error".to_string(),
+        )));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn warnings_only_fail_the_check_when_denied() {
+        let files = [CheckedFile {
+            path: PathBuf::from("styles/app.g3css"),
+            diagnostics: vec![G3cssDiagnostic {
+                severity: G3cssSeverity::Warning,
+                code: "deprecated-property",
+                ..error_diagnostic(&G3cssError::OtherError("deprecated".to_string()))
+            }],
+        }];
+
+        assert_eq!(check_status(&files, false), 0);
+        assert_eq!(check_status(&files, true), EXIT_DENIED_WARNINGS);
+    }
+}
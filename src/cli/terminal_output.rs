@@ -0,0 +1,147 @@
+use std::{
+    env,
+    io::{stderr, IsTerminal},
+    path::Path,
+};
+
+use g3css_ast::{
+    rustal::semantic_checks::error_diagnostic,
+    types::g3css_diagnostic::{G3cssDiagnostic, G3cssSeverity},
+};
+use g3css_parser::types::g3css_error::G3cssError;
+
+use super::cli_args::{ColorChoice, GlobalArgs};
+
+/// Enum representing how much the binary prints.
+/// Quiet - only errors
+/// Normal - errors, warnings and a line per written file
+/// Verbose - everything, including skipped files and details
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+/// Enum representing the ANSI styles used by the output.
+/// Red - errors
+/// Yellow - warnings
+/// Green - successful steps
+/// Cyan - locations and notes
+/// Dim - details
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Paint {
+    Red,
+    Yellow,
+    Green,
+    Cyan,
+    Dim,
+}
+
+/// Prints the messages of the binary according to the verbosity and colour flags.
+#[derive(Debug, Clone, Copy)]
+pub struct Terminal {
+    pub verbosity: Verbosity,
+    pub color: bool,
+}
+
+impl Terminal {
+    /// Builds the terminal described by the global flags.
+    pub fn new(args: &GlobalArgs) -> Self {
+        let verbosity = match (args.quiet, args.verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, _) => Verbosity::Verbose,
+        };
+
+        let color = match args.color {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => stderr().is_terminal() && env::var_os("NO_COLOR").is_none(),
+        };
+
+        Self { verbosity, color }
+    }
+
+    /// Wraps `text` in the escape codes of `paint` when colours are enabled.
+    pub fn paint(&self, text: &str, paint: Paint) -> String {
+        if !self.color {
+            return text.to_string();
+        }
+
+        let code = match paint {
+            Paint::Red => "1;31",
+            Paint::Yellow => "1;33",
+            Paint::Green => "1;32",
+            Paint::Cyan => "36",
+            Paint::Dim => "2",
+        };
+
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    }
+
+    /// Prints an error, whatever the verbosity.
+    pub fn error(&self, message: &str) {
+        eprintln!("{}: {}", self.paint("error", Paint::Red), message);
+    }
+
+    /// Prints a warning, unless the output is quiet.
+    pub fn warning(&self, message: &str) {
+        if self.verbosity > Verbosity::Quiet {
+            eprintln!("{}: {}", self.paint("warning", Paint::Yellow), message);
+        }
+    }
+
+    /// Prints a step of the command, such as a written file, unless the output is quiet.
+    pub fn status(&self, label: &str, message: &str) {
+        if self.verbosity > Verbosity::Quiet {
+            eprintln!("{:>12} {}", self.paint(label, Paint::Green), message);
+        }
+    }
+
+    /// Prints a detail only shown by verbose output.
+    pub fn detail(&self, message: &str) {
+        if self.verbosity == Verbosity::Verbose {
+            eprintln!("{}", self.paint(message, Paint::Dim));
+        }
+    }
+
+    /// Prints a diagnostic of a file as `path:line:column: severity[code]: message`.
+    pub fn diagnostic(&self, path: &Path, diagnostic: &G3cssDiagnostic) {
+        let (label, paint) = match diagnostic.severity {
+            G3cssSeverity::Error => ("error", Paint::Red),
+            G3cssSeverity::Warning => ("warning", Paint::Yellow),
+            G3cssSeverity::Information => ("info", Paint::Cyan),
+            G3cssSeverity::Hint => ("hint", Paint::Cyan),
+        };
+
+        if diagnostic.severity > G3cssSeverity::Error && self.verbosity == Verbosity::Quiet {
+            return;
+        }
+
+        let location = format!(
+            "{}:{}:{}",
+            path.display(),
+            diagnostic.span.line,
+            diagnostic.span.column
+        );
+
+        eprintln!(
+            "{}: {}[{}]: {}",
+            self.paint(&location, Paint::Cyan),
+            self.paint(label, paint),
+            diagnostic.code,
+            diagnostic.message
+        );
+    }
+
+    /// Prints an error met while processing a file, located when it comes from the parser.
+    pub fn file_error(&self, path: &Path, error: &G3cssError) {
+        match error {
+            G3cssError::ParseError(_) => self.diagnostic(path, &error_diagnostic(error)),
+            G3cssError::OtherError(message) => {
+                self.error(&format!("{}: {}", path.display(), message))
+            }
+        }
+    }
+}
@@ -0,0 +1,780 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use g3css_ast::{
+    rustal::cst_builder::parse_cst,
+    types::{
+        syntax_view::{Alias, Class, ClassItem, Global, GlobalItem, Scope, ScopeItem, Variable},
+        typed_ast::ThemeKind,
+    },
+};
+use g3css_common::tables::{css_colors::is_css_color, g3css_properties::css_property_name};
+use g3css_parser::{g3css_parse_source, types::g3css_error::G3cssError};
+use g3css_transformer::{
+    g3css_transformer,
+    rustal::css_emitter::emit_css,
+    types::{
+        css_stylesheet::CssStylesheet,
+        transformer_options::{
+            G3cssCssStyle, G3cssOutputMode, G3cssThemeStrategy, G3cssTransformerOptions,
+        },
+    },
+};
+
+/// Stylesheet of the pages of the style guide. Its classes are lowercase and prefixed with
+/// `sg-`, so they cannot clash with the classes of the globals, which are uppercase.
+const SITE_STYLESHEET: &str = r#"* {
+    box-sizing: border-box;
+}
+
+body {
+    margin: 0;
+    display: flex;
+    font-family: system-ui, -apple-system, "Segoe UI", sans-serif;
+    color: #1f2933;
+    background-color: #f5f7fa;
+}
+
+.sg-nav {
+    position: sticky;
+    top: 0;
+    width: 16rem;
+    height: 100vh;
+    flex-shrink: 0;
+    overflow-y: auto;
+    padding: 1.5rem;
+    background-color: #1f2933;
+    color: #f5f7fa;
+}
+
+.sg-nav a {
+    color: inherit;
+    text-decoration: none;
+}
+
+.sg-nav ul {
+    list-style: none;
+    padding: 0;
+}
+
+.sg-nav li {
+    margin: 0.25rem 0;
+}
+
+.sg-nav ul ul {
+    padding-left: 1rem;
+    font-size: 0.875rem;
+    opacity: 0.8;
+}
+
+.sg-home {
+    font-size: 1.25rem;
+    font-weight: 600;
+}
+
+.sg-main {
+    flex-grow: 1;
+    min-width: 0;
+    padding: 2rem 3rem;
+}
+
+.sg-main > section {
+    margin-bottom: 3rem;
+}
+
+.sg-meta {
+    color: #52606d;
+}
+
+.sg-doc {
+    max-width: 48rem;
+}
+
+.sg-toggle {
+    padding: 0.5rem 1rem;
+    border: 1px solid #9aa5b1;
+    border-radius: 4px;
+    background-color: #ffffff;
+    color: #1f2933;
+    cursor: pointer;
+}
+
+table {
+    border-collapse: collapse;
+    background-color: #ffffff;
+}
+
+th,
+td {
+    padding: 0.5rem 1rem;
+    border: 1px solid #e4e7eb;
+    text-align: left;
+    vertical-align: top;
+}
+
+.sg-swatch {
+    display: inline-block;
+    width: 2rem;
+    height: 2rem;
+    margin-right: 0.5rem;
+    border: 1px solid #9aa5b1;
+    border-radius: 4px;
+    vertical-align: middle;
+}
+
+.sg-class {
+    padding: 1.5rem;
+    margin-bottom: 1.5rem;
+    border: 1px solid #e4e7eb;
+    border-radius: 8px;
+    background-color: #ffffff;
+}
+
+.sg-preview {
+    padding: 2rem;
+    margin: 1rem 0;
+    border: 1px dashed #9aa5b1;
+    border-radius: 4px;
+    background-image: linear-gradient(45deg, #f5f7fa 25%, transparent 25%),
+        linear-gradient(-45deg, #f5f7fa 25%, transparent 25%);
+    background-size: 16px 16px;
+}
+
+pre {
+    overflow-x: auto;
+    padding: 1rem;
+    border-radius: 4px;
+    background-color: #1f2933;
+    color: #f5f7fa;
+}
+"#;
+
+/// Text of the element every class is previewed on.
+const SAMPLE_TEXT: &str = "The quick brown fox jumps over the lazy dog.";
+
+/// Represents a generated style guide.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct StyleGuide {
+    /// Files of the site, by their path relative to its root, with their content.
+    pub files: Vec<(PathBuf, String)>,
+    /// Source files left out of the site, with the error met while reading them.
+    pub failures: Vec<(PathBuf, G3cssError)>,
+}
+
+/// A global read for the style guide.
+struct GlobalGuide {
+    /// Source file of the global.
+    path: PathBuf,
+    /// Typed AST of the global, with its doc comments.
+    global: Global,
+    /// CSS generated for the global, used by the previews and to show the CSS of its classes.
+    stylesheet: CssStylesheet,
+    /// Name of the page of the global, without its extension, unique within the site.
+    page: String,
+}
+
+/// Escapes text for an HTML attribute or element.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Derives the page name of a global from its name (e.g. `APP_THEME` -> `app-theme`),
+/// numbering it when another file of the site already uses the name.
+fn page_name(name: &str, used: &[String]) -> String {
+    let page = name
+        .to_lowercase()
+        .chars()
+        .map(|character| match character.is_alphanumeric() {
+            true => character,
+            false => '-',
+        })
+        .collect::<String>();
+    let page = match page.trim_matches('-') {
+        "" => "global",
+        page => page,
+    };
+
+    let taken =
+        |name: &String| matches!(name.as_str(), "index" | "style-guide") || used.contains(name);
+
+    (1..)
+        .map(|number| match number {
+            1 => page.to_string(),
+            number => format!("{}-{}", page, number),
+        })
+        .find(|name| !taken(name))
+        .unwrap_or_default()
+}
+
+/// Renders a doc comment as paragraphs, blank lines separating them.
+fn doc_html(doc: Option<&str>) -> String {
+    doc.map_or(String::new(), |doc| {
+        doc.split("\n\n")
+            .map(|paragraph| format!("<p class=\"sg-doc\">{}</p>\n", escape_html(paragraph)))
+            .collect()
+    })
+}
+
+/// Collects the elements of a global other than its themes.
+fn scope_items(global: &Global) -> impl Iterator<Item = ScopeItem> {
+    global.children().filter_map(|child| match child {
+        GlobalItem::Item(item) => Some(item),
+        GlobalItem::Theme(_) => None,
+    })
+}
+
+/// Collects the variables of a global outside of its themes, in source order.
+fn variables(global: &Global) -> Vec<Variable> {
+    scope_items(global)
+        .flat_map(|item| match item {
+            ScopeItem::Variables(variables) => variables.variables().collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// Collects the aliases of a global, in source order.
+fn aliases(global: &Global) -> Vec<Alias> {
+    scope_items(global)
+        .flat_map(|item| match item {
+            ScopeItem::Aliases(aliases) => aliases.aliases().collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// Collects the classes of a global, in source order.
+fn classes(global: &Global) -> Vec<Class> {
+    scope_items(global)
+        .flat_map(|item| match item {
+            ScopeItem::Class(class) => vec![class],
+            ScopeItem::Classes(classes) => classes.classes().collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// Collects the variables of the light and dark themes of a global.
+fn theme_variables(global: &Global) -> (Vec<Variable>, Vec<Variable>) {
+    let (mut light, mut dark) = (vec![], vec![]);
+
+    for child in global.children() {
+        if let GlobalItem::Theme(theme) = child {
+            match theme.kind() {
+                ThemeKind::Light => light.extend(theme.variables()),
+                ThemeKind::Dark => dark.extend(theme.variables()),
+            }
+        }
+    }
+
+    (light, dark)
+}
+
+/// Renders the themes of a global as a table, one row per variable, with a swatch for every
+/// colour.
+fn themes_section(global: &Global) -> String {
+    let (light, dark) = theme_variables(global);
+
+    if light.is_empty() && dark.is_empty() {
+        return String::new();
+    }
+
+    // Variables are listed in the order of the light theme, then of the dark theme.
+    let mut names = light.iter().map(Variable::key).collect::<Vec<_>>();
+    names.extend(
+        dark.iter()
+            .map(Variable::key)
+            .filter(|key| !light.iter().any(|variable| variable.key() == *key)),
+    );
+
+    let swatch = |variables: &[Variable], name: &str| {
+        variables
+            .iter()
+            .find(|variable| variable.key() == name)
+            .map_or(String::new(), |variable| {
+                let value = variable.value();
+                let code = format!("<code>{}</code>", escape_html(&value));
+
+                // Lengths, font stacks and other values have nothing to paint.
+                match is_css_color(&value) {
+                    true => format!(
+                        "<span class=\"sg-swatch\" style=\"background: {}\"></span>{}",
+                        escape_html(&value),
+                        code
+                    ),
+                    false => code,
+                }
+            })
+    };
+
+    let mut html = String::from(
+        "<section id=\"themes\">\n<h2>Themes</h2>\n<table>\n\
+         <tr><th>Variable</th><th>Light</th><th>Dark</th><th>Description</th></tr>\n",
+    );
+
+    for name in names {
+        let doc = light
+            .iter()
+            .chain(&dark)
+            .find_map(|variable| (variable.key() == name).then(|| variable.doc()))
+            .flatten();
+
+        html.push_str(&format!(
+            "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape_html(&name),
+            swatch(&light, &name),
+            swatch(&dark, &name),
+            doc_html(doc.as_deref())
+        ));
+    }
+
+    html.push_str("</table>\n</section>\n");
+    html
+}
+
+/// Renders the variables of a global outside of its themes.
+fn variables_section(global: &Global) -> String {
+    let variables = variables(global);
+
+    if variables.is_empty() {
+        return String::new();
+    }
+
+    let mut html = String::from(
+        "<section id=\"variables\">\n<h2>Variables</h2>\n<table>\n\
+         <tr><th>Variable</th><th>Value</th><th>Description</th></tr>\n",
+    );
+
+    for variable in variables {
+        html.push_str(&format!(
+            "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>\n",
+            escape_html(&variable.key()),
+            escape_html(&variable.value()),
+            doc_html(variable.doc().as_deref())
+        ));
+    }
+
+    html.push_str("</table>\n</section>\n");
+    html
+}
+
+/// Renders the aliases of a global with the CSS property each stands for.
+fn aliases_section(global: &Global) -> String {
+    let aliases = aliases(global);
+
+    if aliases.is_empty() {
+        return String::new();
+    }
+
+    let mut html = String::from(
+        "<section id=\"aliases\">\n<h2>Aliases</h2>\n<table>\n\
+         <tr><th>Alias</th><th>Property</th><th>CSS property</th><th>Description</th></tr>\n",
+    );
+
+    for alias in aliases {
+        html.push_str(&format!(
+            "<tr><td><code>{}</code></td><td><code>{}</code></td><td><code>{}</code></td>\
+             <td>{}</td></tr>\n",
+            escape_html(&alias.key()),
+            escape_html(&alias.value()),
+            css_property_name(&alias.value()).unwrap_or_default(),
+            doc_html(alias.doc().as_deref())
+        ));
+    }
+
+    html.push_str("</table>\n</section>\n");
+    html
+}
+
+/// Renders the breakpoints of the project with the classes of a global adapting to each.
+fn breakpoints_section(global: &Global, options: &G3cssTransformerOptions) -> String {
+    let classes = classes(global);
+
+    let mut html = String::from(
+        "<section id=\"breakpoints\">\n<h2>Breakpoints</h2>\n<table>\n\
+         <tr><th>Breakpoint</th><th>Media query</th><th>Classes</th></tr>\n",
+    );
+
+    for (name, media) in &options.breakpoints {
+        let users = classes
+            .iter()
+            .filter(|class| {
+                class.body().any(|item| match item {
+                    ClassItem::PanoramicViewer(viewer) => viewer
+                        .breakpoints()
+                        .any(|breakpoint| &breakpoint.name() == name),
+                    _ => false,
+                })
+            })
+            .map(|class| {
+                let name = escape_html(&class.name());
+                format!("<a href=\"#class-{}\"><code>{}</code></a>", name, name)
+            })
+            .collect::<Vec<_>>();
+
+        html.push_str(&format!(
+            "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>\n",
+            escape_html(name),
+            escape_html(media),
+            users.join(", ")
+        ));
+    }
+
+    html.push_str("</table>\n</section>\n");
+    html
+}
+
+/// Renders every class of a global with its documentation, a preview and its CSS.
+fn classes_section(guide: &GlobalGuide) -> String {
+    let classes = classes(&guide.global);
+
+    if classes.is_empty() {
+        return String::new();
+    }
+
+    let mut html = String::from("<section id=\"classes\">\n<h2>Classes</h2>\n");
+
+    for class in classes {
+        let class_name = class.name();
+        let name = escape_html(&class_name);
+
+        // The preview applies the CSS classes the transformer generated for the class.
+        let applied = guide
+            .stylesheet
+            .class_map
+            .get(&class_name)
+            .map_or(class_name.clone(), |names| names.join(" "));
+
+        let stylesheet = CssStylesheet {
+            rules: guide
+                .stylesheet
+                .rules
+                .iter()
+                .filter(|rule| rule.class_name.as_deref() == Some(class_name.as_str()))
+                .cloned()
+                .collect(),
+            ..CssStylesheet::default()
+        };
+
+        html.push_str(&format!(
+            "<div class=\"sg-class\" id=\"class-{}\">\n<h3><code>{}</code></h3>\n",
+            name, name
+        ));
+
+        if let Some(inherits) = class.inherits() {
+            let inherits = escape_html(&inherits);
+            html.push_str(&format!(
+                "<p class=\"sg-meta\">Inherits <a href=\"#class-{}\"><code>{}</code></a></p>\n",
+                inherits, inherits
+            ));
+        }
+
+        html.push_str(&doc_html(class.doc().as_deref()));
+        html.push_str(&format!(
+            "<div class=\"sg-preview\"><div class=\"{}\">{}</div></div>\n",
+            escape_html(&applied),
+            SAMPLE_TEXT
+        ));
+        html.push_str(&format!(
+            "<pre><code>{}</code></pre>\n</div>\n",
+            escape_html(&emit_css(&stylesheet, G3cssCssStyle::Pretty))
+        ));
+    }
+
+    html.push_str("</section>\n");
+    html
+}
+
+/// Wraps the body of a page in the layout of the site, with a navigation listing every
+/// global and the sections of the current one.
+fn page(title: &str, guides: &[GlobalGuide], current: Option<&GlobalGuide>, body: &str) -> String {
+    let mut navigation = String::new();
+
+    for guide in guides {
+        navigation.push_str(&format!(
+            "<li><a href=\"{}.html\">{}</a>",
+            guide.page,
+            escape_html(&guide.global.name())
+        ));
+
+        if current.is_some_and(|current| current.page == guide.page) {
+            navigation.push_str("\n<ul>\n");
+
+            for (id, label) in [
+                ("themes", "Themes"),
+                ("variables", "Variables"),
+                ("aliases", "Aliases"),
+                ("breakpoints", "Breakpoints"),
+                ("classes", "Classes"),
+            ] {
+                if body.contains(&format!("<section id=\"{}\">", id)) {
+                    navigation.push_str(&format!("<li><a href=\"#{}\">{}</a></li>\n", id, label));
+                }
+            }
+
+            navigation.push_str("</ul>\n");
+        }
+
+        navigation.push_str("</li>\n");
+    }
+
+    let stylesheet = current.map_or(String::new(), |current| {
+        format!("<link rel=\"stylesheet\" href=\"{}.css\">\n", current.page)
+    });
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{} · Style guide</title>\n<link rel=\"stylesheet\" href=\"style-guide.css\">\n\
+         {}</head>\n<body>\n<nav class=\"sg-nav\">\n\
+         <a class=\"sg-home\" href=\"index.html\">Style guide</a>\n<ul>\n{}</ul>\n</nav>\n\
+         <main class=\"sg-main\">\n{}</main>\n</body>\n</html>\n",
+        escape_html(title),
+        stylesheet,
+        navigation,
+        body
+    )
+}
+
+/// Renders the page of a global.
+fn global_page(
+    guide: &GlobalGuide,
+    guides: &[GlobalGuide],
+    options: &G3cssTransformerOptions,
+) -> String {
+    let global = &guide.global;
+    let (_, dark) = theme_variables(global);
+
+    let mut body = format!(
+        "<h1>{}</h1>\n<p class=\"sg-meta\">Declared in <code>{}</code></p>\n",
+        escape_html(&global.name()),
+        escape_html(&guide.path.display().to_string())
+    );
+
+    // The stylesheet of the page scopes the dark theme to the `dark` class of the root.
+    if !dark.is_empty() {
+        body.push_str(
+            "<p><button class=\"sg-toggle\" type=\"button\" \
+             onclick=\"document.documentElement.classList.toggle('dark')\">\
+             Toggle the dark theme</button></p>\n",
+        );
+    }
+
+    body.push_str(&themes_section(global));
+    body.push_str(&variables_section(global));
+    body.push_str(&aliases_section(global));
+    body.push_str(&breakpoints_section(global, options));
+    body.push_str(&classes_section(guide));
+
+    page(&global.name(), guides, Some(guide), &body)
+}
+
+/// Renders the index of the site, listing every global.
+fn index_page(guides: &[GlobalGuide]) -> String {
+    let mut body = String::from("<h1>Style guide</h1>\n");
+
+    if guides.is_empty() {
+        body.push_str("<p>The project declares no global scope.</p>\n");
+    } else {
+        body.push_str(
+            "<table>\n<tr><th>Global</th><th>File</th><th>Theme variables</th>\
+             <th>Variables</th><th>Aliases</th><th>Classes</th></tr>\n",
+        );
+
+        for guide in guides {
+            let (light, dark) = theme_variables(&guide.global);
+
+            body.push_str(&format!(
+                "<tr><td><a href=\"{}.html\">{}</a></td><td><code>{}</code></td><td>{}</td>\
+                 <td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                guide.page,
+                escape_html(&guide.global.name()),
+                escape_html(&guide.path.display().to_string()),
+                light.len().max(dark.len()),
+                variables(&guide.global).len(),
+                aliases(&guide.global).len(),
+                classes(&guide.global).len()
+            ));
+        }
+
+        body.push_str("</table>\n");
+    }
+
+    page("Index", guides, None, &body)
+}
+
+/// Reads a source file, returning `None` if it declares a component. The page of the
+/// global is named once every global is known.
+fn read_global(
+    path: &Path,
+    options: &G3cssTransformerOptions,
+) -> Result<Option<GlobalGuide>, G3cssError> {
+    let source =
+        fs::read_to_string(path).map_err(|error| G3cssError::OtherError(error.to_string()))?;
+
+    let Some(Scope::Global(global)) = Scope::from_root(&parse_cst(&source)?) else {
+        return Ok(None);
+    };
+
+    let stylesheet = g3css_transformer(&*g3css_parse_source(&source)?, None, options)?;
+
+    Ok(Some(GlobalGuide {
+        path: path.to_path_buf(),
+        global,
+        stylesheet,
+        page: String::new(),
+    }))
+}
+
+/// Generates a static HTML style guide of the globals of a project.
+///
+/// The site holds an index of the globals and a page per global showing its light and dark
+/// themes with a swatch per colour, its variables and aliases, the breakpoints of the project
+/// and every class with its doc comment, its CSS and a preview of the class applied to a
+/// sample element. Components are left out, as they only use what their global declares.
+///
+/// # Arguments
+///
+/// - `paths` - The source files of the project.
+/// - `options` - The options of the project, whose breakpoints and browser targets are
+///   used to generate the CSS of the previews.
+///
+/// # Returns
+///
+/// - `StyleGuide` - The files of the site, and the source files that could not be read,
+///   parsed or transformed.
+pub fn build_style_guide(paths: &[PathBuf], options: &G3cssTransformerOptions) -> StyleGuide {
+    // Previews need one readable rule set per class, and a dark theme the page can toggle.
+    let options = G3cssTransformerOptions {
+        output_mode: G3cssOutputMode::Static,
+        optimize: false,
+        css_style: G3cssCssStyle::Pretty,
+        theme: G3cssThemeStrategy::Class,
+        ..options.clone()
+    };
+
+    let mut guide = StyleGuide::default();
+    let mut guides = vec![];
+
+    for path in paths {
+        match read_global(path, &options) {
+            Ok(Some(global)) => guides.push(global),
+            Ok(None) => (),
+            Err(error) => guide.failures.push((path.clone(), error)),
+        }
+    }
+
+    guides.sort_by_key(|guide| guide.global.name());
+
+    let mut pages = vec![];
+
+    for global in &mut guides {
+        global.page = page_name(&global.global.name(), &pages);
+        pages.push(global.page.clone());
+    }
+
+    guide.files.push((
+        PathBuf::from("style-guide.css"),
+        SITE_STYLESHEET.to_string(),
+    ));
+    guide
+        .files
+        .push((PathBuf::from("index.html"), index_page(&guides)));
+
+    for global in &guides {
+        guide.files.push((
+            PathBuf::from(format!("{}.css", global.page)),
+            emit_css(&global.stylesheet, G3cssCssStyle::Pretty),
+        ));
+        guide.files.push((
+            PathBuf::from(format!("{}.html", global.page)),
+            global_page(global, &guides, &options),
+        ));
+    }
+
+    guide
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLOBAL: &str = r##"define global scope "APP" {
+    define light theme {
+        define variables {
+            surface: "#ffffff",
+            shadow: "rgba(0,0,0,0.2)",
+            gap: "8px",
+        },
+    },
+
+    define dark theme {
+        define variables {
+            surface: "black",
+            font: "Inter,sans-serif",
+        },
+    },
+
+    /// Cards wrap <section> & "aside" content.
+    define class "CARD" {
+        properties: {
+            padding: "4px",
+        },
+    },
+}
+"##;
+
+    /// Generates the style guide of `source`, returning the page of its global.
+    fn global_page_of(name: &str, source: &str) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "g3css-style-guide-{}-{}.g3css",
+            std::process::id(),
+            name
+        ));
+        fs::write(&path, source).unwrap();
+
+        let guide = build_style_guide(
+            std::slice::from_ref(&path),
+            &G3cssTransformerOptions::default(),
+        );
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(guide.failures, []);
+        guide
+            .files
+            .into_iter()
+            .find_map(|(file, content)| (file == Path::new("app.html")).then_some(content))
+            .unwrap()
+    }
+
+    #[test]
+    fn swatches_are_only_rendered_for_colours() {
+        let page = global_page_of("swatches", GLOBAL);
+        let swatch =
+            |value: &str| format!("<span class=\"sg-swatch\" style=\"background: {}\">", value);
+
+        assert!(page.contains(&swatch("#ffffff")));
+        assert!(page.contains(&swatch("rgba(0,0,0,0.2)")));
+        assert!(page.contains(&swatch("black")));
+        assert!(!page.contains(&swatch("8px")));
+        assert!(!page.contains(&swatch("Inter,sans-serif")));
+        assert!(page.contains("<td><code>8px</code></td>"));
+        assert!(page.contains("<td><code>Inter,sans-serif</code></td>"));
+    }
+
+    #[test]
+    fn doc_comments_are_escaped() {
+        let page = global_page_of("escaping", GLOBAL);
+
+        assert!(page.contains(
+            "<p class=\"sg-doc\">Cards wrap &lt;section&gt; &amp; &quot;aside&quot; content.</p>"
+        ));
+        assert!(!page.contains("<section> &"));
+    }
+}
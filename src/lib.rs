@@ -1,8 +1,29 @@
-pub use g3css_ast::*;
-pub use g3css_lsp::*;
-pub use g3css_observer::*;
+pub mod build {
+    pub mod built_stylesheet;
+    pub mod project_builder;
+}
+
+pub mod cache {
+    pub mod build_cache;
+    pub mod cached_scope;
+}
+
+pub mod docs {
+    pub mod style_guide;
+}
+
+pub mod cli {
+    pub mod check_reports;
+    pub mod cli_args;
+    pub mod command_handlers;
+    pub mod terminal_output;
+}
+
+pub use g3css_ast::{g3css_ast_setter, g3css_format};
+pub use g3css_lsp::g3css_lsp;
+pub use g3css_observer::g3css_observer;
 pub use g3css_parser::*;
-pub use g3css_transformer::*;
+pub use g3css_transformer::g3css_transformer;
 
 pub fn run_framework(file_path: &str) {
     match g3css_parser(file_path) {
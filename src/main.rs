@@ -1,5 +1,7 @@
-use galadriel3css::run_framework;
+use clap::Parser;
+use galadriel3css::cli::{cli_args::G3cssCli, command_handlers::run_cli};
+use std::process::ExitCode;
 
-fn main() {
-    run_framework("./mockup/testing-component-a.g3css");
+fn main() -> ExitCode {
+    run_cli(G3cssCli::parse())
 }